@@ -1,25 +1,57 @@
+use abap_lsp::codelens::{self, ReferencesData, TestTarget};
+use abap_lsp::completion::{self, ResolveData};
 use abap_lsp::context::{CONTEXT_STORE, ClientContext};
-use abap_lsp::tokens::{SemanticToken, TokenType};
-use std::sync::Arc;
+use abap_lsp::document::PositionEncoding;
+use abap_lsp::settings::{Formatter, Settings};
+use abap_lsp::tokens::{TokenModifier, TokenType};
+use abap_lsp::{diagnostics, formatting, hover, navigation, references, signature};
+use adt_query::models::adtcore::ObjectReference;
+use serde_json::Value;
+use std::collections::HashSet;
+use std::sync::{Arc, OnceLock, RwLock};
+use std::time::Duration;
 use std::vec;
 use tokio::sync::OnceCell;
 use tower_lsp::jsonrpc::{Error, Result};
 use tower_lsp::lsp_types::{
-    DidOpenTextDocumentParams, InitializedParams, MessageType, SemanticTokenType, SemanticTokens,
+    CodeLens, CodeLensOptions, CodeLensParams, Command, CompletionItem, CompletionOptions,
+    CompletionParams, CompletionResponse, DidChangeConfigurationParams,
+    DidChangeTextDocumentParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
+    DocumentFormattingParams, DocumentRangeFormattingParams, DocumentSymbolParams,
+    DocumentSymbolResponse, ExecuteCommandOptions, ExecuteCommandParams, FoldingRange,
+    FoldingRangeParams, FoldingRangeProviderCapability, GotoDefinitionParams,
+    GotoDefinitionResponse, Hover, HoverParams, HoverProviderCapability, InitializedParams,
+    Location, MessageType, OneOf, Position, Range, ReferenceParams, SemanticTokenModifier,
+    SemanticTokenType, SemanticTokensDeltaParams, SemanticTokensFullDeltaResult,
     SemanticTokensFullOptions, SemanticTokensLegend, SemanticTokensOptions, SemanticTokensParams,
-    SemanticTokensResult, TextDocumentSyncCapability, TextDocumentSyncKind,
-    WorkDoneProgressOptions,
+    SemanticTokensRangeParams, SemanticTokensRangeResult, SemanticTokensResult, SignatureHelp,
+    SignatureHelpOptions, SignatureHelpParams, TextDocumentSyncCapability, TextDocumentSyncKind,
+    TextDocumentSyncOptions, TextEdit, Url, WorkDoneProgressOptions, WorkspaceSymbol,
+    WorkspaceSymbolOptions,
 };
 use tower_lsp::{
     Client as LspClient, LanguageServer,
     lsp_types::{InitializeParams, InitializeResult, ServerCapabilities},
 };
 
+use crate::methods::aunit::{RunUnitTestsParams, RunUnitTestsResult};
+use crate::methods::error;
+
 #[derive(Debug)]
 pub struct Backend {
     pub client: LspClient,
 
     pub context: OnceCell<Arc<ClientContext>>,
+
+    /// The encoding of positions negotiated with the client on `initialize`.
+    pub position_encoding: OnceLock<PositionEncoding>,
+
+    /// The settings of the editor, see [`Settings`].
+    pub settings: RwLock<Settings>,
+
+    /// Whether the client resolves the text edits of completion items lazily, see
+    /// [`completion::resolves_text_edit`].
+    pub resolves_completion_edits: OnceLock<bool>,
 }
 
 impl Backend {
@@ -27,6 +59,9 @@ impl Backend {
         return Self {
             client,
             context: OnceCell::new(),
+            position_encoding: OnceLock::new(),
+            settings: RwLock::new(Settings::default()),
+            resolves_completion_edits: OnceLock::new(),
         };
     }
 
@@ -37,16 +72,251 @@ impl Backend {
     pub fn context(&self) -> Result<&Arc<ClientContext>> {
         self.context.get().ok_or(Error::internal_error())
     }
+
+    pub fn position_encoding(&self) -> PositionEncoding {
+        self.position_encoding.get().copied().unwrap_or_default()
+    }
+
+    pub fn settings(&self) -> Settings {
+        self.settings.read().unwrap().clone()
+    }
+
+    /// How long to wait for further changes before running a check on the backend.
+    const CHECK_DEBOUNCE: Duration = Duration::from_millis(750);
+
+    /// Schedules a check run of the backend for a version of the document.
+    ///
+    /// The check is skipped if the document changed in the meantime, since the change
+    /// scheduled another check. Results for outdated versions are discarded as well.
+    pub fn schedule_check(&self, uri: Url, version: i32) {
+        let Ok(ctx) = self.context() else { return };
+        let (ctx, client) = (ctx.clone(), self.client.clone());
+
+        tokio::spawn(async move {
+            tokio::time::sleep(Self::CHECK_DEBOUNCE).await;
+
+            let Some(doc) = ctx.fetch_document(uri.as_str()) else {
+                return;
+            };
+            let (adt_uri, content, encoding) = {
+                let doc = doc.lock().unwrap();
+                if doc.version() != Some(version) {
+                    return;
+                }
+                (doc.adt_uri().to_owned(), doc.raw_content(), doc.encoding())
+            };
+
+            let result =
+                diagnostics::check_run_diagnostics(&ctx.adt_client, &adt_uri, &content, encoding)
+                    .await;
+            let check_diagnostics = match result {
+                Ok(diagnostics) => diagnostics,
+                Err(e) => {
+                    client
+                        .log_message(MessageType::WARNING, format!("Check run failed: {e}"))
+                        .await;
+                    return;
+                }
+            };
+
+            let diagnostics = {
+                let mut doc = doc.lock().unwrap();
+                if doc.version() != Some(version) {
+                    return;
+                }
+                doc.set_check_diagnostics(check_diagnostics);
+                doc.diagnostics()
+            };
+            client
+                .publish_diagnostics(uri, diagnostics, Some(version))
+                .await;
+        });
+    }
+
+    /// The references of the symbol at the position, those within the document are
+    /// taken from its syntax tree and all others from the where-used list of the backend.
+    async fn find_references(
+        &self,
+        uri: &Url,
+        position: Position,
+        include_declaration: bool,
+    ) -> Result<Option<Vec<Location>>> {
+        let ctx = self.context()?;
+        let Some(doc) = ctx.fetch_document(uri.as_str()) else {
+            return Ok(None);
+        };
+
+        let (adt_uri, position, local) = {
+            let doc = doc.lock().unwrap();
+            if doc.identifier_at(&position).is_none() {
+                return Ok(None);
+            }
+            let local = doc.local_references(&position, include_declaration);
+            (doc.adt_uri().to_owned(), doc.to_utf16(position), local)
+        };
+        let (scope, mut locations) = match local {
+            Some((scope, references)) => (
+                Some(scope),
+                references
+                    .into_iter()
+                    .map(|range| Location::new(uri.clone(), range))
+                    .collect(),
+            ),
+            None => (None, vec![]),
+        };
+
+        let usages = references::usage_locations(
+            &ctx.adt_client,
+            &adt_uri,
+            position.line + 1,
+            position.character,
+        )
+        .await;
+        match usages {
+            Ok(usages) => {
+                for usage in usages {
+                    let Some(location) = navigation::document_location(ctx, &usage).await else {
+                        continue;
+                    };
+                    // The syntax tree is more recent than the saved source of the backend.
+                    let covered = scope.is_some_and(|scope| {
+                        location.uri == *uri
+                            && scope.start <= location.range.start
+                            && location.range.start <= scope.end
+                    });
+                    if covered {
+                        continue;
+                    }
+                    locations.push(location);
+                }
+            }
+            Err(e) => {
+                self.client
+                    .log_message(MessageType::WARNING, format!("Where-used list failed: {e}"))
+                    .await
+            }
+        }
+        Ok(Some(locations))
+    }
+
+    /// Shows a summary of a test run, e.g. `2 of 3 tests passed`
+    async fn report_unit_tests(&self, result: &RunUnitTestsResult) {
+        let methods = result.classes.iter().flat_map(|class| &class.methods);
+        let (passed, total) = methods.fold((0, 0), |(passed, total), method| {
+            (passed + usize::from(method.passed), total + 1)
+        });
+        let kind = match result.successful {
+            true => MessageType::INFO,
+            false => MessageType::ERROR,
+        };
+        self.client
+            .show_message(kind, format!("{passed} of {total} tests passed"))
+            .await;
+    }
+
+    /// Formats the document, or only the lines of the range, with the formatter chosen
+    /// in the settings.
+    async fn format(&self, uri: &Url, range: Option<Range>) -> Result<Option<Vec<TextEdit>>> {
+        let ctx = self.context()?;
+        let Some(doc) = ctx.fetch_document(uri.as_str()) else {
+            return Ok(None);
+        };
+        let settings = self.settings().formatting;
+
+        let (formatted, version) = match settings.formatter {
+            Formatter::Native => {
+                let doc = doc.lock().unwrap();
+                (doc.format(&settings), doc.version())
+            }
+            Formatter::Backend => {
+                let (content, version) = {
+                    let doc = doc.lock().unwrap();
+                    (doc.raw_content(), doc.version())
+                };
+                match formatting::pretty_print(&ctx.adt_client, &content).await {
+                    Ok(formatted) => (formatted, version),
+                    Err(e) => {
+                        self.client
+                            .log_message(
+                                MessageType::WARNING,
+                                format!("Pretty printer failed: {e}"),
+                            )
+                            .await;
+                        return Ok(None);
+                    }
+                }
+            }
+        };
+
+        let doc = doc.lock().unwrap();
+        // The document changed while the backend was formatting it.
+        if doc.version() != version {
+            return Ok(None);
+        }
+        Ok(Some(doc.formatting_edits(&formatted, range)))
+    }
 }
 
 #[tower_lsp::async_trait]
 impl LanguageServer for Backend {
-    async fn initialize(&self, _: InitializeParams) -> Result<InitializeResult> {
+    async fn initialize(&self, params: InitializeParams) -> Result<InitializeResult> {
+        let supported = params
+            .capabilities
+            .general
+            .as_ref()
+            .and_then(|g| g.position_encodings.as_deref());
+        let encoding = *self
+            .position_encoding
+            .get_or_init(|| PositionEncoding::negotiate(supported));
+        let _ = self
+            .resolves_completion_edits
+            .set(completion::resolves_text_edit(&params.capabilities));
+        if let Some(settings) = params
+            .initialization_options
+            .as_ref()
+            .and_then(Settings::from_value)
+        {
+            *self.settings.write().unwrap() = settings;
+        }
+
         Ok(InitializeResult {
             capabilities: ServerCapabilities {
-                text_document_sync: Some(TextDocumentSyncCapability::Kind(
-                    TextDocumentSyncKind::INCREMENTAL,
+                position_encoding: Some(encoding.kind()),
+                text_document_sync: Some(TextDocumentSyncCapability::Options(
+                    TextDocumentSyncOptions {
+                        open_close: Some(true),
+                        change: Some(TextDocumentSyncKind::INCREMENTAL),
+                        ..Default::default()
+                    },
                 )),
+                document_symbol_provider: Some(OneOf::Left(true)),
+                workspace_symbol_provider: Some(OneOf::Right(WorkspaceSymbolOptions {
+                    resolve_provider: Some(true),
+                    work_done_progress_options: WorkDoneProgressOptions::default(),
+                })),
+                hover_provider: Some(HoverProviderCapability::Simple(true)),
+                definition_provider: Some(OneOf::Left(true)),
+                references_provider: Some(OneOf::Left(true)),
+                signature_help_provider: Some(SignatureHelpOptions {
+                    trigger_characters: Some(vec!["(".into(), "=".into()]),
+                    retrigger_characters: Some(vec![" ".into()]),
+                    ..Default::default()
+                }),
+                completion_provider: Some(CompletionOptions {
+                    resolve_provider: Some(true),
+                    trigger_characters: Some(vec!["-".into(), ">".into(), "~".into()]),
+                    ..Default::default()
+                }),
+                document_formatting_provider: Some(OneOf::Left(true)),
+                document_range_formatting_provider: Some(OneOf::Left(true)),
+                folding_range_provider: Some(FoldingRangeProviderCapability::Simple(true)),
+                code_lens_provider: Some(CodeLensOptions {
+                    resolve_provider: Some(true),
+                }),
+                execute_command_provider: Some(ExecuteCommandOptions {
+                    commands: vec![codelens::RUN_UNIT_TESTS.into()],
+                    ..Default::default()
+                }),
                 semantic_tokens_provider: Some(
                     SemanticTokensOptions {
                         legend: SemanticTokensLegend {
@@ -55,10 +325,13 @@ impl LanguageServer for Backend {
                                 .iter()
                                 .map(|n| SemanticTokenType::new(n))
                                 .collect(),
-                            token_modifiers: vec![],
+                            token_modifiers: TokenModifier::names()
+                                .iter()
+                                .map(|n| SemanticTokenModifier::new(n))
+                                .collect(),
                         },
-                        full: Some(SemanticTokensFullOptions::Bool(true)),
-                        range: Some(false),
+                        full: Some(SemanticTokensFullOptions::Delta { delta: Some(true) }),
+                        range: Some(true),
                         work_done_progress_options: WorkDoneProgressOptions {
                             work_done_progress: Some(false),
                         },
@@ -71,8 +344,90 @@ impl LanguageServer for Backend {
         })
     }
 
+    /// Documents are loaded through `filesystem/source` before the editor opens them,
+    /// opening only starts tracking the version of the document.
+    ///
+    /// Documents that were evicted in the meantime, e.g. closed and reopened from the
+    /// editors cache, are loaded again for the object the filesystem names them after.
     async fn did_open(&self, params: DidOpenTextDocumentParams) {
-        println!("Got a did_open message!");
+        let Ok(ctx) = self.context() else { return };
+        let document = params.text_document;
+
+        let doc = match ctx.fetch_document(document.uri.as_str()) {
+            Some(doc) => doc,
+            None => match self.load_document(ctx, &document.uri, &document.text).await {
+                Some(doc) => doc,
+                None => {
+                    self.client
+                        .log_message(
+                            MessageType::WARNING,
+                            format!(
+                                "Opened {} which is not part of the filesystem.",
+                                document.uri
+                            ),
+                        )
+                        .await;
+                    return;
+                }
+            },
+        };
+        let diagnostics = {
+            let mut doc = doc.lock().unwrap();
+            doc.open(document.version, &document.text, self.position_encoding());
+            doc.diagnostics()
+        };
+        self.client
+            .publish_diagnostics(document.uri.clone(), diagnostics, Some(document.version))
+            .await;
+        self.schedule_check(document.uri, document.version);
+    }
+
+    async fn did_change(&self, params: DidChangeTextDocumentParams) {
+        let Ok(ctx) = self.context() else { return };
+        let document = params.text_document;
+
+        let Some(doc) = ctx.fetch_document(document.uri.as_str()) else {
+            return;
+        };
+        let applied = {
+            let mut doc = doc.lock().unwrap();
+            if doc.version().is_some_and(|v| v >= document.version) {
+                // Out of order or duplicate notification, the edits are already applied.
+                return;
+            }
+            doc.apply_changes(document.version, &params.content_changes)
+                .map(|_| doc.diagnostics())
+        };
+        let diagnostics = match applied {
+            Ok(diagnostics) => diagnostics,
+            Err(e) => {
+                self.client
+                    .log_message(
+                        MessageType::WARNING,
+                        format!("{} is out of sync, resynchronizing: {e}", document.uri),
+                    )
+                    .await;
+                let resynced = self
+                    .resync_document(ctx, &doc, &document.uri, document.version)
+                    .await;
+                let Some(diagnostics) = resynced else { return };
+                diagnostics
+            }
+        };
+        self.client
+            .publish_diagnostics(document.uri.clone(), diagnostics, Some(document.version))
+            .await;
+        self.schedule_check(document.uri, document.version);
+    }
+
+    /// Closed documents are evicted, unsaved changes are discarded by the editor and
+    /// the next read should reflect the state of the backend.
+    async fn did_close(&self, params: DidCloseTextDocumentParams) {
+        let Ok(ctx) = self.context() else { return };
+        ctx.evict_document(params.text_document.uri.as_str());
+        self.client
+            .publish_diagnostics(params.text_document.uri, vec![], None)
+            .await;
     }
 
     async fn initialized(&self, _: InitializedParams) {
@@ -81,54 +436,400 @@ impl LanguageServer for Backend {
             .await;
     }
 
-    async fn semantic_tokens_full(
+    async fn did_change_configuration(&self, params: DidChangeConfigurationParams) {
+        if let Some(settings) = Settings::from_value(&params.settings) {
+            *self.settings.write().unwrap() = settings;
+        }
+    }
+
+    /// Declarations within the document are shown as written, everything else is
+    /// looked up through the element info of the backend.
+    async fn hover(&self, params: HoverParams) -> Result<Option<Hover>> {
+        let ctx = self.context()?;
+        let document = params.text_document_position_params;
+        let Some(doc) = ctx.fetch_document(document.text_document.uri.as_str()) else {
+            return Ok(None);
+        };
+
+        let (adt_uri, content, range, position) = {
+            let doc = doc.lock().unwrap();
+            let Some((_, range)) = doc.identifier_at(&document.position) else {
+                return Ok(None);
+            };
+            if let Some(declaration) = doc.declaration_at(&document.position) {
+                return Ok(Some(hover::declaration_hover(&declaration, range)));
+            }
+            let position = doc.to_utf16(document.position);
+            (doc.adt_uri().to_owned(), doc.raw_content(), range, position)
+        };
+
+        let info = hover::element_info(
+            &ctx.adt_client,
+            &adt_uri,
+            &content,
+            position.line + 1,
+            position.character,
+        )
+        .await;
+        match info {
+            Ok(info) => Ok(Some(hover::element_info_hover(
+                &info,
+                ctx.adt_client.destination(),
+                range,
+            ))),
+            // Nothing is known about most positions, e.g. keywords.
+            Err(_) => Ok(None),
+        }
+    }
+
+    /// Declarations within the document are resolved locally, everything else is
+    /// resolved through the navigation of the backend, which may lead to another object.
+    async fn goto_definition(
         &self,
-        params: SemanticTokensParams,
-    ) -> Result<Option<SemanticTokensResult>> {
-        let ctx = self.context().unwrap();
-        let obj = ctx
-            .fetch_document(params.text_document.uri.as_str())
-            .unwrap();
+        params: GotoDefinitionParams,
+    ) -> Result<Option<GotoDefinitionResponse>> {
+        let ctx = self.context()?;
+        let document = params.text_document_position_params;
+        let Some(doc) = ctx.fetch_document(document.text_document.uri.as_str()) else {
+            return Ok(None);
+        };
 
-        let mut nodes = obj.lock().unwrap().semantic_tokens();
-
-        nodes.sort_by_key(|n| n.start_byte);
-
-        let mut result: Vec<tower_lsp::lsp_types::SemanticToken> = vec![];
-        let mut prev: Option<SemanticToken> = None;
-        for i in 0..nodes.len() {
-            let curr = nodes[i];
-            if let Some(prev) = prev {
-                result.push(tower_lsp::lsp_types::SemanticToken {
-                    delta_line: curr.row - prev.row,
-                    delta_start: if curr.row == prev.row {
-                        curr.column - prev.column
-                    } else {
-                        curr.column
-                    },
-                    length: curr.length,
-                    token_type: curr.token_type.index(),
-                    token_modifiers_bitset: 0,
-                })
-            } else {
-                result.push(tower_lsp::lsp_types::SemanticToken {
-                    delta_line: curr.row,
-                    delta_start: curr.column,
-                    length: curr.length,
-                    token_type: curr.token_type.index(),
-                    token_modifiers_bitset: 0,
-                })
+        let (adt_uri, content, position) = {
+            let doc = doc.lock().unwrap();
+            if let Some(declaration) = doc.declaration_at(&document.position) {
+                let range = doc.byte_range(declaration.start_byte, declaration.end_byte);
+                return Ok(Some(GotoDefinitionResponse::Scalar(Location::new(
+                    document.text_document.uri,
+                    range,
+                ))));
+            }
+            let position = doc.to_utf16(document.position);
+            (doc.adt_uri().to_owned(), doc.raw_content(), position)
+        };
+
+        let Ok(target) = navigation::definition_target(
+            &ctx.adt_client,
+            &adt_uri,
+            &content,
+            position.line + 1,
+            position.character,
+        )
+        .await
+        else {
+            return Ok(None);
+        };
+
+        let location = navigation::document_location(ctx, &target).await;
+        Ok(location.map(GotoDefinitionResponse::Scalar))
+    }
+
+    /// Usages within the document are found in its syntax tree if the symbol is declared
+    /// in it, usages within other objects through the where-used list of the backend.
+    async fn references(&self, params: ReferenceParams) -> Result<Option<Vec<Location>>> {
+        let document = params.text_document_position;
+        self.find_references(
+            &document.text_document.uri,
+            document.position,
+            params.context.include_declaration,
+        )
+        .await
+    }
+
+    /// Lenses to run the tests are resolved right away, those counting the references
+    /// of methods and forms once the editor shows them, see [`Self::code_lens_resolve`].
+    async fn code_lens(&self, params: CodeLensParams) -> Result<Option<Vec<CodeLens>>> {
+        let ctx = self.context()?;
+        let Some(doc) = ctx.fetch_document(params.text_document.uri.as_str()) else {
+            return Ok(None);
+        };
+        let lenses = doc.lock().unwrap().code_lenses();
+        Ok(Some(lenses))
+    }
+
+    async fn code_lens_resolve(&self, lens: CodeLens) -> Result<CodeLens> {
+        let Some(data) = lens
+            .data
+            .clone()
+            .and_then(|data| serde_json::from_value::<ReferencesData>(data).ok())
+        else {
+            return Ok(lens);
+        };
+        let Ok(uri) = Url::parse(&data.uri) else {
+            return Ok(lens);
+        };
+
+        let position = Position::new(data.line, data.character);
+        let count = self
+            .find_references(&uri, position, false)
+            .await?
+            .map_or(0, |locations| locations.len());
+        Ok(CodeLens {
+            // Merely informative, the editor shows lenses without a command as plain text.
+            command: Some(Command {
+                title: codelens::references_title(count),
+                command: String::new(),
+                arguments: None,
+            }),
+            ..lens
+        })
+    }
+
+    async fn execute_command(&self, params: ExecuteCommandParams) -> Result<Option<Value>> {
+        match params.command.as_str() {
+            codelens::RUN_UNIT_TESTS => {
+                let mut arguments = params.arguments.into_iter();
+                let uri = arguments
+                    .next()
+                    .and_then(|uri| serde_json::from_value::<String>(uri).ok())
+                    .ok_or_else(|| Error::invalid_params("The URI of the document is missing."))?;
+                let target = arguments
+                    .next()
+                    .and_then(|target| serde_json::from_value::<TestTarget>(target).ok());
+
+                let result = self
+                    .run_unit_tests(RunUnitTestsParams {
+                        uris: vec![uri],
+                        coverage: None,
+                        class: target.as_ref().map(|t| t.class.clone()),
+                        method: target.and_then(|t| t.method),
+                    })
+                    .await?;
+                self.report_unit_tests(&result).await;
+                Ok(serde_json::to_value(result).ok())
             }
-            prev = Some(curr);
+            command => Err(Error::invalid_params(format!("Unknown command {command}"))),
         }
+    }
+
+    /// Proposals of the backend come first, followed by the symbols declared in the
+    /// document and the keywords of the grammar.
+    async fn completion(&self, params: CompletionParams) -> Result<Option<CompletionResponse>> {
+        let ctx = self.context()?;
+        let document = params.text_document_position;
+        let uri = document.text_document.uri.to_string();
+        let Some(doc) = ctx.fetch_document(&uri) else {
+            return Ok(None);
+        };
+
+        let (adt_uri, content, position, declared) = {
+            let doc = doc.lock().unwrap();
+            (
+                doc.adt_uri().to_owned(),
+                doc.raw_content(),
+                doc.to_utf16(document.position),
+                doc.declaration_completions(&document.position),
+            )
+        };
+
+        let (line, column) = (position.line + 1, position.character);
+        let proposals =
+            match completion::proposals(&ctx.adt_client, &adt_uri, &content, line, column).await {
+                Ok(proposals) => proposals,
+                Err(e) => {
+                    self.client
+                        .log_message(MessageType::WARNING, format!("Code completion failed: {e}"))
+                        .await;
+                    Default::default()
+                }
+            };
+
+        let mut items: Vec<CompletionItem> = {
+            let doc = doc.lock().unwrap();
+            proposals
+                .proposals
+                .iter()
+                .enumerate()
+                .filter_map(|(index, proposal)| {
+                    let prefix = column.saturating_sub(proposal.prefix_length);
+                    let start = doc.from_utf16(Position::new(position.line, prefix));
+                    let range = Range::new(start, document.position);
+                    let data = ResolveData {
+                        uri: uri.clone(),
+                        line,
+                        column,
+                        pattern_key: proposal.identifier.clone(),
+                    };
+                    completion::proposal_completion(index, proposal, range, data)
+                })
+                .collect()
+        };
+        // The backend proposes declared symbols and keywords as well, but without details.
+        items.retain(|item| {
+            item.data.is_some()
+                || !declared
+                    .iter()
+                    .any(|d| d.label.eq_ignore_ascii_case(&item.label))
+        });
+        let proposed: HashSet<String> = items.iter().map(|i| i.label.to_uppercase()).collect();
+        items.extend(declared);
+        items.extend(
+            completion::keyword_completions()
+                .into_iter()
+                .filter(|keyword| !proposed.contains(&keyword.label)),
+        );
+        Ok(Some(CompletionResponse::Array(items)))
+    }
+
+    /// Retrieves the pattern to insert for proposals of the backend, e.g. the parameters
+    /// of a function module.
+    async fn completion_resolve(&self, item: CompletionItem) -> Result<CompletionItem> {
+        let Some(uri) = item
+            .data
+            .clone()
+            .and_then(|data| serde_json::from_value::<ResolveData>(data).ok())
+            .map(|data| data.uri)
+        else {
+            return Ok(item);
+        };
+        let ctx = self.context()?;
+        let Some(doc) = ctx.fetch_document(&uri) else {
+            return Ok(item);
+        };
+        let (adt_uri, content) = {
+            let doc = doc.lock().unwrap();
+            (doc.adt_uri().to_owned(), doc.raw_content())
+        };
+        let edit = self
+            .resolves_completion_edits
+            .get()
+            .copied()
+            .unwrap_or_default();
+        Ok(completion::resolve(&ctx.adt_client, &adt_uri, &content, item, edit).await)
+    }
 
-        return Ok(Some(
-            SemanticTokens {
-                result_id: None,
-                data: result,
+    /// Signatures of methods of local classes are taken from the document, all others
+    /// are resolved through the backend and cached in the context.
+    async fn signature_help(&self, params: SignatureHelpParams) -> Result<Option<SignatureHelp>> {
+        let ctx = self.context()?;
+        let document = params.text_document_position_params;
+        let Some(doc) = ctx.fetch_document(document.text_document.uri.as_str()) else {
+            return Ok(None);
+        };
+
+        let (call, adt_uri, content, callee) = {
+            let doc = doc.lock().unwrap();
+            let Some((call, local)) = doc.call_at(&document.position) else {
+                return Ok(None);
+            };
+            if let Some(local) = local {
+                return Ok(Some(signature::signature_help(&local, &call)));
             }
-            .into(),
-        ));
+            let callee = doc.utf16_position(call.callee_byte);
+            (call, doc.adt_uri().to_owned(), doc.raw_content(), callee)
+        };
+
+        let resolved = signature::resolve_signature(
+            ctx,
+            &call,
+            &adt_uri,
+            &content,
+            callee.line + 1,
+            callee.character,
+        )
+        .await;
+        Ok(resolved.map(|signature| signature::signature_help(&signature, &call)))
+    }
+
+    async fn document_symbol(
+        &self,
+        params: DocumentSymbolParams,
+    ) -> Result<Option<DocumentSymbolResponse>> {
+        let ctx = self.context()?;
+        let Some(doc) = ctx.fetch_document(params.text_document.uri.as_str()) else {
+            return Ok(None);
+        };
+        let symbols = doc.lock().unwrap().document_symbols();
+        Ok(Some(DocumentSymbolResponse::Nested(symbols)))
+    }
+
+    async fn formatting(&self, params: DocumentFormattingParams) -> Result<Option<Vec<TextEdit>>> {
+        self.format(&params.text_document.uri, None).await
+    }
+
+    async fn range_formatting(
+        &self,
+        params: DocumentRangeFormattingParams,
+    ) -> Result<Option<Vec<TextEdit>>> {
+        self.format(&params.text_document.uri, Some(params.range))
+            .await
+    }
+
+    // `workspace/symbol` is routed to `Backend::workspace_symbol` instead, see
+    // `methods::symbols::route_workspace_symbol`.
+
+    /// Locates the document of an object listed by `workspace/symbol`, see
+    /// [`navigation::object_document_uri`].
+    async fn symbol_resolve(&self, symbol: WorkspaceSymbol) -> Result<WorkspaceSymbol> {
+        let ctx = self.context()?;
+        let Some(object) = symbol
+            .data
+            .clone()
+            .and_then(|data| serde_json::from_value::<ObjectReference>(data).ok())
+        else {
+            return Ok(symbol);
+        };
+
+        let uri = navigation::object_document_uri(ctx, &object)
+            .await
+            .and_then(|uri| Url::parse(&uri).ok())
+            .ok_or_else(|| {
+                error::server_error(
+                    error::BACKEND_ERROR,
+                    format!("{} could not be located in the filesystem.", object.name),
+                )
+            })?;
+        Ok(WorkspaceSymbol {
+            location: OneOf::Left(Location::new(uri, Range::default())),
+            ..symbol
+        })
+    }
+
+    async fn folding_range(&self, params: FoldingRangeParams) -> Result<Option<Vec<FoldingRange>>> {
+        let ctx = self.context()?;
+        let Some(doc) = ctx.fetch_document(params.text_document.uri.as_str()) else {
+            return Ok(None);
+        };
+        let ranges = doc.lock().unwrap().folding_ranges();
+        Ok(Some(ranges))
+    }
+
+    async fn semantic_tokens_full(
+        &self,
+        params: SemanticTokensParams,
+    ) -> Result<Option<SemanticTokensResult>> {
+        let ctx = self.context()?;
+        let Some(doc) = ctx.fetch_document(params.text_document.uri.as_str()) else {
+            return Ok(None);
+        };
+        let tokens = doc.lock().unwrap().semantic_tokens_full();
+        Ok(Some(tokens.into()))
+    }
+
+    async fn semantic_tokens_full_delta(
+        &self,
+        params: SemanticTokensDeltaParams,
+    ) -> Result<Option<SemanticTokensFullDeltaResult>> {
+        let ctx = self.context()?;
+        let Some(doc) = ctx.fetch_document(params.text_document.uri.as_str()) else {
+            return Ok(None);
+        };
+        let delta = doc
+            .lock()
+            .unwrap()
+            .semantic_tokens_delta(&params.previous_result_id);
+        Ok(Some(delta))
+    }
+
+    async fn semantic_tokens_range(
+        &self,
+        params: SemanticTokensRangeParams,
+    ) -> Result<Option<SemanticTokensRangeResult>> {
+        let ctx = self.context()?;
+        let Some(doc) = ctx.fetch_document(params.text_document.uri.as_str()) else {
+            return Ok(None);
+        };
+        let tokens = doc.lock().unwrap().semantic_tokens_range(params.range);
+        Ok(Some(tokens.into()))
     }
 
     async fn shutdown(&self) -> Result<()> {
@@ -138,3 +839,92 @@ impl LanguageServer for Backend {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use adt_query::{
+        ClientBuilder, ConnectionParameters, HttpConnectionBuilder, auth::Credentials,
+        models::vfs::RepositoryObject,
+    };
+    use futures_util::StreamExt as _;
+    use tower_lsp::LspService;
+    use tower_lsp::lsp_types::{TextDocumentIdentifier, TextDocumentItem};
+    use vfs::nodes::{Group, GroupNode, RepositoryObjectNode};
+
+    /// A context of a system that is not reachable, so every request to it fails.
+    fn unreachable_context() -> ClientContext {
+        let params = HttpConnectionBuilder::default()
+            .hostname(Url::parse("http://127.0.0.1:1").unwrap())
+            .client("001")
+            .language("en")
+            .build()
+            .unwrap();
+        let client = ClientBuilder::default()
+            .connection_params(ConnectionParameters::Http(params))
+            .credentials(Credentials::new("DEVELOPER", "secret"))
+            .dispatcher(reqwest::Client::new())
+            .build()
+            .unwrap();
+        ClientContext::new(client, "A4H".to_owned())
+    }
+
+    fn opened(uri: &Url, version: i32, text: &str) -> DidOpenTextDocumentParams {
+        DidOpenTextDocumentParams {
+            text_document: TextDocumentItem::new(uri.clone(), "abap".into(), version, text.into()),
+        }
+    }
+
+    #[tokio::test]
+    async fn closed_document_is_loaded_again_on_open() {
+        let (service, socket) = LspService::new(Backend::new);
+        tokio::spawn(socket.for_each(|_| async {}));
+        let backend = service.inner();
+        let ctx = Arc::new(unreachable_context());
+        {
+            let mut tree = ctx.filetree.lock().await;
+            let root = tree.root();
+            let local = tree.insert(root, GroupNode::new(Group::LocalObjects));
+            tree.insert(
+                local,
+                RepositoryObjectNode {
+                    name: "ZTEST".to_owned(),
+                    object_kind: RepositoryObject::Program,
+                    adt_uri: "/sap/bc/adt/programs/programs/ztest".to_owned(),
+                },
+            );
+        }
+        backend.context.set(ctx.clone()).unwrap();
+
+        let uri = Url::parse("adt://A4H/Local%20Objects/ZTEST.prog").unwrap();
+        backend.did_open(opened(&uri, 1, "WRITE a.")).await;
+        backend
+            .did_close(DidCloseTextDocumentParams {
+                text_document: TextDocumentIdentifier::new(uri.clone()),
+            })
+            .await;
+        assert!(ctx.fetch_document(uri.as_str()).is_none());
+
+        backend.did_open(opened(&uri, 2, "WRITE b.")).await;
+        let doc = ctx
+            .fetch_document(uri.as_str())
+            .expect("document is loaded again");
+        let doc = doc.lock().unwrap();
+        assert_eq!(doc.adt_uri(), "/sap/bc/adt/programs/programs/ztest");
+        assert_eq!(doc.raw_content(), "WRITE b.");
+        assert_eq!(doc.version(), Some(2));
+    }
+
+    #[tokio::test]
+    async fn document_outside_of_filesystem_is_not_loaded() {
+        let (service, socket) = LspService::new(Backend::new);
+        tokio::spawn(socket.for_each(|_| async {}));
+        let backend = service.inner();
+        let ctx = Arc::new(unreachable_context());
+        backend.context.set(ctx.clone()).unwrap();
+
+        let uri = Url::parse("adt://A4H/Local%20Objects/ZUNKNOWN.prog").unwrap();
+        backend.did_open(opened(&uri, 1, "WRITE a.")).await;
+        assert!(ctx.fetch_document(uri.as_str()).is_none());
+    }
+}
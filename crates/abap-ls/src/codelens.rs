@@ -0,0 +1,217 @@
+use serde::{Deserialize, Serialize};
+use tower_lsp::lsp_types::{CodeLens, Command, Range};
+
+use crate::declarations::{Declaration, DeclarationKind};
+use crate::statements::{Statement, Token};
+
+/// The command of the lenses of tests, executed by the server through
+/// `workspace/executeCommand` with the URI of the document and a [`TestTarget`]
+pub const RUN_UNIT_TESTS: &str = "abap.runUnitTests";
+
+/// The tests to run, i.e. a test class or one of its methods.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TestTarget {
+    pub class: String,
+
+    /// The test method, all methods of the class are run if `None`.
+    pub method: Option<String>,
+}
+
+/// What a [`Lens`] is shown for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LensKind {
+    /// Runs the tests of a test class or method.
+    RunTests(TestTarget),
+
+    /// Counts the references of a method or form, resolved once the lens is visible.
+    References,
+}
+
+/// A lens above a declaration, located by the byte range of its name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lens {
+    pub kind: LensKind,
+    pub start_byte: usize,
+    pub end_byte: usize,
+}
+
+/// Attached to the lenses of references, so their declaration can be located on resolve.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReferencesData {
+    /// The URI of the document of the declaration.
+    pub uri: String,
+
+    /// The 0-based line of the name of the declaration, in the encoding of the editor.
+    pub line: u32,
+
+    /// The column of the name of the declaration, in the encoding of the editor.
+    pub character: u32,
+}
+
+/// The lenses of the document, i.e. to run the test classes declared `FOR TESTING`
+/// and their test methods, as well as the reference counts of methods and forms.
+pub fn lenses(statements: &[Statement], declarations: &[Declaration]) -> Vec<Lens> {
+    let mut lenses: Vec<Lens> = declarations
+        .iter()
+        .filter(|d| matches!(d.kind, DeclarationKind::Method | DeclarationKind::Form))
+        .map(|d| Lens {
+            kind: LensKind::References,
+            start_byte: d.start_byte,
+            end_byte: d.end_byte,
+        })
+        .collect();
+
+    let mut test_class: Option<String> = None;
+    for statement in statements {
+        for part in statement.parts() {
+            let word = |i: usize| {
+                part.get(i)
+                    .map(|t| t.text.to_uppercase())
+                    .unwrap_or_default()
+            };
+            match word(0).as_str() {
+                "CLASS" if word(2) == "DEFINITION" => {
+                    test_class = is_for_testing(&part).then(|| part[1].text.clone());
+                    if let Some(class) = &test_class {
+                        lenses.push(run_tests_lens(part[1], class, None));
+                    }
+                }
+                "ENDCLASS" => test_class = None,
+                "METHODS" if part.len() > 1 && is_for_testing(&part) => {
+                    if let Some(class) = &test_class {
+                        let method = part[1].text.clone();
+                        lenses.push(run_tests_lens(part[1], class, Some(method)));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    lenses.sort_by_key(|lens| lens.start_byte);
+    lenses
+}
+
+/// Turns the lens of a document into a [`CodeLens`] at the range of the declaration.
+///
+/// Lenses of references are left unresolved, counting them requires the where-used
+/// list of the backend.
+pub fn code_lens(lens: &Lens, uri: &str, range: Range) -> CodeLens {
+    match &lens.kind {
+        LensKind::RunTests(target) => {
+            let title = match target.method {
+                Some(_) => "Run test",
+                None => "Run all tests in class",
+            };
+            CodeLens {
+                range,
+                command: Some(Command {
+                    title: title.to_owned(),
+                    command: RUN_UNIT_TESTS.to_owned(),
+                    arguments: Some(vec![
+                        serde_json::Value::from(uri),
+                        serde_json::to_value(target).unwrap_or_default(),
+                    ]),
+                }),
+                data: None,
+            }
+        }
+        LensKind::References => CodeLens {
+            range,
+            command: None,
+            data: serde_json::to_value(ReferencesData {
+                uri: uri.to_owned(),
+                line: range.start.line,
+                character: range.start.character,
+            })
+            .ok(),
+        },
+    }
+}
+
+/// The title of a lens of references, e.g. `3 references`
+pub fn references_title(count: usize) -> String {
+    match count {
+        1 => "1 reference".to_owned(),
+        _ => format!("{count} references"),
+    }
+}
+
+fn run_tests_lens(name: &Token, class: &str, method: Option<String>) -> Lens {
+    Lens {
+        kind: LensKind::RunTests(TestTarget {
+            class: class.to_owned(),
+            method,
+        }),
+        start_byte: name.start_byte,
+        end_byte: name.end_byte,
+    }
+}
+
+/// Whether the statement declares a test class or method, e.g.
+/// `CLASS ltcl_order DEFINITION FOR TESTING RISK LEVEL HARMLESS.`
+fn is_for_testing(part: &[&Token]) -> bool {
+    part.windows(2)
+        .any(|pair| pair[0].is("FOR") && pair[1].is("TESTING"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::statements::{lex, statements};
+
+    fn test_targets(source: &str) -> Vec<TestTarget> {
+        lenses(&statements(&lex(source)), &[])
+            .into_iter()
+            .filter_map(|lens| match lens.kind {
+                LensKind::RunTests(target) => Some(target),
+                LensKind::References => None,
+            })
+            .collect()
+    }
+
+    fn target(class: &str, method: Option<&str>) -> TestTarget {
+        TestTarget {
+            class: class.to_owned(),
+            method: method.map(str::to_owned),
+        }
+    }
+
+    #[test]
+    fn test_classes_and_methods_get_lenses() {
+        let source = r#"CLASS ltcl_order DEFINITION FINAL FOR TESTING
+  DURATION SHORT RISK LEVEL HARMLESS.
+  PRIVATE SECTION.
+    DATA cut TYPE REF TO zcl_order.
+    METHODS setup.
+    METHODS creates_order FOR TESTING RAISING cx_static_check.
+    METHODS: rejects_empty FOR TESTING,
+      helper.
+ENDCLASS."#;
+
+        assert_eq!(
+            test_targets(source),
+            vec![
+                target("ltcl_order", None),
+                target("ltcl_order", Some("creates_order")),
+                target("ltcl_order", Some("rejects_empty")),
+            ]
+        );
+    }
+
+    #[test]
+    fn methods_of_productive_classes_get_no_lenses() {
+        let source = r#"CLASS lcl_helper DEFINITION.
+  PUBLIC SECTION.
+    METHODS run FOR TESTING.
+ENDCLASS.
+
+CLASS lcl_helper IMPLEMENTATION.
+  METHOD run.
+  ENDMETHOD.
+ENDCLASS."#;
+
+        assert!(test_targets(source).is_empty());
+    }
+}
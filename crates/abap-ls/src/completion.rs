@@ -0,0 +1,263 @@
+use std::collections::HashSet;
+use std::sync::LazyLock;
+
+use adt_query::{
+    api::abapsource::{CodeCompletionInsertionBuilder, CodeCompletionProposalsBuilder},
+    dispatch::StatelessDispatch,
+    error::OperationError,
+    models::abapsource::{CompletionProposal, CompletionProposals},
+};
+use serde::{Deserialize, Serialize};
+use tower_lsp::lsp_types::{
+    ClientCapabilities, CompletionItem, CompletionItemKind, CompletionTextEdit, Documentation,
+    InsertTextFormat, MarkupContent, MarkupKind, Range, TextEdit,
+};
+
+use crate::context::AdtClient;
+use crate::declarations::{Declaration, DeclarationKind};
+use crate::navigation;
+
+/// The keywords of the grammar, i.e. its anonymous nodes that are words like `DATA`
+/// or `SELECT-OPTIONS`, in upper case.
+pub static KEYWORDS: LazyLock<Vec<String>> = LazyLock::new(|| {
+    let language: tree_sitter::Language = tree_sitter_abap::LANGUAGE.into();
+    let mut keywords: Vec<String> = (0..language.node_kind_count() as u16)
+        .filter(|id| !language.node_kind_is_named(*id) && language.node_kind_is_visible(*id))
+        .filter_map(|id| language.node_kind_for_id(id))
+        .filter(|kind| {
+            kind.len() > 1
+                && kind.starts_with(|c: char| c.is_ascii_alphabetic())
+                && kind.chars().all(|c| c.is_ascii_alphabetic() || c == '-')
+        })
+        .map(str::to_uppercase)
+        .collect();
+    keywords.sort();
+    keywords.dedup();
+    keywords
+});
+
+/// Attached to proposals of the backend that have a pattern to insert, so it can be
+/// retrieved once the proposal is resolved.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResolveData {
+    /// The URI of the document the completion was requested in.
+    pub uri: String,
+
+    /// The 1-based line of the completion within the source.
+    pub line: u32,
+
+    /// The 0-based column (UTF-16) of the completion within the source.
+    pub column: u32,
+
+    /// The identifier of the proposal.
+    pub pattern_key: String,
+}
+
+/// Completes the keywords of the grammar.
+pub fn keyword_completions() -> Vec<CompletionItem> {
+    KEYWORDS
+        .iter()
+        .map(|keyword| CompletionItem {
+            label: keyword.clone(),
+            kind: Some(CompletionItemKind::KEYWORD),
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// Completes the symbols declared in the document that are visible at the byte offset.
+///
+/// Data is only visible once it is declared, procedures and classes may be used
+/// before their declaration.
+pub fn declaration_completions(declarations: &[Declaration], byte: usize) -> Vec<CompletionItem> {
+    let mut seen = HashSet::new();
+    declarations
+        .iter()
+        .rev()
+        .filter(|d| {
+            d.start_byte <= byte
+                || !matches!(
+                    d.kind,
+                    DeclarationKind::Data | DeclarationKind::Constant | DeclarationKind::Type
+                )
+        })
+        .filter(|d| seen.insert(d.name.to_uppercase()))
+        .map(|d| CompletionItem {
+            label: d.name.clone(),
+            kind: Some(match d.kind {
+                DeclarationKind::Data => CompletionItemKind::VARIABLE,
+                DeclarationKind::Constant => CompletionItemKind::CONSTANT,
+                DeclarationKind::Type => CompletionItemKind::TYPE_PARAMETER,
+                DeclarationKind::Method => CompletionItemKind::METHOD,
+                DeclarationKind::Event => CompletionItemKind::EVENT,
+                DeclarationKind::Form => CompletionItemKind::FUNCTION,
+                DeclarationKind::Class => CompletionItemKind::CLASS,
+                DeclarationKind::Interface => CompletionItemKind::INTERFACE,
+            }),
+            detail: Some(d.text.clone()),
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// Retrieves the proposals of the backend at a (1-based) line and (0-based) column
+/// of the source, e.g. DDIC types, class members and function modules.
+pub async fn proposals(
+    client: &AdtClient,
+    adt_uri: &str,
+    content: &str,
+    line: u32,
+    column: u32,
+) -> Result<CompletionProposals, OperationError> {
+    let operation = CodeCompletionProposalsBuilder::default()
+        .source_uri(navigation::source_uri(adt_uri))
+        .source(content)
+        .line(line)
+        .column(column)
+        .build()
+        .map_err(|_| OperationError::UninitializedField("source_uri"))?;
+
+    let response = operation.dispatch(client).await?;
+    Ok(response.take().into_body().inner())
+}
+
+/// Whether the client accepts the text edit of a completion item on resolve, otherwise
+/// proposals are inserted without their pattern, see [`resolve_insertion`].
+pub fn resolves_text_edit(capabilities: &ClientCapabilities) -> bool {
+    capabilities
+        .text_document
+        .as_ref()
+        .and_then(|t| t.completion.as_ref())
+        .and_then(|c| c.completion_item.as_ref())
+        .and_then(|c| c.resolve_support.as_ref())
+        .is_some_and(|r| r.properties.iter().any(|p| p == "textEdit"))
+}
+
+/// Converts the proposal at an index of the proposals of the backend, proposals with
+/// a pattern to insert carry the [`ResolveData`] to retrieve it on resolve.
+///
+/// The range is where the proposal is inserted, i.e. its prefix up to the position of
+/// the completion. Returns `None` for placeholders that do not complete anything.
+pub fn proposal_completion(
+    index: usize,
+    proposal: &CompletionProposal,
+    range: Range,
+    data: ResolveData,
+) -> Option<CompletionItem> {
+    if proposal.is_meta || proposal.identifier.starts_with('@') {
+        return None;
+    }
+    Some(CompletionItem {
+        label: proposal.identifier.clone(),
+        kind: Some(match proposal.has_insertion {
+            true => CompletionItemKind::FUNCTION,
+            false => CompletionItemKind::REFERENCE,
+        }),
+        // Keep the order of relevance of the backend.
+        sort_text: Some(format!("{index:05}")),
+        text_edit: Some(CompletionTextEdit::Edit(TextEdit::new(
+            range,
+            proposal.identifier.clone(),
+        ))),
+        data: proposal
+            .has_insertion
+            .then(|| serde_json::to_value(data).ok())
+            .flatten(),
+        ..Default::default()
+    })
+}
+
+/// Retrieves the full pattern to insert for a proposal, e.g. a `CALL FUNCTION`
+/// statement including its parameters.
+pub async fn insertion(
+    client: &AdtClient,
+    adt_uri: &str,
+    content: &str,
+    data: &ResolveData,
+) -> Result<String, OperationError> {
+    let operation = CodeCompletionInsertionBuilder::default()
+        .source_uri(navigation::source_uri(adt_uri))
+        .source(content)
+        .line(data.line)
+        .column(data.column)
+        .pattern_key(data.pattern_key.as_str())
+        .build()
+        .map_err(|_| OperationError::UninitializedField("source_uri"))?;
+
+    let response = operation.dispatch(client).await?;
+    Ok(response.take().into_body().inner().into_owned())
+}
+
+/// Resolves the pattern to insert of an item with [`ResolveData`], items without
+/// are returned as is.
+pub async fn resolve(
+    client: &AdtClient,
+    adt_uri: &str,
+    content: &str,
+    mut item: CompletionItem,
+    edit: bool,
+) -> CompletionItem {
+    let Some(data) = item
+        .data
+        .take()
+        .and_then(|data| serde_json::from_value::<ResolveData>(data).ok())
+    else {
+        return item;
+    };
+    match insertion(client, adt_uri, content, &data).await {
+        Ok(pattern) => resolve_insertion(item, pattern, edit),
+        Err(_) => item,
+    }
+}
+
+/// Completes the item with the pattern to insert as its documentation, which also
+/// replaces the text of its edit if the client accepts the edit on resolve.
+pub fn resolve_insertion(mut item: CompletionItem, pattern: String, edit: bool) -> CompletionItem {
+    if pattern.trim().is_empty() {
+        return item;
+    }
+    item.documentation = Some(Documentation::MarkupContent(MarkupContent {
+        kind: MarkupKind::Markdown,
+        value: format!("```abap\n{pattern}\n```"),
+    }));
+    if !edit {
+        return item;
+    }
+    item.insert_text_format = Some(InsertTextFormat::PLAIN_TEXT);
+    match &mut item.text_edit {
+        Some(CompletionTextEdit::Edit(edit)) => edit.new_text = pattern,
+        _ => item.insert_text = Some(pattern),
+    }
+    item
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tower_lsp::lsp_types::Position;
+
+    #[test]
+    fn insertion_replaces_the_prefix_of_the_proposal() {
+        let range = Range::new(Position::new(1, 5), Position::new(1, 8));
+        let item = CompletionItem {
+            label: "RFC_PING".into(),
+            text_edit: Some(CompletionTextEdit::Edit(TextEdit::new(
+                range,
+                "RFC_PING".into(),
+            ))),
+            ..Default::default()
+        };
+
+        let pattern = "CALL FUNCTION 'RFC_PING'.".to_string();
+        let unresolved = resolve_insertion(item.clone(), pattern.clone(), false);
+        assert_eq!(unresolved.text_edit, item.text_edit);
+        assert!(unresolved.documentation.is_some());
+
+        let item = resolve_insertion(item, pattern.clone(), true);
+        assert_eq!(
+            item.text_edit,
+            Some(CompletionTextEdit::Edit(TextEdit::new(range, pattern)))
+        );
+        assert!(item.insert_text.is_none());
+    }
+}
@@ -1,3 +1,4 @@
+use adt_query::api::object::SourceCodeObject;
 use std::collections::HashMap;
 use std::sync::{Arc, LazyLock, Mutex as SyncMutex};
 use std::time::Duration;
@@ -6,6 +7,8 @@ use tokio::time::{self, Instant};
 use vfs::tree::VirtualFileTree;
 
 use crate::document::SourceCodeDocument;
+use crate::navigation;
+use crate::signature::FunctionSignature;
 
 pub type AdtClient = adt_query::Client<reqwest::Client>;
 
@@ -21,6 +24,10 @@ pub struct ClientContext {
     pub filetree: AsyncMutex<VirtualFileTree>,
 
     pub documents: SyncMutex<HashMap<String, Arc<SyncMutex<SourceCodeDocument>>>>,
+
+    /// Signatures of function modules and methods for the signature help, cached for
+    /// as long as the context lives since they rarely change.
+    pub signatures: SyncMutex<HashMap<String, Arc<FunctionSignature>>>,
 }
 
 impl ClientContext {
@@ -30,6 +37,7 @@ impl ClientContext {
             adt_client,
             filetree: AsyncMutex::new(VirtualFileTree::new(system)),
             documents: SyncMutex::new(HashMap::new()),
+            signatures: SyncMutex::new(HashMap::new()),
         }
     }
 
@@ -47,6 +55,56 @@ impl ClientContext {
     pub fn fetch_document(&self, vfs_uri: &str) -> Option<Arc<SyncMutex<SourceCodeDocument>>> {
         self.documents.lock().unwrap().get(vfs_uri).cloned()
     }
+
+    /// Removes the document from the context, e.g. once it was closed in the editor.
+    pub fn evict_document(&self, vfs_uri: &str) -> Option<Arc<SyncMutex<SourceCodeDocument>>> {
+        self.documents.lock().unwrap().remove(vfs_uri)
+    }
+
+    pub fn cached_signature(&self, key: &str) -> Option<Arc<FunctionSignature>> {
+        self.signatures.lock().unwrap().get(key).cloned()
+    }
+
+    pub fn cache_signature(
+        &self,
+        key: String,
+        signature: FunctionSignature,
+    ) -> Arc<FunctionSignature> {
+        let signature = Arc::new(signature);
+        self.signatures
+            .lock()
+            .unwrap()
+            .insert(key, signature.clone());
+        signature
+    }
+
+    /// Finds the document whose source a uri of the ADT backend refers to, e.g. the
+    /// location of a message `/sap/bc/adt/programs/programs/ztest/source/main`.
+    ///
+    /// Includes of classes are not necessarily open themselves, if the class is open
+    /// the uri of the include document is returned, see [`navigation::include_document_uri`].
+    ///
+    /// Returns the VFS uri of the document if found.
+    pub fn find_document_by_adt_uri(&self, adt_uri: &str) -> Option<String> {
+        let documents = self.documents.lock().unwrap();
+        let find = |adt_uri: &str| {
+            documents
+                .iter()
+                .find(|(_, doc)| {
+                    let doc = doc.lock().unwrap();
+                    adt_uri == doc.adt_uri() || adt_uri == navigation::source_uri(doc.adt_uri())
+                })
+                .map(|(uri, _)| uri.clone())
+        };
+        if let Some(uri) = find(adt_uri) {
+            return Some(uri);
+        }
+        match SourceCodeObject::from_object_uri(adt_uri)? {
+            SourceCodeObject::ClassInclude(_, include) => find(navigation::object_uri(adt_uri))
+                .map(|class| navigation::include_document_uri(&class, include)),
+            _ => None,
+        }
+    }
 }
 
 /// Global static context store across all connections, maintains each client
@@ -0,0 +1,344 @@
+use crate::statements::{Statement, Token, TokenKind};
+
+/// What is declared by a [`Declaration`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeclarationKind {
+    /// `DATA`, `CLASS-DATA`, `STATICS`, `FIELD-SYMBOLS`, `PARAMETERS`, `SELECT-OPTIONS`
+    /// as well as inline declarations like `DATA(lv_x)`
+    Data,
+    Constant,
+    Type,
+    Method,
+    Event,
+    Form,
+    Class,
+    Interface,
+}
+
+/// A declaration within a source, resolved from its statements.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Declaration {
+    pub kind: DeclarationKind,
+
+    /// The name as written in the declaration.
+    pub name: String,
+
+    /// The declaring statement, e.g `DATA lv_x TYPE i.` also if it is part of a chain.
+    pub text: String,
+
+    /// The byte range of the name within the source.
+    pub start_byte: usize,
+    pub end_byte: usize,
+}
+
+/// Collects the declarations of the statements in order of their appearance.
+pub fn declarations(statements: &[Statement]) -> Vec<Declaration> {
+    let mut declarations = vec![];
+    for statement in statements {
+        for part in statement.parts() {
+            inline_declarations(&part, &mut declarations);
+
+            let words: Vec<String> = part.iter().map(|t| t.text.to_uppercase()).collect();
+            let word = |i: usize| words.get(i).map(String::as_str).unwrap_or_default();
+            let Some(first) = words.first() else { continue };
+
+            let (kind, name) = match first.as_str() {
+                "DATA" | "CLASS-DATA" | "STATICS" | "FIELD-SYMBOLS" | "PARAMETERS"
+                | "PARAMETER" | "SELECT-OPTIONS" | "RANGES" | "CONSTANTS" | "TYPES" => {
+                    let kind = match first.as_str() {
+                        "CONSTANTS" => DeclarationKind::Constant,
+                        "TYPES" => DeclarationKind::Type,
+                        _ => DeclarationKind::Data,
+                    };
+                    match (word(1), word(2), word(3)) {
+                        ("BEGIN", "OF", "ENUM") => (kind, 4),
+                        ("BEGIN", "OF", _) => (kind, 3),
+                        ("END", "OF", _) => continue,
+                        _ => (kind, 1),
+                    }
+                }
+                "METHODS" | "CLASS-METHODS" => (DeclarationKind::Method, 1),
+                "EVENTS" | "CLASS-EVENTS" => (DeclarationKind::Event, 1),
+                "FORM" => (DeclarationKind::Form, 1),
+                "CLASS" if word(2) == "DEFINITION" && !words.iter().any(|w| w == "DEFERRED") => {
+                    (DeclarationKind::Class, 1)
+                }
+                "INTERFACE" if !matches!(word(2), "DEFERRED" | "LOAD") => {
+                    (DeclarationKind::Interface, 1)
+                }
+                _ => continue,
+            };
+
+            let Some(token) = part.get(name) else {
+                continue;
+            };
+            // Strip the length of declarations like `DATA text(10) TYPE c.`
+            let name = token.text.split('(').next().unwrap_or_default();
+            if name.is_empty() {
+                continue;
+            }
+            declarations.push(Declaration {
+                kind,
+                name: name.to_owned(),
+                text: statement_text(&part),
+                start_byte: token.start_byte,
+                end_byte: token.start_byte + name.len(),
+            });
+        }
+    }
+    declarations
+}
+
+/// Collects the inline declarations of a statement, e.g. `DATA(lv_x) = 1.`
+fn inline_declarations(part: &[&Token], declarations: &mut Vec<Declaration>) {
+    for token in part.iter().filter(|t| t.kind == TokenKind::Word) {
+        let upper = token.text.to_uppercase();
+        let Some(offset) = ["DATA(", "FINAL(", "FIELD-SYMBOL("]
+            .iter()
+            .find(|prefix| upper.starts_with(*prefix))
+            .map(|prefix| prefix.len())
+        else {
+            continue;
+        };
+        let Some(name) = token.text[offset..]
+            .split(')')
+            .next()
+            .filter(|n| !n.is_empty())
+        else {
+            continue;
+        };
+        let start_byte = token.start_byte + offset;
+        declarations.push(Declaration {
+            kind: DeclarationKind::Data,
+            name: name.to_owned(),
+            text: statement_text(part),
+            start_byte,
+            end_byte: start_byte + name.len(),
+        });
+    }
+}
+
+/// Finds the declaration of a name that is visible at the byte offset.
+///
+/// The closest declaration preceding the offset takes precedence, locals shadow
+/// globals that way. Otherwise the first declaration is used, e.g. for methods that
+/// are implemented before the `FORM` is declared.
+pub fn find<'a>(
+    declarations: &'a [Declaration],
+    name: &str,
+    byte: usize,
+) -> Option<&'a Declaration> {
+    let mut matching = declarations
+        .iter()
+        .filter(|d| d.name.eq_ignore_ascii_case(name));
+    matching
+        .clone()
+        .rfind(|d| d.start_byte <= byte)
+        .or_else(|| matching.next())
+}
+
+/// The identifier at the byte offset, along with its byte range.
+///
+/// Only the segment of compound tokens under the offset is returned, e.g. `ls_data`
+/// of `ls_data-field` or `method` of `lo_obj->method(`.
+pub fn identifier_at(tokens: &[Token], byte: usize) -> Option<(String, usize, usize)> {
+    let token = tokens
+        .iter()
+        .find(|t| t.kind == TokenKind::Word && t.start_byte <= byte && byte <= t.end_byte)?;
+
+    let offset = byte - token.start_byte;
+    let (start, end) = identifiers(&token.text).find(|(s, e)| *s <= offset && offset <= *e)?;
+    Some((
+        token.text[start..end].to_owned(),
+        token.start_byte + start,
+        token.start_byte + end,
+    ))
+}
+
+/// Procedures whose declarations are local to them, by their opening and closing keyword.
+const PROCEDURES: &[(&str, &str)] = &[
+    ("METHOD", "ENDMETHOD"),
+    ("FORM", "ENDFORM"),
+    ("FUNCTION", "ENDFUNCTION"),
+    ("MODULE", "ENDMODULE"),
+];
+
+/// The byte ranges of the procedures of the statements, e.g. of the implementation
+/// of a method from `METHOD` to `ENDMETHOD`.
+pub fn procedures(statements: &[Statement]) -> Vec<(usize, usize)> {
+    let mut procedures = vec![];
+    let mut open: Option<(&str, usize)> = None;
+    for statement in statements {
+        let Some(keyword) = statement.keyword() else {
+            continue;
+        };
+        match open {
+            Some((end, start)) if keyword == end => {
+                procedures.push((start, statement.end_byte));
+                open = None;
+            }
+            None => {
+                open = PROCEDURES
+                    .iter()
+                    .find(|(k, _)| *k == keyword)
+                    .map(|(_, end)| (*end, statement.start_byte));
+            }
+            _ => {}
+        }
+    }
+    procedures
+}
+
+/// The byte range the declaration is visible in, `None` if it is visible in the whole
+/// source. Data, constants and types declared in a procedure are local to it.
+pub fn scope(procedures: &[(usize, usize)], declaration: &Declaration) -> Option<(usize, usize)> {
+    match declaration.kind {
+        DeclarationKind::Data | DeclarationKind::Constant | DeclarationKind::Type => procedures
+            .iter()
+            .find(|(start, end)| *start <= declaration.start_byte && declaration.start_byte < *end)
+            .copied(),
+        _ => None,
+    }
+}
+
+/// The byte ranges of the identifiers that refer to the declaration, i.e. those named
+/// like it within its [`scope`].
+///
+/// Procedures that declare the name again are left out of the scope of global
+/// declarations. Components like `field` of `ls_data-field` or `attr` of `lo_obj->attr`
+/// are not references, except for those of the own class like `me->attr`.
+pub fn references(
+    tokens: &[Token],
+    statements: &[Statement],
+    declarations: &[Declaration],
+    declaration: &Declaration,
+) -> Vec<(usize, usize)> {
+    let procedures = procedures(statements);
+    let scope = scope(&procedures, declaration);
+    let shadowed: Vec<(usize, usize)> = match scope {
+        Some(_) => vec![],
+        None => declarations
+            .iter()
+            .filter(|d| d.name.eq_ignore_ascii_case(&declaration.name) && *d != declaration)
+            .filter_map(|d| self::scope(&procedures, d))
+            .collect(),
+    };
+    let in_scope = |byte: usize| match scope {
+        Some((start, end)) => start <= byte && byte < end,
+        None => !shadowed
+            .iter()
+            .any(|(start, end)| *start <= byte && byte < *end),
+    };
+
+    tokens
+        .iter()
+        .filter(|t| t.kind == TokenKind::Word)
+        .flat_map(|token| {
+            identifiers(&token.text)
+                .filter(|(start, end)| {
+                    token.text[*start..*end].eq_ignore_ascii_case(&declaration.name)
+                        && !is_component(&token.text[..*start])
+                })
+                .map(|(start, end)| (token.start_byte + start, token.start_byte + end))
+        })
+        .filter(|(start, _)| in_scope(*start))
+        .collect()
+}
+
+/// Whether the identifier following the text is a component of something else, e.g.
+/// the text is `ls_data-`, `lo_obj->` or `zcl_class=>`
+fn is_component(text: &str) -> bool {
+    let Some(owner) = text
+        .strip_suffix("->")
+        .or_else(|| text.strip_suffix("=>"))
+        .or_else(|| text.strip_suffix('-'))
+    else {
+        return false;
+    };
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_' || c == '/';
+    // Negative numbers and the like, e.g. `-lv_x`
+    if !owner.ends_with(|c: char| is_ident(c) || matches!(c, '>' | ')' | ']')) {
+        return false;
+    }
+    let name = owner
+        .rsplit(|c: char| !is_ident(c))
+        .next()
+        .unwrap_or_default();
+    !(text.ends_with("->") && name.eq_ignore_ascii_case("me"))
+}
+
+/// The byte ranges of the identifiers within the text of a token, e.g. `lo_obj` and
+/// `method` of `lo_obj->method(`.
+///
+/// Field symbols are named including their angle brackets.
+fn identifiers(text: &str) -> impl Iterator<Item = (usize, usize)> + '_ {
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_' || c == '/';
+
+    let mut ranges = vec![];
+    let mut start = None;
+    for (i, c) in text.char_indices().chain([(text.len(), ' ')]) {
+        match (start, is_ident(c)) {
+            (None, true) => start = Some(i),
+            (Some(s), false) => {
+                ranges.push((s, i));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    ranges.into_iter().map(|(start, end)| {
+        match (text[..start].ends_with('<'), text[end..].starts_with('>')) {
+            (true, true) => (start - 1, end + 1),
+            _ => (start, end),
+        }
+    })
+}
+
+/// The statement as it would be written on its own, e.g. the part of a chain.
+fn statement_text(part: &[&Token]) -> String {
+    let text = part
+        .iter()
+        .map(|t| t.text.as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
+    format!("{text}.")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::statements::{lex, statements};
+
+    #[test]
+    fn references_are_limited_to_the_scope_of_the_declaration() {
+        let source = "DATA lv_count TYPE i.
+METHOD run.
+  DATA lv_count TYPE i.
+  lv_count = ls_data-lv_count + lo_obj->lv_count.
+  me->lv_count = -lv_count.
+ENDMETHOD.
+FORM report.
+  WRITE lv_count.
+ENDFORM.";
+        let tokens = lex(source);
+        let statements = statements(&tokens);
+        let declarations = declarations(&statements);
+        let text = |(start, end): (usize, usize)| &source[start..end];
+        let line = |(start, _): (usize, usize)| source[..start].lines().count();
+
+        let global = &declarations[0];
+        let lines: Vec<_> = references(&tokens, &statements, &declarations, global)
+            .into_iter()
+            .inspect(|r| assert_eq!(text(*r), "lv_count"))
+            .map(line)
+            .collect();
+        assert_eq!(lines, vec![1, 8]);
+
+        let local = &declarations[1];
+        let lines: Vec<_> = references(&tokens, &statements, &declarations, local)
+            .into_iter()
+            .map(line)
+            .collect();
+        assert_eq!(lines, vec![3, 4, 5, 5]);
+    }
+}
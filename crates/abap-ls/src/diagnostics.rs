@@ -0,0 +1,260 @@
+use adt_query::{
+    api::checkruns::RunCheckBuilder,
+    dispatch::StatelessDispatch,
+    error::OperationError,
+    models::{
+        atc::Finding,
+        checkrun::{Artifact, Message, ObjectBuilder, ObjectListBuilder},
+    },
+};
+use ropey::Rope;
+use tower_lsp::lsp_types::{Diagnostic, DiagnosticSeverity, NumberOrString, Position, Range};
+use tree_sitter::{Node, Tree};
+
+use crate::context::AdtClient;
+use crate::document::{PositionEncoding, byte_offset_to_position, position_to_byte_offset};
+use crate::navigation;
+
+/// The source of diagnostics derived from the concrete syntax tree.
+pub const SYNTAX_SOURCE: &str = "abap-ls";
+
+/// The source of diagnostics reported by the check runs of the backend.
+pub const CHECK_SOURCE: &str = "ADT";
+
+/// The source of diagnostics reported by runs of the ABAP Test Cockpit.
+pub const ATC_SOURCE: &str = "ATC";
+
+/// The reporter of the check run that performs the syntax check.
+const CHECK_REPORTER: &str = "abapCheckRun";
+
+/// The maximum length of a code snippet that is quoted in a message.
+const MAX_SNIPPET_LENGTH: usize = 30;
+
+/// Collects diagnostics for all `ERROR` and `MISSING` nodes of the tree.
+///
+/// Only the outermost error is reported, errors nested within an `ERROR` node
+/// are typically follow-up errors of the same mistake and would only add noise.
+pub fn syntax_diagnostics(tree: &Tree, rope: &Rope, encoding: PositionEncoding) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+    if !tree.root_node().has_error() {
+        return diagnostics;
+    }
+
+    let mut cursor = tree.walk();
+    loop {
+        let node = cursor.node();
+        let message = if node.is_missing() {
+            Some(missing_message(&node))
+        } else if node.is_error() {
+            Some(error_message(&node, rope))
+        } else {
+            None
+        };
+
+        if let Some(message) = message {
+            diagnostics.push(Diagnostic {
+                range: Range::new(
+                    byte_offset_to_position(rope, node.start_byte(), encoding),
+                    byte_offset_to_position(rope, node.end_byte(), encoding),
+                ),
+                severity: Some(DiagnosticSeverity::ERROR),
+                source: Some(SYNTAX_SOURCE.into()),
+                message,
+                ..Default::default()
+            });
+        }
+
+        // Only descend into subtrees that contain errors and were not reported yet.
+        let reported = node.is_error() || node.is_missing();
+        if node.has_error() && !reported && cursor.goto_first_child() {
+            continue;
+        }
+        while !cursor.goto_next_sibling() {
+            if !cursor.goto_parent() {
+                return diagnostics;
+            }
+        }
+    }
+}
+
+fn missing_message(node: &Node) -> String {
+    match node.kind() {
+        "." => "Missing period `.` at the end of the statement".into(),
+        ")" => "Missing closing parenthesis `)`".into(),
+        "]" => "Missing closing bracket `]`".into(),
+        "}" => "Missing closing brace `}`".into(),
+        kind if node.is_named() => format!("Missing {}", kind.replace('_', " ")),
+        kind => format!("Missing `{kind}`"),
+    }
+}
+
+fn error_message(node: &Node, rope: &Rope) -> String {
+    let text = rope
+        .byte_slice(node.start_byte()..node.end_byte())
+        .to_string();
+    let text = text.trim();
+
+    if let Some(delimiter) = unterminated_literal(text) {
+        return match delimiter {
+            '|' => "Unterminated string template, expected a closing `|`".into(),
+            c => format!("Unterminated string literal, expected a closing `{c}`"),
+        };
+    }
+
+    let snippet: String = text
+        .split_whitespace()
+        .next()
+        .unwrap_or_default()
+        .chars()
+        .take(MAX_SNIPPET_LENGTH)
+        .collect();
+    if snippet.is_empty() {
+        "Syntax error".into()
+    } else {
+        format!("Syntax error, unexpected `{snippet}`")
+    }
+}
+
+/// Returns the delimiter of a literal that is opened but never closed on its line.
+fn unterminated_literal(text: &str) -> Option<char> {
+    for line in text.lines() {
+        let mut open: Option<char> = None;
+        for c in line.chars() {
+            match (open, c) {
+                (None, '"') => break,
+                (None, '\'' | '`' | '|') => open = Some(c),
+                (Some(delimiter), c) if c == delimiter => open = None,
+                _ => {}
+            }
+        }
+        if open.is_some() {
+            return open;
+        }
+    }
+    None
+}
+
+/// Runs the syntax check of the backend on the (unsaved) content of an object.
+///
+/// The content is sent as an artifact of the check run, so the messages reflect what
+/// is in the editor rather than what is saved in the system. Only messages that refer
+/// to the source of the object itself are returned.
+pub async fn check_run_diagnostics(
+    client: &AdtClient,
+    adt_uri: &str,
+    content: &str,
+    encoding: PositionEncoding,
+) -> Result<Vec<Diagnostic>, OperationError> {
+    let source_uri = navigation::source_uri(adt_uri);
+
+    let objects = ObjectListBuilder::default()
+        .object(
+            ObjectBuilder::default()
+                .object_uri(navigation::object_uri(adt_uri))
+                .version("active")
+                .artifacts(Artifact::plain_text(&source_uri, content).into())
+                .build()
+                .map_err(|_| OperationError::UninitializedField("object_uri"))?,
+        )
+        .build()
+        .map_err(|_| OperationError::UninitializedField("objects"))?;
+
+    let operation = RunCheckBuilder::default()
+        .objects(objects)
+        .reporter(CHECK_REPORTER)
+        .build()
+        .map_err(|_| OperationError::UninitializedField("objects"))?;
+
+    let response = operation.dispatch(client).await?;
+    let rope = Rope::from_str(content);
+
+    Ok(response
+        .body()
+        .reports
+        .iter()
+        .filter_map(|r| r.messages.as_ref())
+        .flat_map(|m| &m.messages)
+        .filter(|m| m.location().uri == source_uri)
+        .map(|m| check_message_diagnostic(m, &rope, encoding))
+        .collect())
+}
+
+fn check_message_diagnostic(
+    message: &Message,
+    rope: &Rope,
+    encoding: PositionEncoding,
+) -> Diagnostic {
+    let location = message.location();
+
+    // Lines of the backend are 1-based, columns are 0-based UTF-16 code units.
+    let to_position = |(line, column): (u32, u32)| {
+        encoding.from_utf16(rope, Position::new(line.saturating_sub(1), column))
+    };
+    let range = match (location.start, location.end) {
+        (Some(start), Some(end)) => Range::new(to_position(start), to_position(end)),
+        (Some(start), None) => word_range(rope, to_position(start), encoding),
+        _ => Range::default(),
+    };
+
+    let severity = match message.kind.as_str() {
+        "E" => DiagnosticSeverity::ERROR,
+        "W" => DiagnosticSeverity::WARNING,
+        _ => DiagnosticSeverity::INFORMATION,
+    };
+
+    Diagnostic {
+        range,
+        severity: Some(severity),
+        source: Some(CHECK_SOURCE.into()),
+        message: message.text.clone(),
+        ..Default::default()
+    }
+}
+
+/// Maps a finding of the ATC to a diagnostic, the id of its check being the code.
+///
+/// Findings of priority 1 are errors, those of priority 2 warnings.
+pub fn atc_diagnostic(finding: &Finding, rope: &Rope, encoding: PositionEncoding) -> Diagnostic {
+    let range = match finding.source_location().start {
+        Some((line, column)) => {
+            let start = encoding.from_utf16(rope, Position::new(line.saturating_sub(1), column));
+            word_range(rope, start, encoding)
+        }
+        None => Range::default(),
+    };
+
+    let severity = match finding.priority {
+        1 => DiagnosticSeverity::ERROR,
+        2 => DiagnosticSeverity::WARNING,
+        _ => DiagnosticSeverity::INFORMATION,
+    };
+
+    Diagnostic {
+        range,
+        severity: Some(severity),
+        code: Some(NumberOrString::String(finding.check_id.clone())),
+        source: Some(ATC_SOURCE.into()),
+        message: format!("{} ({})", finding.message, finding.check_title),
+        ..Default::default()
+    }
+}
+
+/// The range of the word starting at the position, the backend only reports where
+/// a message starts but it is nicer to underline the entire token.
+fn word_range(rope: &Rope, start: Position, encoding: PositionEncoding) -> Range {
+    let Some(offset) = position_to_byte_offset(rope, &start, encoding) else {
+        return Range::new(start, start);
+    };
+    let length: usize = rope
+        .byte_slice(offset..)
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '/' | '~' | '>' | '='))
+        .map(|c| encoding.len(c))
+        .sum::<usize>()
+        .max(1);
+
+    Range::new(
+        start,
+        Position::new(start.line, start.character + length as u32),
+    )
+}
@@ -1,14 +1,34 @@
 use adt_query::{
-    api::object::ObjectSourceRequestBuilder, dispatch::StatelessDispatch as _,
+    api::{
+        classes::ClassSourceBuilder,
+        object::{ObjectSourceRequestBuilder, SourceCodeObject},
+    },
+    dispatch::StatelessDispatch as _,
+    error::OperationError,
+    models::atc::Finding,
     response::CacheControlled,
 };
 use ropey::Rope;
-use tower_lsp::lsp_types::{Position, TextDocumentContentChangeEvent};
-use tree_sitter::{InputEdit, Parser, Point, Query, QueryCursor, StreamingIterator as _, Tree};
+use tower_lsp::lsp_types::{
+    self, CodeLens, CompletionItem, Diagnostic, DocumentSymbol, FoldingRange, Position,
+    PositionEncodingKind, Range, SemanticTokens, SemanticTokensDelta,
+    SemanticTokensFullDeltaResult, TextDocumentContentChangeEvent, TextEdit,
+};
+use tree_sitter::{InputEdit, Parser, Point, QueryCursor, StreamingIterator as _, Tree};
 
 use crate::{
+    codelens, completion,
     context::AdtClient,
-    tokens::{SemanticToken, TokenModifier, TokenType},
+    declarations,
+    declarations::Declaration,
+    diagnostics, folding, formatting,
+    settings::FormattingSettings,
+    signature,
+    signature::{Call, FunctionSignature},
+    statements,
+    statements::{Statement, Token},
+    symbols, tokens,
+    tokens::{HIGHLIGHTS_QUERY, RopeProvider, SemanticToken, TokenModifier, TokenType},
 };
 
 #[derive(Debug)]
@@ -16,12 +36,30 @@ pub struct SourceCodeDocument {
     // The URI of the object in the virtual file explorer
     vfs_uri: String,
 
-    // The ADT URI of the object.
+    // The ADT URI of the object, or of the source of the class include it shows.
     adt_uri: String,
 
     rope: Rope,
 
     cst: Tree,
+
+    // The version of the document in the editor, `None` if it is not open.
+    version: Option<i32>,
+
+    // The encoding of the positions exchanged with the editor.
+    encoding: PositionEncoding,
+
+    // The diagnostics of the last check run of the backend.
+    check_diagnostics: Vec<Diagnostic>,
+
+    // The findings of the last ATC run that included the object.
+    atc_diagnostics: Vec<Diagnostic>,
+
+    // The id of the last semantic tokens result, incremented with each full result.
+    semantic_tokens_result_id: u64,
+
+    // The last full semantic tokens result that deltas are computed against.
+    last_semantic_tokens: Option<(String, Vec<lsp_types::SemanticToken>)>,
 }
 
 impl SourceCodeDocument {
@@ -29,48 +67,107 @@ impl SourceCodeDocument {
         &self.vfs_uri
     }
 
+    pub fn adt_uri(&self) -> &str {
+        &self.adt_uri
+    }
+
     pub fn raw_content(&self) -> String {
         self.rope.to_string()
     }
 
-    /// Fetches the documents source code from the ADT Backend and parses it.
-    pub async fn fetch(vfs_uri: &str, adt_uri: &str, client: &AdtClient) -> Self {
-        let req = ObjectSourceRequestBuilder::default()
-            .object_uri(adt_uri)
-            .build()
-            .unwrap();
+    /// The version of the document in the editor, `None` if it is not open.
+    pub fn version(&self) -> Option<i32> {
+        self.version
+    }
 
-        let result = req.dispatch(&client).await.unwrap();
-        match result {
-            CacheControlled::Modified(t) => {
-                let content = t.into_body().inner();
-                return Self {
-                    vfs_uri: vfs_uri.to_owned(),
-                    adt_uri: adt_uri.to_owned(),
-                    cst: load_parser().parse(&*content, None).unwrap(),
-                    rope: content.into(),
-                };
+    /// The encoding of the positions exchanged with the editor.
+    pub fn encoding(&self) -> PositionEncoding {
+        self.encoding
+    }
+
+    /// Marks the document as opened in the editor with the given content.
+    ///
+    /// The editor is the source of truth, if its content differs from what was
+    /// fetched from the backend, the content of the editor is taken over.
+    pub fn open(&mut self, version: i32, content: &str, encoding: PositionEncoding) {
+        if self.rope != content {
+            self.replace_content(content);
+            self.atc_diagnostics.clear();
+        }
+        self.version = Some(version);
+        self.encoding = encoding;
+    }
+
+    /// Applies the changes of a `textDocument/didChange` notification in order and
+    /// reparses the document once afterwards.
+    ///
+    /// If a change does not fit the content, the document is out of sync with the editor
+    /// and must be [opened](Self::open) with the content of the editor again. The changes
+    /// before it remain applied and the version is left untouched.
+    ///
+    /// Findings of the last ATC run on the edited lines are dropped, those below are
+    /// moved along with their lines. Replacing the entire content drops all of them.
+    pub fn apply_changes(
+        &mut self,
+        version: i32,
+        changes: &[TextDocumentContentChangeEvent],
+    ) -> Result<(), InvalidEdit> {
+        for change in changes {
+            match change.range {
+                Some(range) => {
+                    if let Err(e) = self.apply_client_edit(change) {
+                        self.atc_diagnostics.clear();
+                        self.reparse();
+                        return Err(e);
+                    }
+                    move_past_edit(&mut self.atc_diagnostics, range, &change.text);
+                }
+                None => {
+                    self.replace_content(&change.text);
+                    self.atc_diagnostics.clear();
+                }
             }
-            _ => unimplemented!("Caching"),
         }
+        self.reparse();
+        self.version = Some(version);
+        Ok(())
     }
 
-    /// Refreshes the document the same as the initial [fetch](Self::fetch) (no etag checks).
-    pub async fn refresh(&mut self, client: &AdtClient) {
-        let req = ObjectSourceRequestBuilder::default()
-            .object_uri(&self.adt_uri)
-            .build()
-            .unwrap();
+    /// Creates a document of an object with the given source code and parses it.
+    pub fn new(vfs_uri: &str, adt_uri: &str, content: &str) -> Self {
+        Self {
+            vfs_uri: vfs_uri.to_owned(),
+            adt_uri: adt_uri.to_owned(),
+            cst: load_parser().parse(content, None).unwrap(),
+            rope: content.into(),
+            version: None,
+            encoding: PositionEncoding::default(),
+            check_diagnostics: vec![],
+            atc_diagnostics: vec![],
+            semantic_tokens_result_id: 0,
+            last_semantic_tokens: None,
+        }
+    }
 
-        let result = req.dispatch(&client).await.unwrap();
-        match result {
-            CacheControlled::Modified(t) => {
-                let content = t.into_body().inner();
-                self.cst = load_parser().parse(&*content, None).unwrap();
-                self.rope = content.into();
-            }
-            _ => unimplemented!("Caching"),
+    /// Fetches the documents source code from the ADT Backend and parses it.
+    pub async fn fetch(
+        vfs_uri: &str,
+        adt_uri: &str,
+        client: &AdtClient,
+    ) -> Result<Self, OperationError> {
+        // Without an etag the backend always responds with the source.
+        let content = fetch_source(client, adt_uri).await?.unwrap_or_default();
+        Ok(Self::new(vfs_uri, adt_uri, &content))
+    }
+
+    /// Refreshes the document the same as the initial [fetch](Self::fetch) (no etag checks),
+    /// the current content is kept if the backend responds that it was not modified.
+    pub async fn refresh(&mut self, client: &AdtClient) -> Result<(), OperationError> {
+        if let Some(content) = fetch_source(client, &self.adt_uri).await? {
+            self.cst = load_parser().parse(&content, None).unwrap();
+            self.rope = content.into();
         }
+        Ok(())
     }
 
     /// Applies a [TextDocumentContentChangeEvent] from the client to both the
@@ -81,40 +178,259 @@ impl SourceCodeDocument {
     /// all events have been handled for performance reasons.
     ///
     /// Thus you must call [reparse] after submitting all the client edits.
-    pub fn apply_client_edit(&mut self, event: &TextDocumentContentChangeEvent) -> () {
+    ///
+    /// Columns past the end of a line are clamped to it, an edit whose range starts or
+    /// ends after the last line (or ends before it starts) is rejected without touching
+    /// the document.
+    pub fn apply_client_edit(
+        &mut self,
+        event: &TextDocumentContentChangeEvent,
+    ) -> Result<(), InvalidEdit> {
         let (start, end) = self.document_change_range(event);
 
-        let start_idx = position_to_char_index(&self.rope, &start).expect("Invalid start index");
-        let end_idx = position_to_char_index(&self.rope, &end).expect("invalid end index");
-        let start_byte = position_to_byte_offset(&self.rope, &start).expect("Invalid start byte");
-        let old_end_byte = position_to_byte_offset(&self.rope, &end).expect("Invalid end byte");
+        let encoding = self.encoding;
+        let invalid = || InvalidEdit(Range::new(start, end));
+        let start_byte =
+            position_to_byte_offset(&self.rope, &start, encoding).ok_or_else(invalid)?;
+        let old_end_byte =
+            position_to_byte_offset(&self.rope, &end, encoding).ok_or_else(invalid)?;
+        if old_end_byte < start_byte {
+            return Err(invalid());
+        }
+        let start_idx = self.rope.byte_to_char(start_byte);
+        let end_idx = self.rope.byte_to_char(old_end_byte);
         let new_end_byte = start_byte + event.text.len();
 
+        // The points must be computed before the rope is edited.
+        let start_position = byte_to_point(&self.rope, start_byte);
+        let old_end_position = byte_to_point(&self.rope, old_end_byte);
+
         // Edit the Rope BEFORE fetching the new end position, otherwise PANIC if its out of range :c
         self.rope.remove(start_idx..end_idx);
         if !event.text.is_empty() {
             self.rope.insert(start_idx, &event.text)
         }
 
-        let new_end_pos = {
-            let line = self.rope.byte_to_line(new_end_byte);
-            Point::new(line, new_end_byte - self.rope.line_to_byte(line))
-        };
+        let new_end_pos = byte_to_point(&self.rope, new_end_byte);
 
         self.cst.edit(&InputEdit {
             start_byte: start_byte,
-            start_position: position_to_point(&start),
+            start_position,
             old_end_byte,
-            old_end_position: position_to_point(&end),
+            old_end_position,
             new_end_byte: new_end_byte,
             new_end_position: new_end_pos,
         });
+        Ok(())
+    }
+
+    /// Collects the syntax errors of the concrete syntax tree, see [`diagnostics::syntax_diagnostics`].
+    pub fn syntax_diagnostics(&self) -> Vec<Diagnostic> {
+        diagnostics::syntax_diagnostics(&self.cst, &self.rope, self.encoding)
+    }
+
+    /// Replaces the diagnostics of the last check run of the backend.
+    pub fn set_check_diagnostics(&mut self, diagnostics: Vec<Diagnostic>) {
+        self.check_diagnostics = diagnostics;
+    }
+
+    /// Replaces the findings of the last ATC run, see [`diagnostics::atc_diagnostic`].
+    pub fn set_atc_findings(&mut self, findings: &[&Finding]) {
+        self.atc_diagnostics = findings
+            .iter()
+            .map(|finding| diagnostics::atc_diagnostic(finding, &self.rope, self.encoding))
+            .collect();
+    }
+
+    /// All diagnostics of the document, the local syntax errors followed by the
+    /// diagnostics of the last check run of the backend and the findings of the ATC.
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        let mut diagnostics = self.syntax_diagnostics();
+        diagnostics.extend(self.check_diagnostics.iter().cloned());
+        diagnostics.extend(self.atc_diagnostics.iter().cloned());
+        diagnostics
+    }
+
+    /// The tokens of the document, see [`statements::tokens`].
+    pub fn tokens(&self) -> Vec<Token> {
+        statements::tokens(&self.cst, RopeProvider(&self.rope))
+    }
+
+    /// The statements of the document, see [`statements::statements`].
+    pub fn statements(&self) -> Vec<Statement> {
+        statements::statements(&self.tokens())
+    }
+
+    /// The outline of the document, see [`symbols::document_symbols`].
+    pub fn document_symbols(&self) -> Vec<DocumentSymbol> {
+        symbols::document_symbols(&self.statements(), &self.rope, self.encoding)
+    }
+
+    /// The identifier at the position along with its range, see [`declarations::identifier_at`].
+    pub fn identifier_at(&self, position: &Position) -> Option<(String, Range)> {
+        let byte = position_to_byte_offset(&self.rope, position, self.encoding)?;
+        let (name, start, end) = declarations::identifier_at(&self.tokens(), byte)?;
+        Some((name, self.byte_range(start, end)))
+    }
+
+    /// The declaration of the identifier at the position, if it is declared in the document.
+    pub fn declaration_at(&self, position: &Position) -> Option<Declaration> {
+        let byte = position_to_byte_offset(&self.rope, position, self.encoding)?;
+        let (name, _, _) = declarations::identifier_at(&self.tokens(), byte)?;
+        declarations::find(&declarations::declarations(&self.statements()), &name, byte).cloned()
+    }
+
+    /// The usages of the symbol at the position within the document along with the range
+    /// they were searched in, if it is declared in the document, see
+    /// [`declarations::references`]. The declaration itself is only included if requested.
+    pub fn local_references(
+        &self,
+        position: &Position,
+        include_declaration: bool,
+    ) -> Option<(Range, Vec<Range>)> {
+        let byte = position_to_byte_offset(&self.rope, position, self.encoding)?;
+        let tokens = self.tokens();
+        let statements = statements::statements(&tokens);
+        let declarations = declarations::declarations(&statements);
+        let (name, _, _) = declarations::identifier_at(&tokens, byte)?;
+        let declaration = declarations::find(&declarations, &name, byte)?;
+
+        let procedures = declarations::procedures(&statements);
+        let (start, end) =
+            declarations::scope(&procedures, declaration).unwrap_or((0, self.rope.len_bytes()));
+        let references = declarations::references(&tokens, &statements, &declarations, declaration)
+            .into_iter()
+            .filter(|(start, _)| include_declaration || *start != declaration.start_byte)
+            .map(|(start, end)| self.byte_range(start, end))
+            .collect();
+        Some((self.byte_range(start, end), references))
+    }
+
+    /// The code lenses of the document, see [`codelens::lenses`].
+    pub fn code_lenses(&self) -> Vec<CodeLens> {
+        let statements = self.statements();
+        let declarations = declarations::declarations(&statements);
+        codelens::lenses(&statements, &declarations)
+            .iter()
+            .map(|lens| {
+                let range = self.byte_range(lens.start_byte, lens.end_byte);
+                codelens::code_lens(lens, &self.vfs_uri, range)
+            })
+            .collect()
+    }
+
+    /// The symbols declared in the document that are visible at the position.
+    pub fn declaration_completions(&self, position: &Position) -> Vec<CompletionItem> {
+        let Some(byte) = position_to_byte_offset(&self.rope, position, self.encoding) else {
+            return vec![];
+        };
+        completion::declaration_completions(&declarations::declarations(&self.statements()), byte)
+    }
+
+    /// The call being written at the position along with its signature if the callee
+    /// is declared in the document, see [`signature::local_signature`].
+    pub fn call_at(&self, position: &Position) -> Option<(Call, Option<FunctionSignature>)> {
+        let byte = position_to_byte_offset(&self.rope, position, self.encoding)?;
+        let statements = self.statements();
+        let call = signature::call_at(&statements, byte)?;
+        let local = signature::local_signature(&statements, &call);
+        Some((call, local))
+    }
+
+    /// The position of a byte offset in UTF-16 code units, as used by the backend.
+    pub fn utf16_position(&self, byte: usize) -> Position {
+        byte_offset_to_position(&self.rope, byte, PositionEncoding::Utf16)
+    }
+
+    /// Converts a byte range of the document to a range in the encoding of the editor.
+    pub fn byte_range(&self, start: usize, end: usize) -> Range {
+        Range::new(
+            byte_offset_to_position(&self.rope, start, self.encoding),
+            byte_offset_to_position(&self.rope, end, self.encoding),
+        )
+    }
+
+    /// Converts a position of the editor to UTF-16 code units, as used by the backend.
+    pub fn to_utf16(&self, position: Position) -> Position {
+        position_to_byte_offset(&self.rope, &position, self.encoding)
+            .map(|byte| byte_offset_to_position(&self.rope, byte, PositionEncoding::Utf16))
+            .unwrap_or(position)
+    }
+
+    /// Converts a position of the backend in UTF-16 code units to the encoding of the editor.
+    pub fn from_utf16(&self, position: Position) -> Position {
+        self.encoding.from_utf16(&self.rope, position)
+    }
+
+    /// The folding ranges of the document, see [`folding::folding_ranges`].
+    pub fn folding_ranges(&self) -> Vec<FoldingRange> {
+        folding::folding_ranges(&self.tokens())
+    }
+
+    /// The content of the document formatted by the native formatter, see [`formatting::format`].
+    pub fn format(&self, settings: &FormattingSettings) -> String {
+        let text = self.rope.to_string();
+        let keywords = formatting::keywords(&self.cst, text.as_bytes());
+        formatting::format(&text, &self.tokens(), &keywords, settings)
+    }
+
+    /// The edits that turn the content of the document into the formatted content,
+    /// restricted to the lines of the range if one is given.
+    ///
+    /// Each line that changed is replaced on its own. Should the formatter have added or
+    /// removed lines, the entire document is replaced, unless only a range is formatted.
+    pub fn formatting_edits(&self, formatted: &str, range: Option<Range>) -> Vec<TextEdit> {
+        let lines: Vec<&str> = formatted.split('\n').collect();
+        if lines.len() != self.rope.len_lines() {
+            return match range {
+                Some(_) => vec![],
+                None => vec![TextEdit::new(
+                    self.byte_range(0, self.rope.len_bytes()),
+                    formatted.to_owned(),
+                )],
+            };
+        }
+
+        // A selection of entire lines ends at the start of the line after them.
+        let lines_in_range = range.map_or(0..lines.len(), |range| {
+            let end = match range.end.character == 0 && range.end.line > range.start.line {
+                true => range.end.line,
+                false => range.end.line + 1,
+            };
+            range.start.line as usize..(end as usize).min(lines.len())
+        });
+
+        let mut edits = vec![];
+        for index in lines_in_range {
+            let formatted = lines[index].trim_end_matches('\r');
+            let line = self.rope.line(index).to_string();
+            let current = line.trim_end_matches(['\r', '\n']);
+            if current != formatted {
+                let start = self.rope.line_to_byte(index);
+                edits.push(TextEdit::new(
+                    self.byte_range(start, start + current.len()),
+                    formatted.to_owned(),
+                ));
+            }
+        }
+        edits
+    }
+
+    /// Replaces the entire content of the document and parses it from scratch.
+    pub fn replace_content(&mut self, content: &str) {
+        self.rope = Rope::from_str(content);
+        self.cst = load_parser().parse(content, None).unwrap();
     }
 
     /// Reparses the Concrete Syntax Tree of the document after applying one or more edits.
     pub fn reparse(&mut self) -> () {
+        let rope = &self.rope;
         self.cst = load_parser()
-            .parse(&self.rope.to_string(), Some(&self.cst))
+            .parse_with_options(
+                &mut |byte, _| tokens::chunk_at(rope, byte),
+                Some(&self.cst),
+                None,
+            )
             .expect("Failed to update CST.")
     }
 
@@ -140,72 +456,299 @@ impl SourceCodeDocument {
         }
     }
 
-    /// Runs the highlighty query on the concrete syntax tree and extracts
-    /// the nodes into their corresponding semantic tokens.
-    pub fn semantic_tokens(&self) -> Vec<SemanticToken> {
-        let query = Query::new(
-            &tree_sitter_abap::LANGUAGE.into(),
-            tree_sitter_abap::HIGHLIGHTS_QUERY,
-        )
-        .unwrap();
-
+    /// Runs the highlights query on the concrete syntax tree and extracts the
+    /// nodes into their corresponding semantic tokens, sorted by their position.
+    ///
+    /// If a byte range is given, only the nodes intersecting it are considered.
+    pub fn semantic_tokens(&self, range: Option<std::ops::Range<usize>>) -> Vec<SemanticToken> {
+        let query = &*HIGHLIGHTS_QUERY;
         let mut cursor = QueryCursor::new();
+        if let Some(range) = range {
+            cursor.set_byte_range(range);
+        }
 
-        // This creates a copy, optimize later.
-        let text = self.rope.to_string();
-        let mut matches = cursor.matches(&query, self.cst.root_node(), text.as_bytes());
+        let mut captures = cursor.captures(query, self.cst.root_node(), RopeProvider(&self.rope));
 
         let mut tokens = vec![];
-        while let Some(_match) = matches.next() {
-            for capture in _match.captures {
-                let node = capture.node;
-                let capture_name = query.capture_names()[capture.index as usize];
-
-                let start = node.start_position();
-                let length = node.end_byte() - node.start_byte();
-
-                let token = SemanticToken {
-                    start_byte: node.start_byte(),
-                    row: start.row as u32,
-                    column: start.column as u32,
-                    length: length as u32,
-                    token_type: TokenType::from_name(capture_name),
-                    token_modifiers_bitset: TokenModifier::None,
-                };
+        while let Some((_match, index)) = captures.next() {
+            let capture = _match.captures[*index];
+            let node = capture.node;
+            let capture_name = query.capture_names()[capture.index as usize];
 
-                tokens.push(token);
-            }
+            // Captures that are not highlighted semantically, e.g. punctuation.
+            let Some(token_type) = TokenType::from_name(capture_name) else {
+                continue;
+            };
+
+            let start = byte_offset_to_position(&self.rope, node.start_byte(), self.encoding);
+
+            // Tokens may not span multiple lines, only the first line is highlighted.
+            let length: usize = self
+                .rope
+                .byte_slice(node.byte_range())
+                .chars()
+                .take_while(|c| *c != '\n' && *c != '\r')
+                .map(|c| self.encoding.len(c))
+                .sum();
+
+            tokens.push(SemanticToken {
+                start_byte: node.start_byte(),
+                row: start.line,
+                column: start.character,
+                length: length as u32,
+                token_type,
+                token_modifiers_bitset: TokenModifier::bitset_from_name(capture_name),
+            });
         }
+
+        // A node may be captured more than once, the first capture takes precedence.
+        tokens.sort_by_key(|t| t.start_byte);
+        tokens.dedup_by_key(|t| t.start_byte);
         tokens
     }
+
+    /// The semantic tokens of the entire document, stored under a new result id
+    /// so the next request of the editor can be answered with a delta.
+    pub fn semantic_tokens_full(&mut self) -> SemanticTokens {
+        let data = tokens::encode(&self.semantic_tokens(None));
+        self.semantic_tokens_result_id += 1;
+        let result_id = self.semantic_tokens_result_id.to_string();
+
+        self.last_semantic_tokens = Some((result_id.clone(), data.clone()));
+        SemanticTokens {
+            result_id: Some(result_id),
+            data,
+        }
+    }
+
+    /// The changes to the semantic tokens since the result with the given id.
+    ///
+    /// If the previous result is unknown, e.g. because it was superseded by a newer
+    /// request in the meantime, the full tokens are returned instead.
+    pub fn semantic_tokens_delta(
+        &mut self,
+        previous_result_id: &str,
+    ) -> SemanticTokensFullDeltaResult {
+        let previous = match self.last_semantic_tokens.take() {
+            Some((id, data)) if id == previous_result_id => data,
+            _ => return self.semantic_tokens_full().into(),
+        };
+
+        let tokens = self.semantic_tokens_full();
+        SemanticTokensDelta {
+            result_id: tokens.result_id,
+            edits: tokens::diff(&previous, &tokens.data),
+        }
+        .into()
+    }
+
+    /// The semantic tokens within a range of the document, e.g the visible region.
+    pub fn semantic_tokens_range(&self, range: Range) -> SemanticTokens {
+        let start =
+            position_to_byte_offset(&self.rope, &range.start, self.encoding).unwrap_or_default();
+        let end = position_to_byte_offset(&self.rope, &range.end, self.encoding)
+            .unwrap_or(self.rope.len_bytes());
+
+        SemanticTokens {
+            result_id: None,
+            data: tokens::encode(&self.semantic_tokens(Some(start..end))),
+        }
+    }
+}
+
+/// An edit of the editor that does not fit the content of the document, i.e. the
+/// document is out of sync with the editor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidEdit(pub Range);
+
+impl std::fmt::Display for InvalidEdit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Range { start, end } = self.0;
+        write!(
+            f,
+            "Edit of {}:{} to {}:{} does not fit the document.",
+            start.line, start.character, end.line, end.character
+        )
+    }
+}
+
+impl std::error::Error for InvalidEdit {}
+
+/// How the `character` of a [`Position`] is counted, negotiated with the client on `initialize`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PositionEncoding {
+    /// Characters are counted in bytes, the same as the columns of the syntax tree.
+    Utf8,
+    /// Characters are counted in UTF-16 code units, the default of the protocol.
+    #[default]
+    Utf16,
+}
+
+impl PositionEncoding {
+    /// Picks the encoding from those supported by the client, UTF-8 is preferred as
+    /// it requires no conversion, UTF-16 must be supported by every client.
+    pub fn negotiate(supported: Option<&[PositionEncodingKind]>) -> Self {
+        match supported {
+            Some(kinds) if kinds.contains(&PositionEncodingKind::UTF8) => Self::Utf8,
+            _ => Self::Utf16,
+        }
+    }
+
+    pub fn kind(&self) -> PositionEncodingKind {
+        match self {
+            Self::Utf8 => PositionEncodingKind::UTF8,
+            Self::Utf16 => PositionEncodingKind::UTF16,
+        }
+    }
+
+    /// The length of a character in this encoding.
+    pub fn len(&self, c: char) -> usize {
+        match self {
+            Self::Utf8 => c.len_utf8(),
+            Self::Utf16 => c.len_utf16(),
+        }
+    }
+
+    /// Converts a position counted in UTF-16 code units, like the columns reported
+    /// by the backend, into this encoding.
+    pub fn from_utf16(&self, rope: &Rope, position: Position) -> Position {
+        match self {
+            Self::Utf16 => position,
+            Self::Utf8 => position_to_byte_offset(rope, &position, Self::Utf16)
+                .map(|byte| byte_offset_to_position(rope, byte, *self))
+                .unwrap_or(position),
+        }
+    }
 }
 
-pub fn position_to_char_index(rope: &Rope, position: &Position) -> Option<usize> {
+/// Fetches the source of an object, or of the class include the ADT uri refers to.
+///
+/// Returns `None` if the backend responds that the source was not modified.
+async fn fetch_source(client: &AdtClient, adt_uri: &str) -> Result<Option<String>, OperationError> {
+    let result = match SourceCodeObject::from_object_uri(adt_uri) {
+        Some(SourceCodeObject::ClassInclude(name, include)) => {
+            ClassSourceBuilder::default()
+                .name(name)
+                .include(include)
+                .build()
+                .map_err(|_| OperationError::UninitializedField("name"))?
+                .dispatch(client)
+                .await?
+        }
+        _ => {
+            ObjectSourceRequestBuilder::default()
+                .object_uri(adt_uri)
+                .build()
+                .map_err(|_| OperationError::UninitializedField("object_uri"))?
+                .dispatch(client)
+                .await?
+        }
+    };
+    match result {
+        CacheControlled::Modified(t) => Ok(Some(t.into_body().inner().into_owned())),
+        CacheControlled::NotModified(_) => Ok(None),
+    }
+}
+
+pub fn position_to_char_index(
+    rope: &Rope,
+    position: &Position,
+    encoding: PositionEncoding,
+) -> Option<usize> {
+    position_to_byte_offset(rope, position, encoding).map(|byte| rope.byte_to_char(byte))
+}
+
+/// Converts a position to a byte offset of the rope, `None` if the line is past the end.
+///
+/// As the protocol specifies, a column past the end of the line defaults back to the
+/// end of the line, i.e. before its line break.
+pub fn position_to_byte_offset(
+    rope: &Rope,
+    position: &Position,
+    encoding: PositionEncoding,
+) -> Option<usize> {
     let (line, column) = (position.line as usize, position.character as usize);
     if line >= rope.len_lines() {
         return None;
     }
-    let line_start_char = rope.line_to_char(line);
-    let line_start_cu = rope.char_to_utf16_cu(line_start_char);
+    let line_start_byte = rope.line_to_byte(line);
     let line_text = rope.line(line);
-    let line_cu_count = line_text.chars().map(|c| c.len_utf16()).sum::<usize>();
-    if column > line_cu_count {
-        return None;
+    let break_chars = line_text
+        .chars_at(line_text.len_chars())
+        .reversed()
+        .take_while(|c| *c == '\n' || *c == '\r')
+        .count();
+    let content = line_text.slice(..line_text.len_chars() - break_chars);
+
+    match encoding {
+        PositionEncoding::Utf8 => {
+            let column = column.min(content.len_bytes());
+            // Snap to the start of the character, in case the column points into one.
+            Some(rope.char_to_byte(rope.byte_to_char(line_start_byte + column)))
+        }
+        PositionEncoding::Utf16 => {
+            let line_start_cu = rope.char_to_utf16_cu(rope.line_to_char(line));
+            let column = column.min(content.len_utf16_cu());
+            Some(rope.char_to_byte(rope.utf16_cu_to_char(line_start_cu + column)))
+        }
     }
-    Some(rope.utf16_cu_to_char(line_start_cu + column))
 }
 
-pub fn position_to_byte_offset(rope: &Rope, position: &Position) -> Option<usize> {
-    position_to_char_index(rope, position).map(|v| rope.char_to_byte(v))
+pub fn byte_offset_to_position(rope: &Rope, byte: usize, encoding: PositionEncoding) -> Position {
+    let char_idx = rope.byte_to_char(byte.min(rope.len_bytes()));
+    let line = rope.char_to_line(char_idx);
+    let column = match encoding {
+        PositionEncoding::Utf8 => rope.char_to_byte(char_idx) - rope.line_to_byte(line),
+        PositionEncoding::Utf16 => {
+            rope.char_to_utf16_cu(char_idx) - rope.char_to_utf16_cu(rope.line_to_char(line))
+        }
+    };
+    Position::new(line as u32, column as u32)
 }
 
-pub fn position_to_point(position: &Position) -> Point {
+/// Drops the diagnostics on the lines of an edit and moves those below by the number
+/// of lines the edit added or removed.
+fn move_past_edit(diagnostics: &mut Vec<Diagnostic>, range: Range, text: &str) {
+    let added = text.matches('\n').count() as i64;
+    let removed = range.end.line.saturating_sub(range.start.line) as i64;
+    let shift = |position: &mut Position| {
+        position.line = (position.line as i64 + added - removed) as u32;
+    };
+
+    diagnostics
+        .retain(|d| d.range.end.line < range.start.line || d.range.start.line > range.end.line);
+    for diagnostic in diagnostics
+        .iter_mut()
+        .filter(|d| d.range.start.line > range.end.line)
+    {
+        shift(&mut diagnostic.range.start);
+        shift(&mut diagnostic.range.end);
+    }
+}
+
+/// Converts a byte offset to a point of the syntax tree, whose columns are counted in bytes.
+fn byte_to_point(rope: &Rope, byte: usize) -> Point {
+    let line = rope.byte_to_line(byte);
+    Point::new(line, byte - rope.line_to_byte(line))
+}
+
+/// Converts a position to a point of the syntax tree, whose columns are counted in bytes.
+pub fn position_to_point(rope: &Rope, position: &Position, encoding: PositionEncoding) -> Point {
+    let column = position_to_byte_offset(rope, position, encoding)
+        .map(|byte| byte - rope.line_to_byte(position.line as usize))
+        .unwrap_or(position.character as usize);
     Point {
         row: position.line as usize,
-        column: position.character as usize,
+        column,
     }
 }
 
+/// Splits source code into its tokens through the syntax tree, see [`statements::tokens`].
+pub fn tokenize(source: &str) -> Vec<Token> {
+    let tree = load_parser().parse(source, None).unwrap();
+    statements::tokens(&tree, source.as_bytes())
+}
+
 fn load_parser() -> Parser {
     let mut parser = Parser::new();
     parser
@@ -214,3 +757,80 @@ fn load_parser() -> Parser {
 
     parser
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edit(range: Range, text: &str) -> TextDocumentContentChangeEvent {
+        TextDocumentContentChangeEvent {
+            range: Some(range),
+            range_length: None,
+            text: text.to_owned(),
+        }
+    }
+
+    #[test]
+    fn column_past_end_of_line_is_clamped() {
+        let rope = Rope::from_str("WRITE a.\r\nWRITE b.\n");
+        let position = Position::new(0, 42);
+
+        assert_eq!(
+            position_to_byte_offset(&rope, &position, PositionEncoding::Utf16),
+            Some(8)
+        );
+        assert_eq!(
+            position_to_byte_offset(&rope, &position, PositionEncoding::Utf8),
+            Some(8)
+        );
+        assert_eq!(
+            position_to_byte_offset(&rope, &Position::new(5, 0), PositionEncoding::Utf16),
+            None
+        );
+    }
+
+    #[test]
+    fn edit_past_end_of_line_appends_to_line() {
+        let mut doc = SourceCodeDocument::new("adt://A4H/ZTEST.prog", "", "WRITE a.\nWRITE b.\n");
+        let range = Range::new(Position::new(0, 7), Position::new(0, 99));
+
+        assert_eq!(doc.apply_changes(1, &[edit(range, ", c.")]), Ok(()));
+        assert_eq!(doc.raw_content(), "WRITE a, c.\nWRITE b.\n");
+        assert_eq!(doc.version(), Some(1));
+    }
+
+    #[test]
+    fn edit_past_last_line_is_rejected() {
+        let mut doc = SourceCodeDocument::new("adt://A4H/ZTEST.prog", "", "WRITE a.\n");
+        let range = Range::new(Position::new(3, 0), Position::new(3, 1));
+
+        assert_eq!(
+            doc.apply_changes(1, &[edit(range, "x")]),
+            Err(InvalidEdit(range))
+        );
+        assert_eq!(doc.raw_content(), "WRITE a.\n");
+        assert_eq!(doc.version(), None);
+    }
+
+    #[test]
+    fn edits_drop_atc_findings_on_their_lines_only() {
+        let finding = |line: u32| Diagnostic {
+            range: Range::new(Position::new(line, 6), Position::new(line, 7)),
+            ..Diagnostic::default()
+        };
+        let mut doc =
+            SourceCodeDocument::new("adt://A4H/ZTEST.prog", "", "WRITE a.\nWRITE b.\nWRITE c.\n");
+        doc.atc_diagnostics = vec![finding(0), finding(2)];
+        doc.apply_changes(1, &[]).unwrap();
+        assert_eq!(doc.atc_diagnostics.len(), 2);
+
+        let range = Range::new(Position::new(0, 8), Position::new(0, 8));
+        doc.apply_changes(2, &[edit(range, "\nWRITE d.\n")])
+            .unwrap();
+        assert_eq!(doc.atc_diagnostics, vec![finding(4)]);
+
+        let range = Range::new(Position::new(1, 0), Position::new(3, 0));
+        doc.apply_changes(3, &[edit(range, "")]).unwrap();
+        assert_eq!(doc.atc_diagnostics, vec![finding(2)]);
+    }
+}
@@ -0,0 +1,203 @@
+use std::collections::HashSet;
+
+use tower_lsp::lsp_types::{FoldingRange, FoldingRangeKind};
+
+use crate::statements::{self, Token, TokenKind};
+
+/// Statements that open a block and the statement that closes it.
+pub(crate) const BLOCKS: &[(&str, &str)] = &[
+    ("IF", "ENDIF"),
+    ("CASE", "ENDCASE"),
+    ("DO", "ENDDO"),
+    ("WHILE", "ENDWHILE"),
+    ("LOOP", "ENDLOOP"),
+    ("SELECT", "ENDSELECT"),
+    ("PROVIDE", "ENDPROVIDE"),
+    ("AT", "ENDAT"),
+    ("ON", "ENDON"),
+    ("TRY", "ENDTRY"),
+    ("CLASS", "ENDCLASS"),
+    ("INTERFACE", "ENDINTERFACE"),
+    ("METHOD", "ENDMETHOD"),
+    ("FORM", "ENDFORM"),
+    ("MODULE", "ENDMODULE"),
+    ("FUNCTION", "ENDFUNCTION"),
+    ("DEFINE", "END-OF-DEFINITION"),
+    ("TEST-SEAM", "END-TEST-SEAM"),
+];
+
+/// Statements that start another branch of a block, each branch is folded on its own.
+pub(crate) const BRANCHES: &[(&str, &str)] = &[
+    ("ELSEIF", "ENDIF"),
+    ("ELSE", "ENDIF"),
+    ("CATCH", "ENDTRY"),
+    ("CLEANUP", "ENDTRY"),
+];
+
+struct Block {
+    start_line: u32,
+    end: &'static str,
+}
+
+/// Computes the folding ranges of a source from its tokens.
+///
+/// Blocks are folded up to the line before the statement that closes them, so the
+/// closing `ENDIF.` or `ENDMETHOD.` remains visible.
+pub fn folding_ranges(tokens: &[Token]) -> Vec<FoldingRange> {
+    let mut ranges = vec![];
+    let mut stack: Vec<Block> = vec![];
+
+    for statement in statements::statements(tokens) {
+        let line = statement.start.row as u32;
+        let words: Vec<String> = statement
+            .tokens
+            .iter()
+            .take(4)
+            .map(|t| t.text.to_uppercase())
+            .collect();
+        let Some(keyword) = words.first() else {
+            continue;
+        };
+
+        if statement.is_chained() && statement.end.row > statement.start.row {
+            ranges.push(region(line, statement.end.row as u32));
+        }
+
+        if let Some((_, end)) = BRANCHES.iter().find(|(k, _)| k == keyword) {
+            if let Some(block) = stack.last_mut().filter(|b| b.end == *end) {
+                push_block(&mut ranges, block.start_line, line);
+                block.start_line = line;
+            }
+        } else if keyword == "WHEN" {
+            // Each `WHEN` of a `CASE` is folded as a block nested in the entire `CASE`.
+            if stack.last().is_some_and(|b| b.end == "WHEN") {
+                close(&mut ranges, &mut stack, "WHEN", line);
+            }
+            stack.push(Block {
+                start_line: line,
+                end: "WHEN",
+            });
+        } else if let Some((_, end)) = BLOCKS.iter().find(|(_, e)| e == keyword) {
+            close(&mut ranges, &mut stack, end, line);
+        } else if let Some((_, end)) = BLOCKS.iter().find(|(k, _)| k == keyword) {
+            if opens_block(keyword, &words) {
+                stack.push(Block {
+                    start_line: line,
+                    end,
+                });
+            }
+        } else if let Some(end) = structure(&words) {
+            match end {
+                Some(end) => stack.push(Block {
+                    start_line: line,
+                    end,
+                }),
+                None => close(&mut ranges, &mut stack, "END OF", line),
+            }
+        }
+    }
+
+    ranges.extend(comment_ranges(tokens));
+    ranges
+}
+
+/// Whether the statement actually opens a block, some keywords also start statements
+/// without a block, e.g. `CLASS lcl DEFINITION DEFERRED` or `SELECT SINGLE`.
+pub(crate) fn opens_block(keyword: &str, words: &[String]) -> bool {
+    let word = |i: usize| words.get(i).map(String::as_str).unwrap_or_default();
+    match keyword {
+        "CLASS" => {
+            matches!(word(2), "DEFINITION" | "IMPLEMENTATION")
+                && !matches!(word(3), "DEFERRED" | "LOAD" | "LOCAL")
+        }
+        "INTERFACE" => !matches!(word(2), "DEFERRED" | "LOAD"),
+        "SELECT" => word(1) != "SINGLE",
+        // `ON CHANGE OF`, not the `ON` of other statements.
+        "ON" => word(1) == "CHANGE",
+        // Event blocks like `AT SELECTION-SCREEN` are not closed by `ENDAT`.
+        "AT" => !matches!(
+            word(1),
+            "SELECTION-SCREEN" | "LINE-SELECTION" | "USER-COMMAND"
+        ),
+        _ => true,
+    }
+}
+
+/// Matches `TYPES BEGIN OF name` and `TYPES END OF name` of unchained structure declarations,
+/// returning the end of the block to open or `None` if the structure is closed.
+pub(crate) fn structure(words: &[String]) -> Option<Option<&'static str>> {
+    match words {
+        [_, begin, of, ..] if begin == "BEGIN" && of == "OF" => Some(Some("END OF")),
+        [_, end, of, ..] if end == "END" && of == "OF" => Some(None),
+        _ => None,
+    }
+}
+
+/// Closes the innermost block with the given end, blocks nested within that were
+/// never closed, e.g. a `SELECT` that turned out to be a single select, are dropped.
+fn close(ranges: &mut Vec<FoldingRange>, stack: &mut Vec<Block>, end: &str, line: u32) {
+    let Some(index) = stack.iter().rposition(|b| b.end == end) else {
+        return;
+    };
+    // `WHEN` blocks are implicitly closed along with their `CASE`.
+    for block in stack.drain(index..) {
+        if block.end == end || block.end == "WHEN" {
+            push_block(ranges, block.start_line, line);
+        }
+    }
+}
+
+fn push_block(ranges: &mut Vec<FoldingRange>, start_line: u32, closing_line: u32) {
+    if closing_line > start_line + 1 {
+        ranges.push(region(start_line, closing_line - 1));
+    }
+}
+
+fn region(start_line: u32, end_line: u32) -> FoldingRange {
+    FoldingRange {
+        start_line,
+        end_line,
+        kind: Some(FoldingRangeKind::Region),
+        ..Default::default()
+    }
+}
+
+/// Folds runs of consecutive lines that contain nothing but a comment.
+fn comment_ranges(tokens: &[Token]) -> Vec<FoldingRange> {
+    let code_lines: HashSet<usize> = tokens
+        .iter()
+        .filter(|t| t.kind != TokenKind::Comment)
+        .flat_map(|t| t.start.row..=t.end.row)
+        .collect();
+
+    let mut ranges = vec![];
+    let mut run: Option<(usize, usize)> = None;
+    let comment_lines = tokens
+        .iter()
+        .filter(|t| t.kind == TokenKind::Comment && !code_lines.contains(&t.start.row))
+        .map(|t| t.start.row);
+
+    for line in comment_lines {
+        run = match run {
+            Some((start, end)) if line == end + 1 => Some((start, line)),
+            Some((start, end)) => {
+                ranges.extend(comment_range(start, end));
+                Some((line, line))
+            }
+            None => Some((line, line)),
+        };
+    }
+    if let Some((start, end)) = run {
+        ranges.extend(comment_range(start, end));
+    }
+    ranges
+}
+
+fn comment_range(start: usize, end: usize) -> Option<FoldingRange> {
+    (end > start).then(|| FoldingRange {
+        start_line: start as u32,
+        end_line: end as u32,
+        kind: Some(FoldingRangeKind::Comment),
+        ..Default::default()
+    })
+}
@@ -0,0 +1,264 @@
+use adt_query::{
+    api::abapsource::PrettyPrintBuilder, dispatch::StatelessDispatch, error::OperationError,
+};
+use tree_sitter::Tree;
+
+use crate::completion::KEYWORDS;
+use crate::context::AdtClient;
+use crate::folding::{self, BLOCKS, BRANCHES};
+use crate::settings::{FormattingSettings, KeywordCase};
+use crate::statements::{self, Statement, Token, TokenKind};
+use crate::symbols::{AT_EVENTS, EVENTS};
+
+/// Procedures and definitions that end an event block.
+const PROCESSING_BLOCKS: &[&str] = &["CLASS", "INTERFACE", "FORM", "MODULE", "FUNCTION"];
+
+/// Marks the implicit blocks on the stack of [`statement_levels`], which are closed by
+/// the next statement of the same kind rather than a closing statement.
+const WHEN: &str = "WHEN";
+const SECTION: &str = "SECTION";
+const EVENT: &str = "EVENT";
+
+/// Formats a source with the pretty printer of the backend.
+pub async fn pretty_print(client: &AdtClient, content: &str) -> Result<String, OperationError> {
+    let operation = PrettyPrintBuilder::default()
+        .source(content)
+        .build()
+        .map_err(|_| OperationError::UninitializedField("source"))?;
+
+    let response = operation.dispatch(client).await?;
+    Ok(response.take().into_body().inner().into_owned())
+}
+
+/// The byte ranges of the keywords in the syntax tree, i.e. its anonymous leaves that
+/// are one of the [`KEYWORDS`] of the grammar.
+pub fn keywords(tree: &Tree, source: &[u8]) -> Vec<(usize, usize)> {
+    let mut keywords = vec![];
+    let mut cursor = tree.walk();
+
+    loop {
+        let node = cursor.node();
+        if node.child_count() == 0 {
+            let text = String::from_utf8_lossy(&source[node.start_byte()..node.end_byte()]);
+            if !node.is_named() && KEYWORDS.binary_search(&text.to_uppercase()).is_ok() {
+                keywords.push((node.start_byte(), node.end_byte()));
+            }
+        } else if cursor.goto_first_child() {
+            continue;
+        }
+
+        while !cursor.goto_next_sibling() {
+            if !cursor.goto_parent() {
+                return keywords;
+            }
+        }
+    }
+}
+
+/// Formats a source by converting the case of its keywords and indenting its statements
+/// by the blocks they are nested in.
+///
+/// Lines are never split or joined, so the result has as many lines as the source.
+/// Continuation lines of a statement keep their indentation relative to its first line,
+/// full line `*` comments and lines within literals are left untouched.
+pub fn format(
+    source: &str,
+    tokens: &[Token],
+    keywords: &[(usize, usize)],
+    settings: &FormattingSettings,
+) -> String {
+    let mut text = source.to_owned();
+    if settings.keyword_case != KeywordCase::Preserve {
+        for &(start, end) in keywords {
+            let keyword = match settings.keyword_case {
+                KeywordCase::Lower => text[start..end].to_ascii_lowercase(),
+                _ => text[start..end].to_ascii_uppercase(),
+            };
+            text.replace_range(start..end, &keyword);
+        }
+    }
+    if settings.indent_width == 0 {
+        return text;
+    }
+
+    let statements = statements::statements(tokens);
+    let levels = statement_levels(&statements);
+    let mut deltas: Vec<isize> = vec![];
+    let mut output = String::with_capacity(text.len());
+    let mut line_start = 0;
+
+    for line in text.split_inclusive('\n') {
+        let content = line.trim_start_matches([' ', '\t']);
+        let indent = line.len() - content.len();
+        let first_byte = line_start + indent;
+        line_start += line.len();
+
+        let new_indent = match content.trim().is_empty() {
+            true => None,
+            false => line_indent(
+                &statements,
+                &levels,
+                tokens,
+                &deltas,
+                first_byte,
+                indent,
+                settings.indent_width,
+            ),
+        };
+        deltas.push(new_indent.map_or(0, |new| new as isize - indent as isize));
+        match new_indent {
+            Some(new_indent) => {
+                output.push_str(&" ".repeat(new_indent));
+                output.push_str(content);
+            }
+            None => output.push_str(line),
+        }
+    }
+    output
+}
+
+/// The indentation of a line whose first token starts at a byte, `None` if the line is
+/// left as is.
+fn line_indent(
+    statements: &[Statement],
+    levels: &[usize],
+    tokens: &[Token],
+    deltas: &[isize],
+    first_byte: usize,
+    indent: usize,
+    width: usize,
+) -> Option<usize> {
+    let token = tokens
+        .binary_search_by_key(&first_byte, |t| t.start_byte)
+        .ok()
+        .map(|i| &tokens[i])?;
+    if token.kind == TokenKind::Comment && token.text.starts_with('*') {
+        return None;
+    }
+
+    let next = statements.partition_point(|s| s.start_byte < first_byte);
+    if statements
+        .get(next)
+        .is_some_and(|s| s.start_byte == first_byte)
+    {
+        return Some(levels[next] * width);
+    }
+    match next.checked_sub(1).map(|i| &statements[i]) {
+        // Continuation lines move along with the first line of the statement.
+        Some(statement) if first_byte < statement.end_byte => {
+            let delta = deltas.get(statement.start.row).copied().unwrap_or_default();
+            Some(indent.saturating_add_signed(delta))
+        }
+        // Comments between statements are indented like the statement that follows.
+        _ if token.kind == TokenKind::Comment => {
+            Some(levels.get(next).copied().unwrap_or_default() * width)
+        }
+        _ => None,
+    }
+}
+
+/// The nesting level of each statement, i.e. the number of blocks it is nested in.
+///
+/// Statements that open or close a block are on the level of the block, the branches
+/// of a block (e.g. `ELSE`) as well. `WHEN` and the sections of a class are implicit
+/// blocks of their own, so that the statements within them are indented further.
+fn statement_levels(statements: &[Statement]) -> Vec<usize> {
+    let mut stack: Vec<&'static str> = vec![];
+    let has_endselect = statements
+        .iter()
+        .any(|s| s.keyword().as_deref() == Some("ENDSELECT"));
+
+    statements
+        .iter()
+        .map(|statement| {
+            let words: Vec<String> = statement
+                .tokens
+                .iter()
+                .take(4)
+                .map(|t| t.text.to_uppercase())
+                .collect();
+            let Some(keyword) = words.first() else {
+                return stack.len();
+            };
+            let word = |i: usize| words.get(i).map(String::as_str).unwrap_or_default();
+
+            if let Some((_, end)) = BRANCHES.iter().find(|(k, _)| k == keyword) {
+                match stack.last() == Some(end) {
+                    true => stack.len() - 1,
+                    false => stack.len(),
+                }
+            } else if keyword == WHEN {
+                reopen(&mut stack, WHEN)
+            } else if word(1) == SECTION
+                && matches!(keyword.as_str(), "PUBLIC" | "PROTECTED" | "PRIVATE")
+            {
+                reopen(&mut stack, SECTION)
+            } else if let Some((_, end)) = BLOCKS.iter().find(|(_, e)| e == keyword) {
+                close(&mut stack, end)
+            } else if is_event(keyword, word(1)) {
+                close(&mut stack, EVENT);
+                stack.push(EVENT);
+                stack.len() - 1
+            } else if let Some((_, end)) = BLOCKS.iter().find(|(k, _)| k == keyword) {
+                let opens = folding::opens_block(keyword, &words)
+                    && (keyword != "SELECT" || (has_endselect && !selects_into_table(statement)));
+                if PROCESSING_BLOCKS.contains(&keyword.as_str()) {
+                    close(&mut stack, EVENT);
+                }
+                let level = stack.len();
+                if opens {
+                    stack.push(end);
+                }
+                level
+            } else if let Some(end) = folding::structure(&words) {
+                match end {
+                    Some(end) => {
+                        stack.push(end);
+                        stack.len() - 1
+                    }
+                    None => close(&mut stack, "END OF"),
+                }
+            } else {
+                stack.len()
+            }
+        })
+        .collect()
+}
+
+/// Whether the statement starts an event block of a report, these have no closing
+/// statement and end with the next event or procedure instead.
+fn is_event(keyword: &str, next: &str) -> bool {
+    match keyword {
+        "AT" => AT_EVENTS.contains(&next),
+        _ => EVENTS.contains(&keyword),
+    }
+}
+
+/// Closes the implicit block of the previous `WHEN` or section and opens another one,
+/// returning the level of the statement.
+fn reopen(stack: &mut Vec<&'static str>, block: &'static str) -> usize {
+    if stack.last() == Some(&block) {
+        stack.pop();
+    }
+    stack.push(block);
+    stack.len() - 1
+}
+
+/// Closes the innermost block with the given end along with the blocks nested in it,
+/// returning the level of the closing statement.
+fn close(stack: &mut Vec<&'static str>, end: &str) -> usize {
+    if let Some(index) = stack.iter().rposition(|b| *b == end) {
+        stack.truncate(index);
+    }
+    stack.len()
+}
+
+/// Whether a `SELECT` reads its result into a table at once rather than looping over it,
+/// e.g. `SELECT * FROM sflight INTO TABLE @DATA(flights)`
+fn selects_into_table(statement: &Statement) -> bool {
+    let tokens = &statement.tokens;
+    tokens
+        .iter()
+        .position(|t| t.is("INTO") || t.is("APPENDING"))
+        .is_some_and(|into| tokens[into + 1..].iter().take(5).any(|t| t.is("TABLE")))
+}
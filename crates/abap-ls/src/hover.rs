@@ -0,0 +1,87 @@
+use adt_query::{
+    api::abapsource::ElementInfoRequestBuilder, dispatch::StatelessDispatch, error::OperationError,
+    models::abapsource::ElementInfo,
+};
+use reqwest::Url;
+use tower_lsp::lsp_types::{Hover, HoverContents, MarkupContent, MarkupKind, Range};
+
+use crate::context::AdtClient;
+use crate::declarations::Declaration;
+use crate::navigation;
+
+/// The maximum number of components listed in the hover of an element.
+const MAX_COMPONENTS: usize = 20;
+
+/// Shows the declaring statement of a symbol declared within the document.
+pub fn declaration_hover(declaration: &Declaration, range: Range) -> Hover {
+    markdown_hover(format!("```abap\n{}\n```", declaration.text), range)
+}
+
+/// Looks up the element at a (1-based) line and (0-based) column of the source
+/// through the element info of the backend.
+pub async fn element_info(
+    client: &AdtClient,
+    adt_uri: &str,
+    content: &str,
+    line: u32,
+    column: u32,
+) -> Result<ElementInfo, OperationError> {
+    let operation = ElementInfoRequestBuilder::default()
+        .source_uri(navigation::source_uri(adt_uri))
+        .source(content)
+        .line(line)
+        .column(column)
+        .build()
+        .map_err(|_| OperationError::UninitializedField("source_uri"))?;
+
+    let response = operation.dispatch(client).await?;
+    Ok(response.take().into_body())
+}
+
+/// Shows the name, type and description of an element along with its components.
+///
+/// Links to the documentation are resolved against the system, so they can be opened
+/// in the browser.
+pub fn element_info_hover(info: &ElementInfo, system: &Url, range: Range) -> Hover {
+    let mut text = format!("**{}** `{}`", info.name, info.kind);
+    if let Some(description) = info
+        .documentation
+        .as_deref()
+        .map(str::trim)
+        .filter(|d| !d.is_empty())
+    {
+        text.push_str(&format!("\n\n{description}"));
+    }
+
+    if !info.components.is_empty() {
+        text.push_str("\n\n---\n");
+        for component in info.components.iter().take(MAX_COMPONENTS) {
+            text.push_str(&format!("\n- `{}`", component.name));
+            if let Some(description) = component.documentation.as_deref().map(str::trim) {
+                text.push_str(&format!(" {description}"));
+            }
+        }
+        if info.components.len() > MAX_COMPONENTS {
+            let remaining = info.components.len() - MAX_COMPONENTS;
+            text.push_str(&format!("\n- *... {remaining} more*"));
+        }
+    }
+
+    if let Some(url) = info
+        .documentation_link()
+        .and_then(|link| system.join(&link.href).ok())
+    {
+        text.push_str(&format!("\n\n[Documentation]({url})"));
+    }
+    markdown_hover(text, range)
+}
+
+fn markdown_hover(value: String, range: Range) -> Hover {
+    Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value,
+        }),
+        range: Some(range),
+    }
+}
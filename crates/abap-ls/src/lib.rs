@@ -1,3 +1,16 @@
+pub mod codelens;
+pub mod completion;
 pub mod context;
+pub mod declarations;
+pub mod diagnostics;
 pub mod document;
+pub mod folding;
+pub mod formatting;
+pub mod hover;
+pub mod navigation;
+pub mod references;
+pub mod settings;
+pub mod signature;
+pub mod statements;
+pub mod symbols;
 pub mod tokens;
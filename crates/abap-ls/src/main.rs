@@ -2,7 +2,9 @@ mod backend;
 mod methods;
 
 use crate::backend::Backend;
+use crate::methods::symbols::{WORKSPACE_SYMBOL, route_workspace_symbol};
 use tokio::net::TcpListener;
+use tower::ServiceBuilder;
 use tower_lsp::{LspService, Server};
 
 #[tokio::main]
@@ -22,7 +24,15 @@ async fn main() {
                 .custom_method("connection/connect", Backend::connect)
                 .custom_method("filesystem/expand", Backend::expand)
                 .custom_method("filesystem/source", Backend::read)
+                .custom_method("filesystem/write", Backend::write)
+                .custom_method("abap/activate", Backend::activate)
+                .custom_method("abap/runUnitTests", Backend::run_unit_tests)
+                .custom_method("abap/runAtc", Backend::run_atc)
+                .custom_method(WORKSPACE_SYMBOL, Backend::workspace_symbol)
                 .finish();
+            let service = ServiceBuilder::new()
+                .map_request(route_workspace_symbol)
+                .service(service);
             Server::new(read, write, socket).serve(service).await;
 
             // The backend disconnects here, where we should temporarily send the context
@@ -1,2 +1,8 @@
+pub mod activation;
+pub mod atc;
+pub mod aunit;
 pub mod connection;
+pub mod error;
 pub mod filesystem;
+pub mod symbols;
+pub mod transport;
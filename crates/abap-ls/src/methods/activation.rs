@@ -0,0 +1,147 @@
+use abap_lsp::navigation::{document_range, object_reference};
+use adt_query::{
+    api::activation::ActivateBuilder,
+    dispatch::StatelessDispatch,
+    models::activation::{ActivationResult, Message},
+};
+use serde::{Deserialize, Serialize};
+use tower_lsp::jsonrpc::{Error, Result};
+use tower_lsp::lsp_types::{DiagnosticSeverity, Range};
+
+use crate::backend::Backend;
+use crate::methods::error;
+
+/// Parameters for **`abap/activate`**
+///
+/// Activates the objects of one or many open documents, along with further objects
+/// that are not open, e.g. the pending objects of a previous activation.
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivateParams {
+    /// The URIs of the documents to activate together.
+    pub uris: Vec<String>,
+
+    /// The ADT URIs of further objects to activate together with the documents, e.g
+    /// `/sap/bc/adt/oo/classes/zcl_order`
+    pub objects: Option<Vec<String>>,
+
+    /// Whether the server should check for other inactive objects that need to be
+    /// activated together with the documents, defaults to `true`.
+    pub preaudit: Option<bool>,
+}
+
+/// Response of **`abap/activate`**
+#[derive(Debug, PartialEq, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivateResult {
+    /// Whether all objects were activated.
+    pub activated: bool,
+
+    /// Messages of the activation, mapped to the open documents where possible.
+    pub messages: Vec<ActivationMessage>,
+
+    /// Further inactive objects that have to be activated together with the documents,
+    /// these can be passed as the `objects` of the next activation.
+    pub pending_objects: Vec<PendingObject>,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivationMessage {
+    /// The URI of the document the message refers to, if it is open.
+    pub uri: Option<String>,
+
+    /// The range in the document the message refers to.
+    pub range: Option<Range>,
+
+    pub severity: DiagnosticSeverity,
+
+    pub message: String,
+
+    /// The description of the object the message refers to, e.g `Program ZTEST`
+    pub object: String,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingObject {
+    pub adt_uri: String,
+
+    pub name: String,
+
+    pub object_type: Option<String>,
+}
+
+impl Backend {
+    pub async fn activate(&self, params: ActivateParams) -> Result<ActivateResult> {
+        let ctx = self.context()?;
+
+        let mut builder = ActivateBuilder::default();
+        for uri in &params.uris {
+            let doc = ctx
+                .fetch_document(uri)
+                .ok_or_else(|| error::document_not_open(uri))?;
+            builder.object(object_reference(doc.lock().unwrap().adt_uri()));
+        }
+        for adt_uri in params.objects.iter().flatten() {
+            builder.object(object_reference(adt_uri));
+        }
+        if let Some(preaudit) = params.preaudit {
+            builder.preaudit(preaudit);
+        }
+        let operation = builder.build().map_err(|_| Error::internal_error())?;
+
+        let response = operation
+            .dispatch(&ctx.adt_client)
+            .await
+            .map_err(|e| error::backend_error(&e))?;
+        let result = response.body();
+
+        let messages = result
+            .messages()
+            .iter()
+            .map(|m| {
+                let location = m.location();
+                let uri = location
+                    .as_ref()
+                    .and_then(|l| ctx.find_document_by_adt_uri(&l.uri));
+                ActivationMessage {
+                    range: location
+                        .and_then(|l| l.start)
+                        .or(m.line.map(|line| (line, 0)))
+                        .map(|start| document_range(ctx, uri.as_deref(), start)),
+                    uri,
+                    severity: severity(m),
+                    message: m.text(),
+                    object: m.object_description.clone(),
+                }
+            })
+            .collect();
+
+        let pending_objects = match result {
+            ActivationResult::PendingObjects(objects) => objects
+                .objects()
+                .map(|o| PendingObject {
+                    adt_uri: o.uri.clone(),
+                    name: o.name.clone(),
+                    object_type: o.object_type.clone(),
+                })
+                .collect(),
+            _ => vec![],
+        };
+
+        Ok(ActivateResult {
+            activated: result.is_activated(),
+            messages,
+            pending_objects,
+        })
+    }
+}
+
+fn severity(message: &Message) -> DiagnosticSeverity {
+    match message.kind.as_str() {
+        "E" | "A" | "X" => DiagnosticSeverity::ERROR,
+        "W" => DiagnosticSeverity::WARNING,
+        _ => DiagnosticSeverity::INFORMATION,
+    }
+}
@@ -0,0 +1,224 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use abap_lsp::context::{AdtClient, ClientContext};
+use abap_lsp::navigation::{document_range, object_reference};
+use adt_query::{
+    api::atc::{AtcCustomizingBuilder, AtcWorklistBuilder, CreateWorklistBuilder, RunAtcBuilder},
+    dispatch::StatelessDispatch,
+    models::atc::{Finding, Worklist},
+};
+use serde::{Deserialize, Serialize};
+use tower_lsp::jsonrpc::{Error, Result};
+use tower_lsp::lsp_types::{Range, Url};
+
+use crate::backend::Backend;
+use crate::methods::error;
+
+/// The check variant to run if neither the editor nor the customizing specifies one.
+const DEFAULT_CHECK_VARIANT: &str = "DEFAULT";
+
+/// How long to wait before polling the worklist of a run that is still in progress.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// How often to poll the worklist before giving up on the run to complete, i.e. runs
+/// taking longer than half a minute are reported as incomplete.
+const POLL_ATTEMPTS: u32 = 60;
+
+/// Parameters for **`abap/runAtc`**
+///
+/// Runs the ATC for the objects of one or many open documents, the findings are
+/// published as diagnostics of the documents.
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunAtcParams {
+    /// The URIs of the documents to check.
+    pub uris: Vec<String>,
+
+    /// The check variant to run, defaults to the check variant of the system.
+    pub check_variant: Option<String>,
+}
+
+/// Response of **`abap/runAtc`**
+#[derive(Debug, Eq, PartialEq, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunAtcResult {
+    /// The id of the worklist of the run, e.g. to request exemptions for its findings.
+    pub worklist_id: String,
+
+    pub findings: Vec<AtcFinding>,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AtcFinding {
+    /// The URI of the document of the finding, if it is open.
+    pub uri: Option<String>,
+
+    /// The ADT uri of the source, e.g. `/sap/bc/adt/oo/classes/zcl_x/source/main`
+    pub adt_uri: String,
+
+    pub range: Option<Range>,
+
+    /// The priority of the finding, from `1` (very high) to `4` (low).
+    pub priority: u8,
+
+    pub check_id: String,
+
+    pub check_title: String,
+
+    pub message: String,
+
+    /// Whether the finding can be resolved by a quickfix.
+    pub has_quickfix: bool,
+
+    /// The ADT uri of the finding itself.
+    pub finding_uri: String,
+}
+
+impl Backend {
+    pub async fn run_atc(&self, params: RunAtcParams) -> Result<RunAtcResult> {
+        let ctx = self.context()?;
+        let client = &ctx.adt_client;
+
+        let mut builder = RunAtcBuilder::default();
+        for uri in &params.uris {
+            let doc = ctx
+                .fetch_document(uri)
+                .ok_or_else(|| error::document_not_open(uri))?;
+            builder.object(object_reference(doc.lock().unwrap().adt_uri()));
+        }
+
+        let check_variant = match params.check_variant {
+            Some(variant) => variant,
+            None => system_check_variant(client).await?,
+        };
+        let worklist_id = CreateWorklistBuilder::default()
+            .check_variant(check_variant)
+            .build()
+            .map_err(|_| Error::internal_error())?
+            .dispatch(client)
+            .await
+            .map_err(|e| error::backend_error(&e))?
+            .take()
+            .into_body()
+            .inner()
+            .trim()
+            .to_owned();
+
+        let run = builder
+            .worklist_id(worklist_id.as_str())
+            .build()
+            .map_err(|_| Error::internal_error())?
+            .dispatch(client)
+            .await
+            .map_err(|e| error::backend_error(&e))?;
+        let worklist = poll_worklist(client, &worklist_id, &run.body().timestamp).await?;
+
+        self.publish_atc_diagnostics(ctx, &params.uris, &worklist)
+            .await;
+        let findings = worklist
+            .findings()
+            .map(|finding| atc_finding(ctx, finding))
+            .collect();
+
+        Ok(RunAtcResult {
+            worklist_id,
+            findings,
+        })
+    }
+
+    /// Replaces the ATC diagnostics of the checked documents (and any other open
+    /// document a finding refers to) with the findings of the run.
+    async fn publish_atc_diagnostics(
+        &self,
+        ctx: &ClientContext,
+        uris: &[String],
+        worklist: &Worklist,
+    ) {
+        let mut findings: HashMap<String, Vec<&Finding>> =
+            uris.iter().map(|uri| (uri.clone(), vec![])).collect();
+        for finding in worklist.findings() {
+            if let Some(uri) = ctx.find_document_by_adt_uri(&finding.source_location().uri) {
+                findings.entry(uri).or_default().push(finding);
+            }
+        }
+
+        for (uri, findings) in findings {
+            let (Some(doc), Ok(url)) = (ctx.fetch_document(&uri), Url::parse(&uri)) else {
+                continue;
+            };
+            let (diagnostics, version) = {
+                let mut doc = doc.lock().unwrap();
+                doc.set_atc_findings(&findings);
+                (doc.diagnostics(), doc.version())
+            };
+            self.client
+                .publish_diagnostics(url, diagnostics, version)
+                .await;
+        }
+    }
+}
+
+async fn system_check_variant(client: &AdtClient) -> Result<String> {
+    let customizing = AtcCustomizingBuilder::default()
+        .build()
+        .map_err(|_| Error::internal_error())?
+        .dispatch(client)
+        .await
+        .map_err(|e| error::backend_error(&e))?;
+
+    Ok(customizing
+        .body()
+        .system_check_variant()
+        .filter(|variant| !variant.is_empty())
+        .unwrap_or(DEFAULT_CHECK_VARIANT)
+        .to_owned())
+}
+
+/// Retrieves the findings of the run, polling the worklist until all objects were checked.
+///
+/// Fails with [`error::ATC_INCOMPLETE`] if the run does not complete in time, the id of the
+/// worklist is provided as the data of the error.
+async fn poll_worklist(client: &AdtClient, id: &str, timestamp: &str) -> Result<Worklist> {
+    let mut attempts = 0;
+    loop {
+        let worklist = AtcWorklistBuilder::default()
+            .id(id)
+            .timestamp(timestamp)
+            .build()
+            .map_err(|_| Error::internal_error())?
+            .dispatch(client)
+            .await
+            .map_err(|e| error::backend_error(&e))?
+            .take()
+            .into_body();
+
+        if worklist.complete {
+            return Ok(worklist);
+        }
+        attempts += 1;
+        if attempts >= POLL_ATTEMPTS {
+            return Err(error::atc_incomplete(id));
+        }
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
+
+fn atc_finding(ctx: &ClientContext, finding: &Finding) -> AtcFinding {
+    let location = finding.source_location();
+    let uri = ctx.find_document_by_adt_uri(&location.uri);
+    AtcFinding {
+        range: location
+            .start
+            .map(|start| document_range(ctx, uri.as_deref(), start)),
+        uri,
+        adt_uri: location.uri,
+        priority: finding.priority,
+        check_id: finding.check_id.clone(),
+        check_title: finding.check_title.clone(),
+        message: finding.message.clone(),
+        has_quickfix: finding.has_quickfix(),
+        finding_uri: finding.uri.clone(),
+    }
+}
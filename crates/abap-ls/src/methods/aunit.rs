@@ -0,0 +1,212 @@
+use abap_lsp::{
+    context::ClientContext,
+    navigation::{document_range, object_reference, test_reference},
+};
+use adt_query::{
+    api::aunit::RunUnitTestsBuilder,
+    dispatch::StatelessDispatch,
+    models::{
+        adtcore::SourceLocation,
+        aunit::{Alert, TestClass, TestMethod},
+    },
+};
+use serde::{Deserialize, Serialize};
+use tower_lsp::jsonrpc::{Error, Result};
+use tower_lsp::lsp_types::{DiagnosticSeverity, Range};
+
+use crate::backend::Backend;
+use crate::methods::error;
+
+/// Parameters for **`abap/runUnitTests`**
+///
+/// Runs the ABAP Unit tests of the objects of one or many open documents.
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunUnitTestsParams {
+    /// The URIs of the documents whose tests to run.
+    pub uris: Vec<String>,
+
+    /// Whether to measure the code coverage of the tests, defaults to `false`.
+    pub coverage: Option<bool>,
+
+    /// Only run this test class, e.g. when run from a code lens.
+    pub class: Option<String>,
+
+    /// Only run this test method of the `class`.
+    pub method: Option<String>,
+}
+
+/// Response of **`abap/runUnitTests`**
+#[derive(Debug, PartialEq, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunUnitTestsResult {
+    /// Whether all tests passed.
+    pub successful: bool,
+
+    pub classes: Vec<TestClassResult>,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TestClassResult {
+    pub name: String,
+
+    /// The name of the program (or class) the test class belongs to.
+    pub program: String,
+
+    pub location: TestLocation,
+
+    pub methods: Vec<TestMethodResult>,
+
+    /// Alerts of the class itself, e.g. if its `setup` failed.
+    pub alerts: Vec<TestAlert>,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TestMethodResult {
+    pub name: String,
+
+    pub location: TestLocation,
+
+    /// Whether the method passed, i.e. raised no errors.
+    pub passed: bool,
+
+    /// How long the method took to run, in seconds.
+    pub execution_time: f64,
+
+    pub alerts: Vec<TestAlert>,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TestAlert {
+    /// The kind of the alert, e.g. `failedAssertion` or `exception`.
+    pub kind: String,
+
+    pub severity: DiagnosticSeverity,
+
+    pub message: String,
+
+    /// Further details, e.g. `Expected [1] Actual [2]`
+    pub details: Vec<String>,
+
+    /// Where the alert was raised, i.e. the innermost call of its stack within an
+    /// open document.
+    pub location: Option<TestLocation>,
+}
+
+/// A location in the source code the results refer to.
+#[derive(Debug, Eq, PartialEq, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TestLocation {
+    /// The URI of the document, if it or the class it is an include of is open.
+    pub uri: Option<String>,
+
+    /// The ADT uri of the source, e.g. `/sap/bc/adt/oo/classes/zcl_x/includes/testclasses`
+    pub adt_uri: String,
+
+    pub range: Option<Range>,
+}
+
+impl Backend {
+    pub async fn run_unit_tests(&self, params: RunUnitTestsParams) -> Result<RunUnitTestsResult> {
+        let ctx = self.context()?;
+
+        let mut builder = RunUnitTestsBuilder::default();
+        for uri in &params.uris {
+            let doc = ctx
+                .fetch_document(uri)
+                .ok_or_else(|| error::document_not_open(uri))?;
+            let doc = doc.lock().unwrap();
+            builder.object(match &params.class {
+                Some(class) => test_reference(doc.adt_uri(), class, params.method.as_deref()),
+                None => object_reference(doc.adt_uri()),
+            });
+        }
+        if let Some(coverage) = params.coverage {
+            builder.coverage(coverage);
+        }
+        let operation = builder.build().map_err(|_| Error::internal_error())?;
+
+        let response = operation
+            .dispatch(&ctx.adt_client)
+            .await
+            .map_err(|e| error::backend_error(&e))?;
+        let result = response.body();
+
+        let classes: Vec<TestClassResult> = result
+            .programs
+            .iter()
+            .flat_map(|program| {
+                program
+                    .test_classes()
+                    .iter()
+                    .map(|class| test_class_result(ctx, &program.name, class))
+            })
+            .collect();
+
+        Ok(RunUnitTestsResult {
+            successful: result.is_successful(),
+            classes,
+        })
+    }
+}
+
+fn test_class_result(ctx: &ClientContext, program: &str, class: &TestClass) -> TestClassResult {
+    TestClassResult {
+        name: class.name.clone(),
+        program: program.to_owned(),
+        location: test_location(ctx, class.location(), &class.uri),
+        methods: class
+            .test_methods()
+            .iter()
+            .map(|method| test_method_result(ctx, method))
+            .collect(),
+        alerts: class.alerts().iter().map(|a| test_alert(ctx, a)).collect(),
+    }
+}
+
+fn test_method_result(ctx: &ClientContext, method: &TestMethod) -> TestMethodResult {
+    TestMethodResult {
+        name: method.name.clone(),
+        location: test_location(ctx, method.location(), &method.uri),
+        passed: method.is_successful(),
+        execution_time: method.execution_time,
+        alerts: method.alerts().iter().map(|a| test_alert(ctx, a)).collect(),
+    }
+}
+
+fn test_alert(ctx: &ClientContext, alert: &Alert) -> TestAlert {
+    let locations: Vec<SourceLocation> = alert.stack().iter().map(|e| e.location()).collect();
+    let location = locations
+        .iter()
+        .find(|l| ctx.find_document_by_adt_uri(&l.uri).is_some())
+        .or(locations.first())
+        .map(|l| test_location(ctx, Some(l.clone()), &l.uri));
+
+    TestAlert {
+        kind: alert.kind.clone(),
+        severity: match alert.is_error() {
+            true => DiagnosticSeverity::ERROR,
+            false => DiagnosticSeverity::WARNING,
+        },
+        message: alert.title.clone(),
+        details: alert.details().into_iter().map(str::to_owned).collect(),
+        location,
+    }
+}
+
+/// Maps the location of a test to the open document it is in, falling back to the
+/// uri of the test itself if the backend provided no location.
+fn test_location(ctx: &ClientContext, location: Option<SourceLocation>, uri: &str) -> TestLocation {
+    let location = location.unwrap_or_else(|| SourceLocation::parse(uri));
+    let uri = ctx.find_document_by_adt_uri(&location.uri);
+    TestLocation {
+        range: location
+            .start
+            .map(|start| document_range(ctx, uri.as_deref(), start)),
+        uri,
+        adt_uri: location.uri,
+    }
+}
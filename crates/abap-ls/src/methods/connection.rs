@@ -102,7 +102,7 @@ impl Backend {
         match operation.dispatch(&client).await {
             Err(e) => {
                 let mut err = Error::new(ErrorCode::InternalError);
-                err.message = format!("{:?}", e).into();
+                err.message = e.to_string().into();
                 return Err(err);
             }
             _ => {}
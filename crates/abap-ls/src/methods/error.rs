@@ -0,0 +1,78 @@
+use adt_query::{error::OperationError, models::exception::ExceptionKind};
+use serde_json::json;
+use tower_lsp::jsonrpc::{Error, ErrorCode};
+
+/// The request failed on the ADT backend without a more specific reason.
+pub const BACKEND_ERROR: i64 = -32000;
+
+/// The object is locked, typically because another user is editing it.
+pub const OBJECT_LOCKED: i64 = -32001;
+
+/// The object is not local and changes to it must be recorded in a transport request.
+pub const TRANSPORT_REQUIRED: i64 = -32002;
+
+/// The document the request refers to is not open.
+pub const DOCUMENT_NOT_OPEN: i64 = -32003;
+
+/// The lock on the object was lost, e.g. because the session expired.
+pub const LOCK_LOST: i64 = -32004;
+
+/// The node the request refers to is not (or no longer) part of the filesystem.
+pub const NODE_NOT_FOUND: i64 = -32005;
+
+/// The ATC run did not complete in time, its findings may be fetched later through its worklist.
+pub const ATC_INCOMPLETE: i64 = -32006;
+
+/// Creates an error with a custom server error code.
+pub fn server_error(code: i64, message: impl Into<String>) -> Error {
+    let mut err = Error::new(ErrorCode::ServerError(code));
+    err.message = message.into().into();
+    err
+}
+
+/// Maps an error of the ADT backend to a JSON-RPC error.
+///
+/// If the server responded with an exception, its kind and T100 message key are
+/// provided as the data of the error for the client to act upon.
+pub fn backend_error(e: &OperationError) -> Error {
+    let Some(exception) = e.adt_exception() else {
+        return server_error(BACKEND_ERROR, e.to_string());
+    };
+
+    let code = match exception.kind() {
+        ExceptionKind::ResourceAlreadyLocked => OBJECT_LOCKED,
+        ExceptionKind::InvalidLockHandle => LOCK_LOST,
+        _ => BACKEND_ERROR,
+    };
+    let mut err = server_error(code, exception.text());
+    err.data = Some(json!({
+        "kind": format!("{:?}", exception.kind()),
+        "t100": exception.t100_key(),
+        "longText": exception.long_text(),
+    }));
+    err
+}
+
+/// The document the request refers to has not been opened (yet).
+pub fn document_not_open(uri: &str) -> Error {
+    server_error(DOCUMENT_NOT_OPEN, format!("Document '{uri}' is not open."))
+}
+
+/// The node has been removed from the filesystem, e.g. because its parent was refreshed.
+pub fn node_not_found(id: impl std::fmt::Debug) -> Error {
+    server_error(
+        NODE_NOT_FOUND,
+        format!("Node {id:?} is not part of the filesystem."),
+    )
+}
+
+/// The ATC run of the worklist did not complete in time, the id of the worklist is
+/// provided as the data of the error.
+pub fn atc_incomplete(worklist_id: &str) -> Error {
+    let mut err = server_error(
+        ATC_INCOMPLETE,
+        format!("The ATC run of worklist {worklist_id} did not complete in time."),
+    );
+    err.data = Some(json!({ "worklistId": worklist_id }));
+    err
+}
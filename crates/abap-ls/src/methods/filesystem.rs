@@ -1,10 +1,37 @@
+use std::sync::{Arc, Mutex as SyncMutex};
+
+use abap_lsp::context::{AdtClient, ClientContext};
 use abap_lsp::document::SourceCodeDocument;
+use abap_lsp::navigation;
+use adt_query::{
+    api::object::{
+        AccessMode, LockBuilder, SourceCodeObject, UnlockBuilder, UpdateSourceCodeBuilder,
+    },
+    dispatch::StatefulDispatch,
+    session::UserSessionId,
+};
 use serde::{Deserialize, Serialize};
 use slotmap::DefaultKey;
-use tower_lsp::jsonrpc::Result;
+use tower_lsp::jsonrpc::{Error, Result};
+use tower_lsp::lsp_types::{
+    Diagnostic, MessageType, TextDocumentIdentifier, Url, request::Request,
+};
 use vfs::nodes::{VirtualNode, VirtualNodeData};
 
 use crate::backend::Backend;
+use crate::methods::error;
+
+/// Request **`abap/documentContent`**, sent to the editor
+///
+/// Asks for the current content of an open document, e.g. to resynchronize it after
+/// an edit did not fit. The editor responds with `null` if it is not open.
+pub enum DocumentContent {}
+
+impl Request for DocumentContent {
+    type Params = TextDocumentIdentifier;
+    type Result = Option<String>;
+    const METHOD: &'static str = "abap/documentContent";
+}
 
 /// Parameters for **`connection/connect`**
 ///
@@ -39,9 +66,31 @@ pub struct ReadFileResult {
     pub content: String,
 }
 
+/// Parameters for **`filesystem/write`**
+///
+/// Writes the content of a document back to the system.
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WriteFileParams {
+    pub uri: String,
+
+    /// Optional: The content to write, replaces the content of the document.
+    ///
+    /// If not provided, the current content of the document is written.
+    pub content: Option<String>,
+}
+
+/// Response of **`filesystem/write`**
+#[derive(Debug, Eq, PartialEq, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WriteFileResult {
+    /// The transport request the change was recorded in, if the object is not local.
+    pub transport: Option<String>,
+}
+
 impl Backend {
     pub async fn expand(&self, params: ExpandParams) -> Result<ExpandResult> {
-        let ctx = self.context().unwrap();
+        let ctx = self.context()?;
         let mut filetree = ctx.filetree.lock().await;
 
         let result = filetree.expand(params.id, &ctx.adt_client).await;
@@ -51,20 +100,33 @@ impl Backend {
     }
 
     pub async fn read(&self, params: ReadFileParams) -> Result<ReadFileResult> {
-        let ctx = self.context().unwrap();
+        let ctx = self.context()?;
         let filetree = ctx.filetree.lock().await;
 
-        let node = filetree.lookup(params.id).unwrap();
+        let node = filetree
+            .lookup(params.id)
+            .ok_or_else(|| error::node_not_found(params.id))?;
 
         let obj = match &node.data {
             VirtualNodeData::RepositoryObject(obj) => obj,
-            _ => panic!(),
+            _ => {
+                return Err(Error::invalid_params(format!(
+                    "'{}' is not an object with source code.",
+                    params.uri
+                )));
+            }
         };
         let content = if let Some(doc) = ctx.fetch_document(&params.uri) {
             println!("Document {} was already loaded.", params.uri);
             doc.lock().unwrap().raw_content()
         } else {
-            let obj = SourceCodeDocument::fetch(&params.uri, &obj.adt_uri, &ctx.adt_client).await;
+            let adt_uri = match Url::parse(&params.uri) {
+                Ok(uri) => navigation::document_source_uri(&obj.adt_uri, &uri),
+                Err(_) => obj.adt_uri.clone(),
+            };
+            let obj = SourceCodeDocument::fetch(&params.uri, &adt_uri, &ctx.adt_client)
+                .await
+                .map_err(|e| error::backend_error(&e))?;
             let text = obj.raw_content();
             ctx.store_document(obj);
             text
@@ -73,3 +135,166 @@ impl Backend {
         Ok(ReadFileResult { content })
     }
 }
+
+impl Backend {
+    /// Loads the document of an object the editor opens without reading it first, e.g.
+    /// because it was evicted on close, and stores it in the context.
+    ///
+    /// The object is looked up among the nodes of the filesystem, should its source not
+    /// be available the content of the editor is taken over.
+    pub async fn load_document(
+        &self,
+        ctx: &ClientContext,
+        uri: &Url,
+        content: &str,
+    ) -> Option<Arc<SyncMutex<SourceCodeDocument>>> {
+        let adt_uri = {
+            let tree = ctx.filetree.lock().await;
+            navigation::document_adt_uri(&tree, uri)?
+        };
+        let doc = match SourceCodeDocument::fetch(uri.as_str(), &adt_uri, &ctx.adt_client).await {
+            Ok(doc) => doc,
+            Err(e) => {
+                self.client
+                    .log_message(
+                        MessageType::WARNING,
+                        format!("Could not load the source of {uri}: {e}"),
+                    )
+                    .await;
+                SourceCodeDocument::new(uri.as_str(), &adt_uri, content)
+            }
+        };
+        ctx.store_document(doc);
+        ctx.fetch_document(uri.as_str())
+    }
+
+    /// Replaces the content of a document that is out of sync with the editor by the
+    /// content of the editor, or by the source of the backend should the editor not
+    /// provide it.
+    ///
+    /// Returns the diagnostics of the document, `None` if neither provided the content.
+    pub async fn resync_document(
+        &self,
+        ctx: &ClientContext,
+        doc: &Arc<SyncMutex<SourceCodeDocument>>,
+        uri: &Url,
+        version: i32,
+    ) -> Option<Vec<Diagnostic>> {
+        let request = TextDocumentIdentifier::new(uri.clone());
+        let content = match self.client.send_request::<DocumentContent>(request).await {
+            Ok(Some(content)) => content,
+            _ => {
+                let adt_uri = doc.lock().unwrap().adt_uri().to_owned();
+                SourceCodeDocument::fetch(uri.as_str(), &adt_uri, &ctx.adt_client)
+                    .await
+                    .ok()?
+                    .raw_content()
+            }
+        };
+
+        let mut doc = doc.lock().unwrap();
+        doc.open(version, &content, self.position_encoding());
+        Some(doc.diagnostics())
+    }
+
+    pub async fn write(&self, params: WriteFileParams) -> Result<WriteFileResult> {
+        let ctx = self.context()?;
+        let doc = ctx
+            .fetch_document(&params.uri)
+            .ok_or_else(|| error::document_not_open(&params.uri))?;
+
+        let (adt_uri, content) = {
+            let mut doc = doc.lock().unwrap();
+            if let Some(content) = &params.content {
+                doc.replace_content(content);
+            }
+            (doc.adt_uri().to_owned(), doc.raw_content())
+        };
+
+        let object = SourceCodeObject::from_object_uri(&adt_uri).ok_or_else(|| {
+            Error::invalid_params(format!("Writing '{adt_uri}' is not supported."))
+        })?;
+
+        // The lock is bound to the user session, so a dedicated one is used for the
+        // duration of the write. Destroying it releases the lock should unlocking fail.
+        let session = ctx.adt_client.create_user_session();
+        let result = self
+            .write_source(&ctx.adt_client, session, &object, &content)
+            .await;
+        let _ = ctx.adt_client.destroy_user_session(session).await;
+
+        // Replacing the content outdates the diagnostics the same as a change does.
+        if result.is_ok() && params.content.is_some() {
+            let (version, diagnostics) = {
+                let doc = doc.lock().unwrap();
+                (doc.version(), doc.diagnostics())
+            };
+            if let (Some(version), Ok(uri)) = (version, Url::parse(&params.uri)) {
+                self.client
+                    .publish_diagnostics(uri.clone(), diagnostics, Some(version))
+                    .await;
+                self.schedule_check(uri, version);
+            }
+        }
+        result
+    }
+
+    /// Locks the object, updates its source code and unlocks it again.
+    async fn write_source(
+        &self,
+        client: &AdtClient,
+        session: UserSessionId,
+        object: &SourceCodeObject<'_>,
+        content: &str,
+    ) -> Result<WriteFileResult> {
+        let object_uri = object.object_uri();
+
+        // Changes to non-local objects must be recorded in a transport request, which the
+        // user may have to pick. This must happen before the object is locked.
+        let transport = self.select_transport(client, &object_uri).await?;
+
+        let lock = LockBuilder::default()
+            .object_uri(&object_uri)
+            .access_mode(AccessMode::Modify)
+            .build()
+            .map_err(|_| Error::internal_error())?
+            .dispatch(client, session)
+            .await
+            .map_err(|e| error::backend_error(&e))?
+            .take()
+            .into_body()
+            .inner();
+
+        let unlock = UnlockBuilder::default()
+            .object_uri(&object_uri)
+            .lock_handle(&lock.lock_handle)
+            .build()
+            .map_err(|_| Error::internal_error())?;
+
+        // The request the object is locked in takes precedence, e.g. if it was recorded
+        // in the meantime.
+        let transport = Some(lock.transport_number.clone())
+            .filter(|t| !t.is_empty())
+            .or(transport);
+
+        let mut update = UpdateSourceCodeBuilder::default();
+        update
+            .object(object.clone())
+            .lock_handle(&lock.lock_handle)
+            .content(content);
+        if let Some(transport) = &transport {
+            update.corr_nr(transport.as_str());
+        }
+        let update = update
+            .build()
+            .map_err(|_| Error::internal_error())?
+            .dispatch(client, session)
+            .await;
+
+        let unlocked = unlock.dispatch(client, session).await;
+        update.map_err(|e| error::backend_error(&e))?;
+        unlocked.map_err(|e| error::backend_error(&e))?;
+
+        Ok(WriteFileResult { transport })
+    }
+}
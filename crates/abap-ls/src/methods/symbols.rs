@@ -0,0 +1,86 @@
+use adt_query::models::adtcore::ObjectReference;
+use tower_lsp::jsonrpc::{Request, Result};
+use tower_lsp::lsp_types::{
+    MessageType, Url, WorkspaceSymbolParams, WorkspaceSymbolResponse, request::Request as _,
+    request::WorkspaceSymbolRequest,
+};
+
+use abap_lsp::{navigation, symbols};
+
+use crate::backend::Backend;
+
+/// The method **`workspace/symbol`** requests are handled as.
+///
+/// tower-lsp only lets `LanguageServer::symbol` respond with located symbols, locating
+/// an object that was not expanded in the filesystem yet requires a request of its
+/// properties for every result though. The requests are routed to
+/// [`Backend::workspace_symbol`] instead, which responds with workspace symbols whose
+/// location is resolved through `workspaceSymbol/resolve` once the user picks one.
+pub const WORKSPACE_SYMBOL: &str = "abap/workspaceSymbol";
+
+/// Routes `workspace/symbol` requests to [`WORKSPACE_SYMBOL`], see there.
+pub fn route_workspace_symbol(request: Request) -> Request {
+    if request.method() != WorkspaceSymbolRequest::METHOD {
+        return request;
+    }
+    let (_, id, params) = request.into_parts();
+    let mut builder = Request::build(WORKSPACE_SYMBOL);
+    if let Some(id) = id {
+        builder = builder.id(id);
+    }
+    if let Some(params) = params {
+        builder = builder.params(params);
+    }
+    builder.finish()
+}
+
+impl Backend {
+    /// Searches the entire repository rather than the objects expanded in the filesystem.
+    ///
+    /// Objects expanded in the filesystem are located right away, others are located
+    /// once the symbol is resolved, see [`symbols::unlocated_workspace_symbol`].
+    pub async fn workspace_symbol(
+        &self,
+        params: WorkspaceSymbolParams,
+    ) -> Result<Option<WorkspaceSymbolResponse>> {
+        let ctx = self.context()?;
+        let objects = self.search_repository(&params.query).await;
+
+        let tree = ctx.filetree.lock().await;
+        let symbols = objects
+            .iter()
+            .filter_map(|object| match tree.find_object(&object.uri) {
+                Some(id) => {
+                    let uri = Url::parse(&navigation::document_uri(&tree, id)).ok()?;
+                    Some(symbols::workspace_symbol(object, uri))
+                }
+                None => symbols::unlocated_workspace_symbol(object, ctx.system_id()),
+            })
+            .collect();
+        Ok(Some(WorkspaceSymbolResponse::Nested(symbols)))
+    }
+
+    /// The objects of the repository whose name matches the query, except for packages
+    /// as they are folders of the filesystem rather than documents.
+    async fn search_repository(&self, query: &str) -> Vec<ObjectReference> {
+        let Ok(ctx) = self.context() else {
+            return vec![];
+        };
+        if query.trim().is_empty() {
+            return vec![];
+        }
+
+        match symbols::search_objects(&ctx.adt_client, query).await {
+            Ok(mut objects) => {
+                objects.retain(|o| o.object_type.as_deref() != Some("DEVC/K"));
+                objects
+            }
+            Err(e) => {
+                self.client
+                    .log_message(MessageType::WARNING, format!("Quick search failed: {e}"))
+                    .await;
+                vec![]
+            }
+        }
+    }
+}
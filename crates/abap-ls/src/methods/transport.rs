@@ -0,0 +1,246 @@
+use abap_lsp::context::AdtClient;
+use adt_query::{
+    api::cts::{
+        CheckTransportBuilder, CreateObjectTransportBuilder, CreateTransportRequestBuilder,
+        TransportRequestsBuilder,
+    },
+    dispatch::StatelessDispatch,
+    models::cts::{TransportCheck, TransportHeader, TransportOrganizer},
+};
+use serde::{Deserialize, Serialize};
+use tower_lsp::jsonrpc::{Error, Result};
+use tower_lsp::lsp_types::request::Request;
+
+use crate::backend::Backend;
+use crate::methods::error::{self, TRANSPORT_REQUIRED};
+
+/// Request **`abap/selectTransport`**, sent to the editor
+///
+/// Asks the user which transport request to record the change of an object in,
+/// the editor responds with `null` if the user cancelled.
+pub enum SelectTransport {}
+
+impl Request for SelectTransport {
+    type Params = SelectTransportParams;
+    type Result = Option<TransportSelection>;
+    const METHOD: &'static str = "abap/selectTransport";
+}
+
+/// Parameters for **`abap/selectTransport`**
+#[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelectTransportParams {
+    /// The ADT uri of the object being saved.
+    pub object_uri: String,
+
+    /// The package of the object.
+    pub package: String,
+
+    /// The open requests of the user the change can be recorded in, i.e. those matching
+    /// the transport layer of the package.
+    pub requests: Vec<TransportOption>,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransportOption {
+    /// The number of the request, e.g. `A4HK900089`
+    pub number: String,
+
+    pub description: String,
+
+    pub owner: String,
+
+    /// The system the request is transported to.
+    pub target: String,
+}
+
+/// Response of **`abap/selectTransport`**
+#[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum TransportSelection {
+    /// One of the offered requests.
+    Existing { number: String },
+
+    /// A request to create for the change, unless specified the backend derives the
+    /// target from the transport layer of the package.
+    New {
+        description: String,
+        target: Option<String>,
+    },
+}
+
+impl Backend {
+    /// Determines the transport request to record the change of a non-local object in,
+    /// asking the user through **`abap/selectTransport`** unless it is already decided.
+    ///
+    /// Must be called before locking the object, as the lock would be held while the user
+    /// decides. Returns `None` if the change does not have to be recorded after all.
+    pub async fn select_transport(
+        &self,
+        client: &AdtClient,
+        object_uri: &str,
+    ) -> Result<Option<String>> {
+        let check = CheckTransportBuilder::default()
+            .object_uri(object_uri)
+            .build()
+            .map_err(|_| Error::internal_error())?
+            .dispatch(client)
+            .await
+            .map_err(|e| error::backend_error(&e))?
+            .take()
+            .into_body()
+            .inner();
+
+        if !check.requires_transport() {
+            return Ok(None);
+        }
+        // Objects already recorded in a request are locked by it, so it has to be used.
+        if let Some(request) = check.locking_request() {
+            return Ok(Some(request.number.clone()));
+        }
+
+        let organizer = TransportRequestsBuilder::default()
+            .user(client.username())
+            .build()
+            .map_err(|_| Error::internal_error())?
+            .dispatch(client)
+            .await
+            .map_err(|e| error::backend_error(&e))?
+            .take()
+            .into_body();
+
+        let params = SelectTransportParams {
+            object_uri: object_uri.to_owned(),
+            package: check.package.clone(),
+            requests: transport_options(&organizer, &check),
+        };
+        let selection = self
+            .client
+            .send_request::<SelectTransport>(params)
+            .await?
+            .ok_or_else(|| {
+                error::server_error(
+                    TRANSPORT_REQUIRED,
+                    format!("{object_uri} requires a transport request, but none was selected."),
+                )
+            })?;
+
+        match selection {
+            TransportSelection::Existing { number } => Ok(Some(number)),
+            TransportSelection::New {
+                description,
+                target: Some(target),
+            } => create_request(client, &description, &target)
+                .await
+                .map(Some),
+            TransportSelection::New {
+                description,
+                target: None,
+            } => create_object_request(client, object_uri, &check.package, &description)
+                .await
+                .map(Some),
+        }
+    }
+}
+
+/// Creates a workbench request for the user, returning its number.
+async fn create_request(client: &AdtClient, description: &str, target: &str) -> Result<String> {
+    let result = CreateTransportRequestBuilder::default()
+        .description(description)
+        .target(target)
+        .owner(client.username())
+        .build()
+        .map_err(|_| Error::internal_error())?
+        .dispatch(client)
+        .await
+        .map_err(|e| error::backend_error(&e))?
+        .take()
+        .into_body();
+
+    result
+        .request
+        .as_ref()
+        .map(|request| request.number.clone())
+        .ok_or_else(Error::internal_error)
+}
+
+/// Creates a workbench request for the change of an object, returning its number.
+async fn create_object_request(
+    client: &AdtClient,
+    object_uri: &str,
+    package: &str,
+    description: &str,
+) -> Result<String> {
+    let response = CreateObjectTransportBuilder::default()
+        .object_uri(object_uri)
+        .package(package)
+        .description(description)
+        .build()
+        .map_err(|_| Error::internal_error())?
+        .dispatch(client)
+        .await
+        .map_err(|e| error::backend_error(&e))?;
+
+    // The response is the uri of the request, e.g. `/com.sap.cts/object_record/A4HK900089`
+    let uri = response.take().into_body().inner();
+    uri.trim()
+        .rsplit('/')
+        .next()
+        .filter(|number| !number.is_empty())
+        .map(str::to_owned)
+        .ok_or_else(Error::internal_error)
+}
+
+/// The open workbench requests of the user that go to one of the targets the check
+/// offers requests for, along with the offered requests themselves.
+///
+/// The check only knows the targets of the transport layer of the package, requests
+/// of the user to other targets can not record the object.
+fn transport_options(
+    organizer: &TransportOrganizer,
+    check: &TransportCheck,
+) -> Vec<TransportOption> {
+    let offered = check.transports();
+    let mut options: Vec<TransportOption> = organizer
+        .workbench
+        .iter()
+        .flat_map(|category| &category.targets)
+        .filter(|target| {
+            offered
+                .iter()
+                .any(|request| same_target(&request.target, &target.name))
+        })
+        .filter_map(|target| Some((target, target.modifiable.as_ref()?)))
+        .flat_map(|(target, requests)| {
+            requests.requests.iter().map(|request| TransportOption {
+                number: request.number.clone(),
+                description: request.description.clone(),
+                owner: request.owner.clone(),
+                target: target.name.clone(),
+            })
+        })
+        .collect();
+
+    for request in offered {
+        if !options.iter().any(|option| option.number == request.number) {
+            options.push(transport_option(request));
+        }
+    }
+    options
+}
+
+/// Whether two names denote the same target, the organizer encloses them in slashes,
+/// e.g. `/A4H/`, while request headers do not.
+fn same_target(a: &str, b: &str) -> bool {
+    a.trim_matches('/') == b.trim_matches('/')
+}
+
+fn transport_option(request: &TransportHeader) -> TransportOption {
+    TransportOption {
+        number: request.number.clone(),
+        description: request.description.clone(),
+        owner: request.owner.clone(),
+        target: request.target.clone(),
+    }
+}
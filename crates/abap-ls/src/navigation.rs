@@ -0,0 +1,316 @@
+use adt_query::{
+    api::{
+        navigation::NavigationTargetBuilder, object::SourceCodeObject,
+        repository::ObjectPropertiesBuilder,
+    },
+    dispatch::StatelessDispatch,
+    error::OperationError,
+    models::{
+        adtcore::{ObjectReference, ObjectReferenceBuilder, SourceLocation},
+        class::ClassInclude,
+        vfs::Facet,
+    },
+};
+use slotmap::DefaultKey;
+use tower_lsp::lsp_types::{Location, Position, Range, Url};
+use vfs::{
+    nodes::{Group, VirtualNode, VirtualNodeData},
+    tree::VirtualFileTree,
+};
+
+use crate::context::{AdtClient, ClientContext};
+
+/// The scheme of the document URIs of the editor.
+pub const URI_SCHEME: &str = "adt";
+
+/// Replaces slashes in names of the filesystem, e.g. of namespaced objects like `/ABC/PROG`
+const FAKE_FORWARD_SLASH: &str = " ⁄ ";
+
+/// Resolves the definition of the element at a (1-based) line and (0-based) column
+/// of the source through the navigation of the backend.
+pub async fn definition_target(
+    client: &AdtClient,
+    adt_uri: &str,
+    content: &str,
+    line: u32,
+    column: u32,
+) -> Result<SourceLocation, OperationError> {
+    let operation = NavigationTargetBuilder::default()
+        .source_uri(source_uri(adt_uri))
+        .source(content)
+        .line(line)
+        .column(column)
+        .build()
+        .map_err(|_| OperationError::UninitializedField("source_uri"))?;
+
+    let response = operation.dispatch(client).await?;
+    Ok(SourceLocation::parse(&response.take().into_body().uri))
+}
+
+/// The URI of the object a source belongs to, e.g. the class of one of its includes.
+///
+/// `/sap/bc/adt/oo/classes/zcl_x/includes/implementations` -> `/sap/bc/adt/oo/classes/zcl_x`
+pub fn object_uri(source_uri: &str) -> &str {
+    ["/source/", "/includes/"]
+        .iter()
+        .filter_map(|segment| source_uri.find(segment))
+        .min()
+        .map_or(source_uri, |end| &source_uri[..end])
+}
+
+/// The URI of the source of an object, i.e. its main source unless the uri already
+/// refers to a source like the include of a class.
+///
+/// `/sap/bc/adt/programs/programs/zdemo1` -> `/sap/bc/adt/programs/programs/zdemo1/source/main`
+pub fn source_uri(adt_uri: &str) -> String {
+    match object_uri(adt_uri) == adt_uri {
+        true => format!("{adt_uri}/source/main"),
+        false => adt_uri.to_owned(),
+    }
+}
+
+/// The URI of the document of a class include, which the editor names after the
+/// document of the class, e.g. `adt://A4H/.../ZCL_ORDER.clas?testclasses`
+pub fn include_document_uri(class_uri: &str, include: ClassInclude) -> String {
+    format!("{class_uri}?{}", include.as_str())
+}
+
+/// The ADT uri of the source a document of an object shows, i.e. the class include
+/// named by its uri (see [`include_document_uri`]) or the object itself.
+pub fn document_source_uri(object_uri: &str, uri: &Url) -> String {
+    let include = ClassInclude::ALL
+        .iter()
+        .filter(|include| **include != ClassInclude::Main)
+        .find(|include| uri.query() == Some(include.as_str()));
+    match (include, SourceCodeObject::from_object_uri(object_uri)) {
+        (Some(include), Some(SourceCodeObject::GlobalClass(_))) => {
+            format!("{object_uri}/{}", include.source_path())
+        }
+        _ => object_uri.to_owned(),
+    }
+}
+
+/// Maps a location in a source of the backend to a location in a document of the editor.
+///
+/// The object is looked up among the open documents first, otherwise it is located in
+/// the filesystem, see [`object_document_uri`]. Positions only refer to the document if
+/// the location is within its source, i.e. the main source or an include of a class,
+/// which opens as a document of its own (see [`include_document_uri`]). Otherwise (e.g.
+/// an include of a function group) the start of the document is used.
+pub async fn document_location(ctx: &ClientContext, location: &SourceLocation) -> Option<Location> {
+    let object_uri = object_uri(&location.uri);
+    let include = match SourceCodeObject::from_object_uri(&location.uri) {
+        Some(SourceCodeObject::ClassInclude(_, include)) => Some(include),
+        _ => None,
+    };
+    let is_source = include.is_some() || location.uri == source_uri(object_uri);
+    let position = |pos: Option<(u32, u32)>| {
+        pos.filter(|_| is_source)
+            .map(|(line, column)| Position::new(line.saturating_sub(1), column))
+    };
+    let start = position(location.start).unwrap_or_default();
+    let end = position(location.end).unwrap_or(start);
+
+    let open = ctx
+        .find_document_by_adt_uri(&location.uri)
+        .or_else(|| ctx.find_document_by_adt_uri(object_uri));
+    let vfs_uri = match open {
+        Some(vfs_uri) => vfs_uri,
+        None => {
+            let uri = object_document_uri(ctx, &object_reference(object_uri)).await?;
+            match include {
+                Some(include) => include_document_uri(&uri, include),
+                None => uri,
+            }
+        }
+    };
+
+    // Open documents know their encoding, others are opened in UTF-16 by default.
+    let range = match ctx.fetch_document(&vfs_uri) {
+        Some(doc) => {
+            let doc = doc.lock().unwrap();
+            Range::new(doc.from_utf16(start), doc.from_utf16(end))
+        }
+        None => Range::new(start, end),
+    };
+    Some(Location::new(Url::parse(&vfs_uri).ok()?, range))
+}
+
+/// Converts a 1-based line and 0-based column of the backend to a range at that position.
+fn to_range((line, column): (u32, u32)) -> Range {
+    let position = Position::new(line.saturating_sub(1), column);
+    Range::new(position, position)
+}
+
+/// Converts a position of the backend to a range in the open document with the uri,
+/// the position is kept as is if the document is not open.
+pub fn document_range(ctx: &ClientContext, uri: Option<&str>, start: (u32, u32)) -> Range {
+    let range = to_range(start);
+    match uri.and_then(|uri| ctx.fetch_document(uri)) {
+        Some(doc) => {
+            let position = doc.lock().unwrap().from_utf16(range.start);
+            Range::new(position, position)
+        }
+        None => range,
+    }
+}
+
+/// Builds the reference to an object from its ADT uri (or the uri of one of its sources),
+/// the name being the last segment.
+pub fn object_reference(adt_uri: &str) -> ObjectReference {
+    let adt_uri = object_uri(adt_uri);
+    let name = adt_uri
+        .rsplit('/')
+        .next()
+        .unwrap_or_default()
+        .replace("%2f", "/")
+        .replace("%2F", "/")
+        .to_uppercase();
+
+    ObjectReferenceBuilder::default()
+        .uri(adt_uri)
+        .name(name)
+        .build()
+        .unwrap()
+}
+
+/// Builds the reference to a test class (or one of its methods) in the source of an
+/// object, which limits a test run to it, e.g.
+/// `/sap/bc/adt/oo/classes/zcl_x/includes/testclasses#type=CLAS%2FOCL;name=LTCL_X`
+///
+/// Test classes of global classes are located in their `testclasses` include. Methods
+/// are named by their class, padded to the length of class names, and their own name.
+pub fn test_reference(adt_uri: &str, class: &str, method: Option<&str>) -> ObjectReference {
+    let object_uri = object_uri(adt_uri);
+    let (uri, class_type, method_type) = match SourceCodeObject::from_object_uri(adt_uri) {
+        Some(SourceCodeObject::ClassInclude(..)) => (adt_uri.to_owned(), "CLAS/OCL", "CLAS/OLI"),
+        Some(SourceCodeObject::GlobalClass(_)) => (
+            format!("{object_uri}/{}", ClassInclude::TestClasses.source_path()),
+            "CLAS/OCL",
+            "CLAS/OLI",
+        ),
+        _ => (object_uri.to_owned(), "PROG/PLL", "PROG/PLM"),
+    };
+    let class = class.to_uppercase();
+    let (object_type, name, fragment) = match method {
+        Some(method) => {
+            let method = method.to_uppercase();
+            let fragment = format!("{class:<30}{method}").replace(' ', "%20");
+            (method_type, method, fragment)
+        }
+        None => (class_type, class.clone(), class),
+    };
+
+    ObjectReferenceBuilder::default()
+        .uri(format!(
+            "{uri}#type={};name={fragment}",
+            object_type.replace('/', "%2F")
+        ))
+        .object_type(object_type)
+        .name(name)
+        .build()
+        .unwrap()
+}
+
+/// Builds the URI of a node as the editor names it in its filesystem, e.g.
+/// `adt://A4H/System Library/Z_PACKAGE/ZCL_ORDER_SERVICE.clas`
+pub fn document_uri(tree: &VirtualFileTree, id: DefaultKey) -> String {
+    let path = tree
+        .path(id)
+        .into_iter()
+        .map(display_name)
+        .collect::<Vec<_>>()
+        .join("/");
+    format!("{URI_SCHEME}://{path}")
+}
+
+/// Finds the object a document of the editor is named after among the nodes of the
+/// filesystem, returning the ADT uri of the source it shows, see [`document_uri`] and
+/// [`document_source_uri`].
+pub fn document_adt_uri(tree: &VirtualFileTree, uri: &Url) -> Option<String> {
+    let mut document = uri.clone();
+    document.set_query(None);
+    tree.objects()
+        .find(|(id, _)| Url::parse(&document_uri(tree, *id)).is_ok_and(|u| u == document))
+        .map(|(_, obj)| document_source_uri(&obj.adt_uri, uri))
+}
+
+/// Builds the URI of an object as the editor names it in its filesystem, also if the
+/// object was not expanded in the filesystem yet.
+///
+/// The path of such objects is built from their properties, i.e. the packages, group and
+/// type the filesystem places them in, e.g.
+/// `adt://A4H/System Library/Z_SALES/Z_ORDERS/Source Code Library/Classes/ZCL_ORDER.clas`
+pub async fn object_document_uri(ctx: &ClientContext, object: &ObjectReference) -> Option<String> {
+    let system = {
+        let tree = ctx.filetree.lock().await;
+        if let Some(id) = tree.find_object(&object.uri) {
+            return Some(document_uri(&tree, id));
+        }
+        display_name(tree.lookup(tree.root())?)
+    };
+
+    let operation = ObjectPropertiesBuilder::default()
+        .object_uri(object.uri.as_str())
+        .include_facet(Facet::Package)
+        .include_facet(Facet::Owner)
+        .include_facet(Facet::Group)
+        .include_facet(Facet::Type)
+        .build()
+        .ok()?;
+    let response = operation.dispatch(&ctx.adt_client).await.ok()?;
+    let properties = response.body();
+
+    let facet = |facet: Facet| {
+        properties
+            .properties
+            .iter()
+            .filter(move |p| p.facet == facet)
+            .map(|p| p.display_name.replace('/', FAKE_FORWARD_SLASH))
+    };
+    // Local objects are grouped by their owner rather than their package.
+    let mut path = match properties.object.package.as_str() {
+        "$TMP" => {
+            let owner = facet(Facet::Owner).next()?;
+            vec![system, Group::LocalObjects.display_name().to_owned(), owner]
+        }
+        _ => [system, Group::SystemLibrary.display_name().to_owned()]
+            .into_iter()
+            .chain(facet(Facet::Package))
+            .collect(),
+    };
+    path.extend(facet(Facet::Group));
+    path.extend(facet(Facet::Type));
+
+    let object_type = object
+        .object_type
+        .as_deref()
+        .unwrap_or(&properties.object.kind);
+    path.push(file_name(&object.name, object_type));
+    Some(format!("{URI_SCHEME}://{}", path.join("/")))
+}
+
+/// The name of a node in the filesystem of the editor, see [`file_name`].
+fn display_name(node: &VirtualNode) -> String {
+    match &node.data {
+        VirtualNodeData::RepositoryObject(obj) => {
+            let object_type = serde_json::to_value(&obj.object_kind)
+                .ok()
+                .and_then(|v| v.as_str().map(str::to_owned))
+                .unwrap_or_default();
+            file_name(node.name(), &object_type)
+        }
+        _ => node.name().replace('/', FAKE_FORWARD_SLASH),
+    }
+}
+
+/// The name of a repository object in the filesystem of the editor, suffixed with its
+/// object type, e.g `ZCL_ORDER_SERVICE.clas` for `CLAS/OC`
+fn file_name(name: &str, object_type: &str) -> String {
+    let extension: String = object_type.chars().take(4).collect();
+    format!(
+        "{}.{}",
+        name.replace('/', FAKE_FORWARD_SLASH),
+        extension.to_lowercase()
+    )
+}
@@ -0,0 +1,59 @@
+use adt_query::{
+    api::usagereferences::{UsageReferencesBuilder, UsageSnippetsBuilder},
+    dispatch::StatelessDispatch,
+    error::OperationError,
+    models::{adtcore::SourceLocation, usagereferences::UsageSnippetRequestBuilder},
+};
+
+use crate::context::AdtClient;
+use crate::navigation;
+
+/// Searches the system for usages of the element at a (1-based) line and (0-based)
+/// column of the saved source, i.e. the where-used list.
+///
+/// The search only yields the using objects, their code snippets are retrieved in a
+/// second request to locate the usages within their sources.
+pub async fn usage_locations(
+    client: &AdtClient,
+    adt_uri: &str,
+    line: u32,
+    column: u32,
+) -> Result<Vec<SourceLocation>, OperationError> {
+    let operation = UsageReferencesBuilder::default()
+        .uri(navigation::source_uri(adt_uri))
+        .line(line)
+        .column(column)
+        .build()
+        .map_err(|_| OperationError::UninitializedField("uri"))?;
+    let result = operation.dispatch(client).await?.take().into_body();
+
+    let identifiers: Vec<String> = result
+        .results()
+        .filter_map(|obj| obj.object_identifier.clone())
+        .collect();
+    if identifiers.is_empty() {
+        return Ok(vec![]);
+    }
+    let mut request = UsageSnippetRequestBuilder::default();
+    for identifier in identifiers {
+        request.object_identifier(identifier);
+    }
+
+    let operation = UsageSnippetsBuilder::default()
+        .request(
+            request
+                .build()
+                .map_err(|_| OperationError::UninitializedField("request"))?,
+        )
+        .build()
+        .map_err(|_| OperationError::UninitializedField("request"))?;
+    let snippets = operation.dispatch(client).await?.take().into_body();
+
+    Ok(snippets
+        .objects
+        .objects
+        .iter()
+        .flat_map(|obj| obj.snippets.snippets.iter())
+        .map(|snippet| snippet.location())
+        .collect())
+}
@@ -0,0 +1,62 @@
+use serde::Deserialize;
+
+/// The settings of the server, passed as `initializationOptions` and updated through
+/// `workspace/didChangeConfiguration`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Settings {
+    pub formatting: FormattingSettings,
+}
+
+impl Settings {
+    /// Reads the settings from the configuration of the editor, which may contain the
+    /// settings themselves or nest them in their `abap` section.
+    pub fn from_value(value: &serde_json::Value) -> Option<Self> {
+        let value = value.get("abap").unwrap_or(value);
+        serde_json::from_value(value.clone()).ok()
+    }
+}
+
+/// Which formatter formats the documents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Formatter {
+    /// The formatter of the server, works offline on the syntax tree.
+    #[default]
+    Native,
+
+    /// The pretty printer of the backend, according to the settings of the user.
+    Backend,
+}
+
+/// How the native formatter converts the case of keywords.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum KeywordCase {
+    #[default]
+    Upper,
+    Lower,
+    /// Keywords are kept as written.
+    Preserve,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct FormattingSettings {
+    pub formatter: Formatter,
+
+    pub keyword_case: KeywordCase,
+
+    /// The number of spaces to indent by per block, `0` keeps the indentation as written.
+    pub indent_width: usize,
+}
+
+impl Default for FormattingSettings {
+    fn default() -> Self {
+        Self {
+            formatter: Formatter::default(),
+            keyword_case: KeywordCase::default(),
+            indent_width: 2,
+        }
+    }
+}
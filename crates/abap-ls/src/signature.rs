@@ -0,0 +1,802 @@
+use std::sync::Arc;
+
+use adt_query::{
+    api::object::ObjectSourceRequestBuilder, dispatch::StatelessDispatch, response::CacheControlled,
+};
+use tower_lsp::lsp_types::{
+    Documentation, ParameterInformation, ParameterLabel, SignatureHelp, SignatureInformation,
+};
+
+use crate::context::ClientContext;
+use crate::declarations::{self, DeclarationKind};
+use crate::document;
+use crate::navigation;
+use crate::statements::{self, Statement, Token, TokenKind};
+
+/// Keywords of constructor expressions that are followed by a type rather than a
+/// callee, e.g. `VALUE #(` or `CONV string(`
+const CONSTRUCTOR_OPERATORS: &[&str] = &[
+    "VALUE",
+    "CONV",
+    "CAST",
+    "EXACT",
+    "REF",
+    "COND",
+    "SWITCH",
+    "REDUCE",
+    "FILTER",
+    "CORRESPONDING",
+];
+
+/// What is called by a [`Call`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Callee {
+    /// A method, e.g `lo_obj->meth(`, `zcl_x=>meth(` or `CALL METHOD lo_obj->meth`
+    Method(String),
+
+    /// The constructor of a class, e.g `NEW zcl_x(`
+    Constructor(String),
+
+    /// A function module, e.g `CALL FUNCTION 'Z_ORDER_CREATE'`
+    Function(String),
+}
+
+/// A call that is being written at a position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Call {
+    pub callee: Callee,
+
+    /// The byte offset of the name of the callee, used to navigate to its definition.
+    pub callee_byte: usize,
+
+    /// The names of the parameters passed before the position, e.g `iv_a` and `iv_b`
+    /// of `meth( iv_a = 1 iv_b = `
+    pub named_arguments: Vec<String>,
+}
+
+/// The kind of a parameter of a function module or method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParameterKind {
+    Importing,
+    Exporting,
+    Changing,
+    /// Only available for function modules.
+    Tables,
+    /// Only available for methods.
+    Returning,
+}
+
+impl ParameterKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Importing => "IMPORTING",
+            Self::Exporting => "EXPORTING",
+            Self::Changing => "CHANGING",
+            Self::Tables => "TABLES",
+            Self::Returning => "RETURNING",
+        }
+    }
+}
+
+/// A parameter in the signature of a function module or method.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Parameter {
+    /// The name of the parameter as written in the source, e.g `iv_customer`
+    pub name: String,
+
+    /// The kind of the parameter, see [`ParameterKind`]
+    pub kind: ParameterKind,
+
+    /// The typing of the parameter, e.g `TYPE kunnr` or `TYPE REF TO zcl_order`
+    pub typing: Option<String>,
+
+    /// Whether the parameter is passed by value (`VALUE(...)`)
+    pub pass_by_value: bool,
+
+    /// Whether the parameter is `OPTIONAL`
+    pub optional: bool,
+
+    /// The `DEFAULT` value of the parameter, implies that it is optional.
+    pub default: Option<String>,
+}
+
+impl Parameter {
+    pub fn is_optional(&self) -> bool {
+        self.optional || self.default.is_some()
+    }
+}
+
+/// The signature of a function module or method.
+///
+/// ADT does not provide the parameters as part of the metadata of function modules,
+/// they are part of the source code instead, see [`function_signature`] and
+/// [`method_signature`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FunctionSignature {
+    /// The name of the function module or method as written in the source
+    pub name: String,
+
+    /// The parameters in order of declaration
+    pub parameters: Vec<Parameter>,
+
+    /// The classic `EXCEPTIONS` and class-based `RAISING` exceptions
+    pub exceptions: Vec<String>,
+}
+
+impl FunctionSignature {
+    /// Returns the parameters of a certain kind.
+    pub fn parameters_of(&self, kind: ParameterKind) -> impl Iterator<Item = &Parameter> {
+        self.parameters.iter().filter(move |p| p.kind == kind)
+    }
+
+    fn parse_sections(&mut self, tokens: &[&Token]) {
+        enum Section {
+            Parameters(ParameterKind),
+            Exceptions,
+        }
+
+        let mut section: Option<Section> = None;
+        let mut idx = 0;
+
+        while let Some(token) = tokens.get(idx) {
+            idx += 1;
+            let keyword = match token.kind {
+                TokenKind::Word => token.text.to_uppercase(),
+                _ => String::new(),
+            };
+            match keyword.as_str() {
+                "IMPORTING" => section = Some(Section::Parameters(ParameterKind::Importing)),
+                "EXPORTING" => section = Some(Section::Parameters(ParameterKind::Exporting)),
+                "CHANGING" => section = Some(Section::Parameters(ParameterKind::Changing)),
+                "TABLES" => section = Some(Section::Parameters(ParameterKind::Tables)),
+                "RETURNING" => section = Some(Section::Parameters(ParameterKind::Returning)),
+                // Additions of method declarations, e.g. `PREFERRED PARAMETER` or `FOR TESTING`
+                "PREFERRED" | "ABSTRACT" | "FINAL" | "REDEFINITION" | "FOR" => section = None,
+                "EXCEPTIONS" | "RAISING" => section = Some(Section::Exceptions),
+                _ => match &section {
+                    Some(Section::Exceptions) => self.exceptions.push(token.text.clone()),
+                    Some(Section::Parameters(kind)) => {
+                        let (name, pass_by_value) = parameter_name(&token.text);
+                        let mut parameter = Parameter {
+                            name,
+                            kind: *kind,
+                            typing: None,
+                            pass_by_value,
+                            optional: false,
+                            default: None,
+                        };
+
+                        while let Some(next) = tokens.get(idx) {
+                            if next.is("TYPE") || next.is("LIKE") || next.is("STRUCTURE") {
+                                let end = typing_end(tokens, idx + 1);
+                                let words: Vec<&str> =
+                                    tokens[idx..end].iter().map(|t| t.text.as_str()).collect();
+                                parameter.typing = Some(words.join(" "));
+                                idx = end;
+                            } else if next.is("OPTIONAL") {
+                                idx += 1;
+                                parameter.optional = true;
+                            } else if next.is("DEFAULT") {
+                                parameter.default = tokens.get(idx + 1).map(|t| t.text.clone());
+                                idx += 2;
+                            } else {
+                                break;
+                            }
+                        }
+                        self.parameters.push(parameter);
+                    }
+                    None => {}
+                },
+            }
+        }
+    }
+}
+
+/// Finds the innermost call enclosing the byte offset within the statements.
+pub fn call_at(statements: &[Statement], byte: usize) -> Option<Call> {
+    let statement = statements.iter().rev().find(|s| s.start_byte < byte)?;
+    let terminated = statement
+        .tokens
+        .last()
+        .is_some_and(|t| t.end_byte < statement.end_byte);
+    if terminated && byte >= statement.end_byte {
+        return None;
+    }
+    let tokens: Vec<&Token> = statement
+        .tokens
+        .iter()
+        .take_while(|t| t.start_byte < byte)
+        .collect();
+
+    // Track the parentheses opened before the position, the innermost one is the call.
+    let mut open: Vec<(usize, usize)> = vec![];
+    for (index, token) in tokens.iter().enumerate() {
+        if token.kind != TokenKind::Word {
+            continue;
+        }
+        let end = (byte - token.start_byte).min(token.text.len());
+        for (offset, c) in token.text[..end].char_indices() {
+            match c {
+                '(' => open.push((index, offset)),
+                ')' => {
+                    open.pop();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // Parentheses of expressions like `CONV string( ... )` are skipped in favor of
+    // the call enclosing them.
+    if let Some(call) = open
+        .iter()
+        .rev()
+        .find_map(|&(index, offset)| parenthesized_call(&tokens, index, offset))
+    {
+        return Some(call);
+    }
+
+    let word = |i: usize| tokens.get(i).map(|t| t.text.to_uppercase());
+    if word(0).as_deref() != Some("CALL") || tokens.len() < 3 {
+        return None;
+    }
+    let callee_token = tokens[2];
+    if callee_token.end_byte >= byte {
+        return None;
+    }
+    let is_literal = callee_token.text.starts_with(['\'', '`']);
+    let callee = match word(1).as_deref() {
+        // Dynamic calls, e.g. `CALL FUNCTION lv_name`, cannot be resolved.
+        Some("FUNCTION") if is_literal => {
+            Callee::Function(callee_token.text.trim_matches(['\'', '`']).to_uppercase())
+        }
+        Some("METHOD") if !is_literal => {
+            let name = callee_token.text.trim_end_matches('(');
+            let start = name
+                .char_indices()
+                .rev()
+                .take_while(|(_, c)| is_callee_char(*c))
+                .last()
+                .map_or(name.len(), |(i, _)| i);
+            return Some(Call {
+                callee: Callee::Method(name[start..].to_owned()),
+                callee_byte: callee_token.start_byte + start,
+                named_arguments: named_arguments(&tokens[3..]),
+            });
+        }
+        _ => return None,
+    };
+    Some(Call {
+        callee,
+        callee_byte: callee_token.start_byte + 1,
+        named_arguments: named_arguments(&tokens[3..]),
+    })
+}
+
+/// The call opened by the parenthesis at an offset of a token, e.g. `lo_obj->meth(`
+fn parenthesized_call(tokens: &[&Token], index: usize, offset: usize) -> Option<Call> {
+    let token = tokens[index];
+    let text = &token.text[..offset];
+    let start = text
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| is_callee_char(*c))
+        .last()
+        .map(|(i, _)| i)?;
+    let name = text[start..].to_owned();
+
+    let previous = index.checked_sub(1).map(|i| tokens[i].text.to_uppercase());
+    let callee = match previous.as_deref() {
+        _ if start > 0 => Callee::Method(name),
+        Some("NEW") => Callee::Constructor(name),
+        Some(keyword) if CONSTRUCTOR_OPERATORS.contains(&keyword) => return None,
+        _ => Callee::Method(name),
+    };
+    Some(Call {
+        callee,
+        callee_byte: token.start_byte + start,
+        named_arguments: named_arguments(&tokens[index + 1..]),
+    })
+}
+
+/// Extracts the signature of a call from the statements of the document, i.e. of
+/// methods of local classes.
+pub fn local_signature(statements: &[Statement], call: &Call) -> Option<FunctionSignature> {
+    let declarations = declarations::declarations(statements);
+    let declared =
+        |start_byte: usize| method_declaration(statements, |name| name.start_byte == start_byte);
+    match &call.callee {
+        Callee::Method(name) => {
+            let name = name.rsplit('~').next().unwrap_or(name);
+            declarations::find(&declarations, name, call.callee_byte)
+                .filter(|d| d.kind == DeclarationKind::Method)
+                .and_then(|d| declared(d.start_byte))
+        }
+        // The first constructor declared after the class, i.e. within its definition.
+        Callee::Constructor(class) => {
+            let class = declarations
+                .iter()
+                .find(|d| d.kind == DeclarationKind::Class && d.name.eq_ignore_ascii_case(class))?;
+            declarations
+                .iter()
+                .find(|d| {
+                    d.kind == DeclarationKind::Method
+                        && d.start_byte > class.start_byte
+                        && d.name.eq_ignore_ascii_case("constructor")
+                })
+                .and_then(|d| declared(d.start_byte))
+        }
+        Callee::Function(_) => None,
+    }
+}
+
+/// Resolves the signature of a call through the backend, the callee is located by
+/// navigating to its definition from a (1-based) line and (0-based) column.
+///
+/// Signatures are cached in the context, function modules by their name and methods
+/// by the object declaring them.
+pub async fn resolve_signature(
+    ctx: &ClientContext,
+    call: &Call,
+    adt_uri: &str,
+    content: &str,
+    line: u32,
+    column: u32,
+) -> Option<Arc<FunctionSignature>> {
+    if let Callee::Function(name) = &call.callee
+        && let Some(signature) = ctx.cached_signature(&format!("FUNCTION {name}"))
+    {
+        return Some(signature);
+    }
+
+    let target =
+        navigation::definition_target(&ctx.adt_client, adt_uri, content, line, column).await;
+    let object_uri = navigation::object_uri(&target.ok()?.uri).to_owned();
+
+    let (key, method) = match &call.callee {
+        Callee::Function(name) => (format!("FUNCTION {name}"), None),
+        Callee::Method(name) => {
+            let name = name.rsplit('~').next().unwrap_or(name);
+            (format!("{object_uri}=>{name}"), Some(name))
+        }
+        Callee::Constructor(_) => (format!("{object_uri}=>CONSTRUCTOR"), Some("constructor")),
+    };
+    let key = key.to_uppercase();
+    if let Some(signature) = ctx.cached_signature(&key) {
+        return Some(signature);
+    }
+
+    let operation = ObjectSourceRequestBuilder::default()
+        .object_uri(object_uri.as_str())
+        .build()
+        .ok()?;
+    let source = match operation.dispatch(&ctx.adt_client).await.ok()? {
+        CacheControlled::Modified(response) => response.into_body().inner().into_owned(),
+        CacheControlled::NotModified(_) => return None,
+    };
+
+    let tokens = document::tokenize(&source);
+    let signature = match method {
+        Some(method) => method_signature(&statements::statements(&tokens), method)?,
+        None => function_signature(&tokens, document::tokenize)?,
+    };
+    Some(ctx.cache_signature(key, signature))
+}
+
+/// Extracts the signature of a function module from the tokens of its source, i.e.
+/// from the source-based signature of its `FUNCTION` statement.
+///
+/// Classic function modules declare the interface in the `*"Local Interface:` comment
+/// block following the statement instead, the block is split up by `tokenize` once
+/// its comment markers are removed. Returns `None` without a `FUNCTION` statement.
+pub fn function_signature(
+    tokens: &[Token],
+    tokenize: impl Fn(&str) -> Vec<Token>,
+) -> Option<FunctionSignature> {
+    let statements = statements::statements(tokens);
+    let statement = statements
+        .iter()
+        .find(|s| s.keyword().as_deref() == Some("FUNCTION"))?;
+    let mut signature = FunctionSignature {
+        name: statement.tokens.get(1)?.text.clone(),
+        ..Default::default()
+    };
+
+    if statement.tokens.len() > 2 {
+        let tokens: Vec<&Token> = statement.tokens[2..].iter().collect();
+        signature.parse_sections(&tokens);
+        return Some(signature);
+    }
+
+    let interface: Vec<&str> = tokens
+        .iter()
+        .skip_while(|t| t.start_byte < statement.end_byte)
+        .take_while(|t| t.kind == TokenKind::Comment && t.text.starts_with("*\""))
+        .map(|t| t.text.trim_start_matches("*\""))
+        .filter(|line| !line.starts_with('-') && !line.starts_with("*\""))
+        .collect();
+    let interface = tokenize(&interface.join("\n"));
+    signature.parse_sections(&interface.iter().collect::<Vec<_>>());
+    Some(signature)
+}
+
+/// Extracts the signature of a method from the statements of the class or interface
+/// declaring it, i.e. from its `METHODS` or `CLASS-METHODS` declaration.
+///
+/// Returns `None` if the method is not declared in the statements, e.g. if it is inherited.
+pub fn method_signature(statements: &[Statement], method: &str) -> Option<FunctionSignature> {
+    method_declaration(statements, |name| name.text.eq_ignore_ascii_case(method))
+}
+
+/// The signature of the method whose name in its declaration matches, the parts of
+/// chained declarations like `METHODS: a IMPORTING x TYPE i, b.` are matched on their own.
+fn method_declaration(
+    statements: &[Statement],
+    matches: impl Fn(&Token) -> bool,
+) -> Option<FunctionSignature> {
+    statements
+        .iter()
+        .filter(|s| matches!(s.keyword().as_deref(), Some("METHODS" | "CLASS-METHODS")))
+        .flat_map(Statement::parts)
+        .find(|part| part.get(1).is_some_and(|name| matches(name)))
+        .map(|part| {
+            let mut signature = FunctionSignature {
+                name: part[1].text.clone(),
+                ..Default::default()
+            };
+            signature.parse_sections(&part[2..]);
+            signature
+        })
+}
+
+/// Finds the end of a typing whose words start at the index, e.g. of
+/// `TYPE SORTED TABLE OF mara WITH UNIQUE KEY matnr`
+///
+/// The typing ends before the additions of the parameter, the next section or
+/// the next parameter, which is the word followed by its own typing.
+fn typing_end(tokens: &[&Token], start: usize) -> usize {
+    let is_typing = |t: &&Token| t.is("TYPE") || t.is("LIKE") || t.is("STRUCTURE");
+
+    // The first word is always part of the typing, e.g. `TYPE any`
+    let mut idx = (start + 1).min(tokens.len());
+    while let Some(token) = tokens.get(idx) {
+        let keyword = match token.kind {
+            TokenKind::Word => token.text.to_uppercase(),
+            _ => String::new(),
+        };
+        let ends = match keyword.as_str() {
+            "OPTIONAL" | "IMPORTING" | "EXPORTING" | "CHANGING" | "TABLES" | "RETURNING"
+            | "EXCEPTIONS" | "RAISING" | "PREFERRED" | "ABSTRACT" | "FINAL" | "REDEFINITION" => {
+                true
+            }
+            // Unless part of the key, e.g. `WITH DEFAULT KEY`
+            "DEFAULT" => !tokens[idx - 1].is("WITH"),
+            "FOR" => tokens.get(idx + 1).is_some_and(|t| t.is("TESTING")),
+            _ => {
+                keyword.starts_with("VALUE(")
+                    || keyword.starts_with("REFERENCE(")
+                    || tokens.get(idx + 1).is_some_and(is_typing)
+            }
+        };
+        if ends {
+            break;
+        }
+        idx += 1;
+    }
+    idx
+}
+
+/// Extracts the name of a parameter declaration, e.g. `VALUE(iv_name)`
+fn parameter_name(text: &str) -> (String, bool) {
+    let upper = text.to_uppercase();
+    let inner = |prefix: &str| text[prefix.len()..].trim_end_matches(')').to_string();
+
+    if upper.starts_with("VALUE(") {
+        (inner("VALUE("), true)
+    } else if upper.starts_with("REFERENCE(") {
+        (inner("REFERENCE("), false)
+    } else {
+        (text.to_string(), false)
+    }
+}
+
+/// Shows the signature of a call with the parameter being passed as active parameter.
+///
+/// Parameters are grouped by their kind as declared, optional ones in brackets, e.g.
+/// `create( IMPORTING iv_customer TYPE kunnr [iv_note TYPE string] RETURNING ro_order TYPE REF TO zcl_order )`
+///
+/// Function modules are shown without parentheses, as they are called.
+pub fn signature_help(signature: &FunctionSignature, call: &Call) -> SignatureHelp {
+    let is_function = matches!(call.callee, Callee::Function(_));
+    let mut label = signature.name.clone();
+    if !is_function {
+        label.push('(');
+    }
+    let mut parameters = vec![];
+    let mut kind = None;
+    for parameter in &signature.parameters {
+        if kind != Some(parameter.kind) {
+            kind = Some(parameter.kind);
+            label.push(' ');
+            label.push_str(parameter.kind.as_str());
+        }
+        label.push(' ');
+        let start = label.encode_utf16().count() as u32;
+        label.push_str(&parameter_label(parameter));
+        let end = label.encode_utf16().count() as u32;
+
+        parameters.push(ParameterInformation {
+            label: ParameterLabel::LabelOffsets([start, end]),
+            documentation: parameter_documentation(parameter).map(Documentation::String),
+        });
+    }
+    if !is_function {
+        label.push_str(" )");
+    }
+
+    let documentation = (!signature.exceptions.is_empty())
+        .then(|| Documentation::String(format!("Raises {}", signature.exceptions.join(", "))));
+
+    let active_parameter = active_parameter(signature, call);
+    SignatureHelp {
+        signatures: vec![SignatureInformation {
+            label,
+            documentation,
+            parameters: Some(parameters),
+            active_parameter,
+        }],
+        active_signature: Some(0),
+        active_parameter,
+    }
+}
+
+/// The parameter last passed by name, otherwise the first importing parameter of a
+/// method, which is passed without a name if it is the only one.
+fn active_parameter(signature: &FunctionSignature, call: &Call) -> Option<u32> {
+    let position = match call.named_arguments.last() {
+        Some(name) => signature
+            .parameters
+            .iter()
+            .position(|p| p.name.eq_ignore_ascii_case(name)),
+        None if !matches!(call.callee, Callee::Function(_)) => signature
+            .parameters
+            .iter()
+            .position(|p| p.kind == ParameterKind::Importing),
+        None => None,
+    };
+    position.map(|p| p as u32)
+}
+
+fn parameter_label(parameter: &Parameter) -> String {
+    let mut label = parameter.name.clone();
+    if let Some(typing) = &parameter.typing {
+        label.push(' ');
+        label.push_str(typing);
+    }
+    match parameter.is_optional() {
+        true => format!("[{label}]"),
+        false => label,
+    }
+}
+
+fn parameter_documentation(parameter: &Parameter) -> Option<String> {
+    let mut notes = vec![];
+    if parameter.pass_by_value {
+        notes.push("passed by value".to_owned());
+    }
+    if let Some(default) = &parameter.default {
+        notes.push(format!("defaults to {default}"));
+    } else if parameter.optional {
+        notes.push("optional".to_owned());
+    }
+    (!notes.is_empty()).then(|| notes.join(", "))
+}
+
+/// The names of the parameters passed in the tokens, i.e. those followed by `=`
+fn named_arguments(tokens: &[&Token]) -> Vec<String> {
+    tokens
+        .windows(2)
+        .filter(|pair| pair[1].text == "=")
+        .map(|pair| pair[0].text.clone())
+        .collect()
+}
+
+fn is_callee_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '/' | '~')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::statements::lex;
+
+    #[test]
+    fn parse_source_based_signature() {
+        let source = r#"FUNCTION z_order_create
+  IMPORTING
+    VALUE(iv_customer) TYPE kunnr
+    is_header TYPE zorder_header OPTIONAL
+    iv_mode TYPE char1 DEFAULT 'A'
+    io_logger TYPE REF TO zcl_logger OPTIONAL
+  EXPORTING
+    VALUE(ev_order) TYPE vbeln
+  TABLES
+    it_items LIKE zorder_item
+  EXCEPTIONS
+    not_found
+    locked.
+
+  " Implementation
+ENDFUNCTION."#;
+
+        let result = function_signature(&lex(source), lex).unwrap();
+        assert_eq!(result.name, "z_order_create");
+        assert_eq!(result.parameters.len(), 6);
+        assert_eq!(result.parameters[0].name, "iv_customer");
+        assert!(result.parameters[0].pass_by_value);
+        assert!(!result.parameters[0].is_optional());
+        assert!(result.parameters[2].is_optional());
+        assert_eq!(
+            result.parameters[3].typing.as_deref(),
+            Some("TYPE REF TO zcl_logger")
+        );
+        assert_eq!(result.parameters_of(ParameterKind::Tables).count(), 1);
+        assert_eq!(result.exceptions, vec!["not_found", "locked"]);
+    }
+
+    #[test]
+    fn parse_generic_and_table_typings() {
+        let source = r#"FUNCTION z_order_read
+  IMPORTING
+    it_materials TYPE STANDARD TABLE OF mara
+    it_any TYPE ANY TABLE
+    it_range TYPE RANGE OF matnr OPTIONAL
+    is_line TYPE LINE OF zorder_items
+    is_like LIKE LINE OF gt_orders
+    it_sorted TYPE SORTED TABLE OF mara WITH UNIQUE KEY matnr werks
+    it_default TYPE STANDARD TABLE OF mara WITH DEFAULT KEY
+    iv_text TYPE string DEFAULT 'a b.'
+    VALUE(iv_flag) TYPE abap_bool DEFAULT abap_true
+  EXCEPTIONS
+    not_found.
+ENDFUNCTION."#;
+
+        let result = function_signature(&lex(source), lex).unwrap();
+        let typings: Vec<_> = result
+            .parameters
+            .iter()
+            .map(|p| (p.name.as_str(), p.typing.as_deref().unwrap_or_default()))
+            .collect();
+        assert_eq!(
+            typings,
+            vec![
+                ("it_materials", "TYPE STANDARD TABLE OF mara"),
+                ("it_any", "TYPE ANY TABLE"),
+                ("it_range", "TYPE RANGE OF matnr"),
+                ("is_line", "TYPE LINE OF zorder_items"),
+                ("is_like", "LIKE LINE OF gt_orders"),
+                (
+                    "it_sorted",
+                    "TYPE SORTED TABLE OF mara WITH UNIQUE KEY matnr werks"
+                ),
+                ("it_default", "TYPE STANDARD TABLE OF mara WITH DEFAULT KEY"),
+                ("iv_text", "TYPE string"),
+                ("iv_flag", "TYPE abap_bool"),
+            ]
+        );
+        assert!(result.parameters[2].optional);
+        assert!(!result.parameters[6].is_optional());
+        assert_eq!(result.parameters[7].default.as_deref(), Some("'a b.'"));
+        assert_eq!(result.parameters[8].default.as_deref(), Some("abap_true"));
+        assert_eq!(result.exceptions, vec!["not_found"]);
+    }
+
+    #[test]
+    fn parse_local_interface_comment_block() {
+        let source = r#"FUNCTION Z_LEGACY_READ.
+*"----------------------------------------------------------------------
+*"*"Local Interface:
+*"  IMPORTING
+*"     VALUE(IV_ID) TYPE  I
+*"  EXPORTING
+*"     REFERENCE(EV_TEXT) TYPE  STRING
+*"  EXCEPTIONS
+*"      NOT_FOUND
+*"----------------------------------------------------------------------
+  ev_text = 'x'.
+ENDFUNCTION."#;
+
+        let result = function_signature(&lex(source), lex).unwrap();
+        assert_eq!(result.name, "Z_LEGACY_READ");
+        assert_eq!(result.parameters.len(), 2);
+        assert_eq!(result.parameters[1].kind, ParameterKind::Exporting);
+        assert_eq!(result.parameters[1].typing.as_deref(), Some("TYPE STRING"));
+        assert_eq!(result.exceptions, vec!["NOT_FOUND"]);
+    }
+
+    #[test]
+    fn method_signature_is_parsed_from_source() {
+        let source = r#"CLASS zcl_order DEFINITION PUBLIC CREATE PUBLIC.
+  PUBLIC SECTION.
+    "! Creates an order
+    METHODS create
+      IMPORTING
+        iv_customer    TYPE kunnr
+        iv_note        TYPE string DEFAULT 'v1.0'
+        VALUE(iv_date) TYPE d OPTIONAL
+      RETURNING
+        VALUE(ro_order) TYPE REF TO zcl_order
+      RAISING
+        zcx_order_error.
+    METHODS: cancel, release IMPORTING iv_force TYPE abap_bool DEFAULT abap_false.
+ENDCLASS.
+
+CLASS zcl_order IMPLEMENTATION.
+  METHOD create.
+  ENDMETHOD.
+ENDCLASS."#;
+        let statements = statements::statements(&lex(source));
+
+        let create = method_signature(&statements, "CREATE").unwrap();
+        assert_eq!(create.name, "create");
+        assert_eq!(create.parameters.len(), 4);
+        assert_eq!(create.parameters[1].default.as_deref(), Some("'v1.0'"));
+        assert!(create.parameters[2].is_optional());
+        assert_eq!(create.parameters[3].kind, ParameterKind::Returning);
+        assert_eq!(
+            create.parameters[3].typing.as_deref(),
+            Some("TYPE REF TO zcl_order")
+        );
+        assert_eq!(create.exceptions, vec!["zcx_order_error"]);
+
+        let release = method_signature(&statements, "release").unwrap();
+        assert_eq!(release.parameters[0].name, "iv_force");
+        assert!(method_signature(&statements, "missing").is_none());
+    }
+
+    #[test]
+    fn method_signature_with_table_typings() {
+        let source = r#"CLASS zcl_order DEFINITION PUBLIC.
+  PUBLIC SECTION.
+    METHODS read
+      IMPORTING
+        it_keys  TYPE SORTED TABLE OF mara WITH UNIQUE KEY matnr
+        iv_title TYPE string DEFAULT 'open orders'
+      EXPORTING
+        et_items TYPE ANY TABLE.
+ENDCLASS."#;
+
+        let read = method_signature(&statements::statements(&lex(source)), "read").unwrap();
+        let typings: Vec<_> = read
+            .parameters
+            .iter()
+            .map(|p| p.typing.as_deref().unwrap_or_default())
+            .collect();
+        assert_eq!(
+            typings,
+            vec![
+                "TYPE SORTED TABLE OF mara WITH UNIQUE KEY matnr",
+                "TYPE string",
+                "TYPE ANY TABLE"
+            ]
+        );
+        assert_eq!(read.parameters[1].default.as_deref(), Some("'open orders'"));
+    }
+
+    #[test]
+    fn local_signature_of_chained_method_declaration() {
+        let source = r#"CLASS lcl_order DEFINITION.
+  PUBLIC SECTION.
+    METHODS: cancel, release IMPORTING iv_force TYPE abap_bool.
+ENDCLASS.
+
+START-OF-SELECTION.
+  lo_order->release( "#;
+        let statements = statements::statements(&lex(source));
+
+        let call = call_at(&statements, source.len()).unwrap();
+        let signature = local_signature(&statements, &call).unwrap();
+        assert_eq!(signature.name, "release");
+        assert_eq!(signature.parameters[0].name, "iv_force");
+    }
+}
@@ -0,0 +1,276 @@
+use tree_sitter::{Node, Point, TextProvider, Tree};
+
+/// The lexical kind of a [`Token`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    /// Keywords, identifiers, operators... anything that is not one of the other kinds.
+    Word,
+    /// Character or string literals and string templates.
+    Literal,
+    /// Full line (`*`) and end of line (`"`) comments.
+    Comment,
+    /// The period ending a statement
+    Period,
+    /// The colon of a chained statement
+    Colon,
+    /// The comma separating the parts of a chained statement
+    Comma,
+}
+
+/// A token of the source code as written, e.g `START-OF-SELECTION` or `ls_data-field`.
+///
+/// Tokens are made up of the adjacent leaves of the concrete syntax tree, which makes
+/// them independent of how the grammar splits up compound words.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub text: String,
+    pub start_byte: usize,
+    pub end_byte: usize,
+    pub start: Point,
+    pub end: Point,
+}
+
+impl Token {
+    /// Whether the token is the given keyword, ignoring the case.
+    pub fn is(&self, keyword: &str) -> bool {
+        self.kind == TokenKind::Word && self.text.eq_ignore_ascii_case(keyword)
+    }
+
+    fn is_separator(&self) -> bool {
+        matches!(
+            self.kind,
+            TokenKind::Period | TokenKind::Colon | TokenKind::Comma | TokenKind::Comment
+        )
+    }
+}
+
+/// An ABAP statement, ranging from its first token up to and including its period.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Statement {
+    /// The tokens of the statement, excluding comments and the period.
+    pub tokens: Vec<Token>,
+    pub start_byte: usize,
+    pub end_byte: usize,
+    pub start: Point,
+    pub end: Point,
+}
+
+impl Statement {
+    /// Whether this is a chained statement, e.g `DATA: a TYPE i, b TYPE i.`
+    pub fn is_chained(&self) -> bool {
+        self.tokens.iter().any(|t| t.kind == TokenKind::Colon)
+    }
+
+    /// The first word of the statement in uppercase, e.g `DATA`
+    pub fn keyword(&self) -> Option<String> {
+        self.tokens
+            .first()
+            .filter(|t| t.kind == TokenKind::Word)
+            .map(|t| t.text.to_uppercase())
+    }
+
+    /// Resolves a chained statement into the individual statements it stands for.
+    ///
+    /// `DATA: a TYPE i, b TYPE i.` results in `DATA a TYPE i` and `DATA b TYPE i`, a
+    /// statement that is not chained results in a single part with all its tokens.
+    pub fn parts(&self) -> Vec<Vec<&Token>> {
+        let Some(colon) = self.tokens.iter().position(|t| t.kind == TokenKind::Colon) else {
+            return vec![self.tokens.iter().collect()];
+        };
+
+        let prefix = &self.tokens[..colon];
+        self.tokens[colon + 1..]
+            .split(|t| t.kind == TokenKind::Comma)
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| prefix.iter().chain(segment).collect())
+            .collect()
+    }
+}
+
+/// Collects the tokens of the source from the leaves of the concrete syntax tree.
+///
+/// The text of the leaves is taken from the provider, e.g. the chunks of a rope.
+pub fn tokens<I: AsRef<[u8]>>(tree: &Tree, mut source: impl TextProvider<I>) -> Vec<Token> {
+    let mut tokens: Vec<Token> = vec![];
+    let mut cursor = tree.walk();
+
+    loop {
+        let node = cursor.node();
+        if is_token_node(&node) {
+            if let Some(token) = leaf_token(&node, &mut source) {
+                match tokens.last_mut() {
+                    Some(prev) if can_merge(prev, &token) => {
+                        prev.text.push_str(&token.text);
+                        prev.end_byte = token.end_byte;
+                        prev.end = token.end;
+                        if token.kind == TokenKind::Word {
+                            prev.kind = TokenKind::Word;
+                        }
+                    }
+                    _ => tokens.push(token),
+                }
+            }
+        } else if cursor.goto_first_child() {
+            continue;
+        }
+
+        while !cursor.goto_next_sibling() {
+            if !cursor.goto_parent() {
+                return tokens;
+            }
+        }
+    }
+}
+
+/// Groups the tokens into statements, comments are not part of any statement.
+pub fn statements(tokens: &[Token]) -> Vec<Statement> {
+    let mut statements = vec![];
+    let mut current: Vec<Token> = vec![];
+
+    for token in tokens.iter().filter(|t| t.kind != TokenKind::Comment) {
+        if token.kind != TokenKind::Period {
+            current.push(token.clone());
+            continue;
+        }
+        let first = current.first().unwrap_or(token);
+        statements.push(Statement {
+            start_byte: first.start_byte,
+            start: first.start,
+            end_byte: token.end_byte,
+            end: token.end,
+            tokens: std::mem::take(&mut current),
+        });
+    }
+
+    // The last statement may not be terminated (yet) while typing.
+    if let (Some(first), Some(last)) = (current.first(), current.last()) {
+        statements.push(Statement {
+            start_byte: first.start_byte,
+            start: first.start,
+            end_byte: last.end_byte,
+            end: last.end,
+            tokens: current.clone(),
+        });
+    }
+    statements
+}
+
+/// Whether the node is a token on its own, comments and literals are not split up further.
+fn is_token_node(node: &Node) -> bool {
+    let kind = node.kind();
+    node.child_count() == 0
+        || kind.contains("comment")
+        || kind.contains("string")
+        || kind.contains("literal")
+}
+
+fn leaf_token<I: AsRef<[u8]>>(node: &Node, source: &mut impl TextProvider<I>) -> Option<Token> {
+    if node.start_byte() == node.end_byte() {
+        // Zero width, e.g. MISSING nodes inserted by the parser.
+        return None;
+    }
+    let mut bytes = vec![];
+    for chunk in source.text(*node) {
+        bytes.extend_from_slice(chunk.as_ref());
+    }
+    let text = String::from_utf8_lossy(&bytes).into_owned();
+
+    let kind = if node.kind().contains("comment")
+        || text.starts_with('"')
+        || (text.starts_with('*') && node.start_position().column == 0)
+    {
+        TokenKind::Comment
+    } else if node.kind().contains("string")
+        || node.kind().contains("literal")
+        || text.starts_with(['\'', '`', '|'])
+    {
+        TokenKind::Literal
+    } else {
+        match text.as_str() {
+            "." => TokenKind::Period,
+            ":" => TokenKind::Colon,
+            "," => TokenKind::Comma,
+            _ => TokenKind::Word,
+        }
+    };
+
+    Some(Token {
+        kind,
+        text,
+        start_byte: node.start_byte(),
+        end_byte: node.end_byte(),
+        start: node.start_position(),
+        end: node.end_position(),
+    })
+}
+
+/// Leaves without whitespace in between form a single token, e.g `ls_data` `-` `field`.
+fn can_merge(prev: &Token, next: &Token) -> bool {
+    prev.end_byte == next.start_byte && !prev.is_separator() && !next.is_separator()
+}
+
+/// Splits the source into tokens the same as [`tokens`] without a syntax tree, so tests
+/// do not depend on the grammar.
+#[cfg(test)]
+pub(crate) fn lex(source: &str) -> Vec<Token> {
+    let bytes = source.as_bytes();
+    let point = |byte: usize| {
+        let line_start = source[..byte].rfind('\n').map_or(0, |i| i + 1);
+        Point::new(source[..byte].matches('\n').count(), byte - line_start)
+    };
+    let token = |kind: TokenKind, start: usize, end: usize| Token {
+        kind,
+        text: source[start..end].to_owned(),
+        start_byte: start,
+        end_byte: end,
+        start: point(start),
+        end: point(end),
+    };
+    let is_separator = |i: usize| {
+        matches!(bytes[i], b'.' | b':' | b',')
+            && bytes.get(i + 1).is_none_or(|b| b.is_ascii_whitespace())
+    };
+
+    let mut tokens = vec![];
+    let mut i = 0;
+    while i < bytes.len() {
+        let start = i;
+        match bytes[i] {
+            b if b.is_ascii_whitespace() => {
+                i += 1;
+                continue;
+            }
+            b'"' => {
+                i = source[i..].find('\n').map_or(bytes.len(), |n| i + n);
+                tokens.push(token(TokenKind::Comment, start, i));
+            }
+            b'*' if point(i).column == 0 => {
+                i = source[i..].find('\n').map_or(bytes.len(), |n| i + n);
+                tokens.push(token(TokenKind::Comment, start, i));
+            }
+            quote @ (b'\'' | b'`' | b'|') => {
+                i = source[i + 1..]
+                    .find(quote as char)
+                    .map_or(bytes.len(), |n| i + n + 2);
+                tokens.push(token(TokenKind::Literal, start, i));
+            }
+            _ if is_separator(i) => {
+                i += 1;
+                let kind = match bytes[start] {
+                    b'.' => TokenKind::Period,
+                    b':' => TokenKind::Colon,
+                    _ => TokenKind::Comma,
+                };
+                tokens.push(token(kind, start, i));
+            }
+            _ => {
+                while i < bytes.len() && !bytes[i].is_ascii_whitespace() && !is_separator(i) {
+                    i += 1;
+                }
+                tokens.push(token(TokenKind::Word, start, i));
+            }
+        }
+    }
+    tokens
+}
@@ -0,0 +1,501 @@
+use adt_query::{
+    api::repository::QuickSearchBuilder, dispatch::StatelessDispatch, error::OperationError,
+    models::adtcore::ObjectReference,
+};
+use ropey::Rope;
+use tower_lsp::lsp_types::{
+    DocumentSymbol, Location, OneOf, Range, SymbolKind, Url, WorkspaceLocation, WorkspaceSymbol,
+};
+
+use crate::context::AdtClient;
+use crate::document::{PositionEncoding, byte_offset_to_position};
+use crate::navigation::URI_SCHEME;
+use crate::statements::{Statement, Token, TokenKind};
+
+/// Statements that start an event block of a report, e.g `START-OF-SELECTION`
+///
+/// `GET` of logical databases is left out, as it cannot be told apart from statements
+/// like `GET TIME` by its first words.
+pub(crate) const EVENTS: &[&str] = &[
+    "LOAD-OF-PROGRAM",
+    "INITIALIZATION",
+    "START-OF-SELECTION",
+    "END-OF-SELECTION",
+    "TOP-OF-PAGE",
+    "END-OF-PAGE",
+];
+
+/// Events introduced by `AT`, which otherwise starts a control level within a loop.
+pub(crate) const AT_EVENTS: &[&str] = &["SELECTION-SCREEN", "LINE-SELECTION", "USER-COMMAND"];
+
+/// Statements that declare data objects or types, optionally as a `BEGIN OF` structure.
+const DECLARATIONS: &[(&str, SymbolKind)] = &[
+    ("DATA", SymbolKind::VARIABLE),
+    ("CLASS-DATA", SymbolKind::VARIABLE),
+    ("STATICS", SymbolKind::VARIABLE),
+    ("FIELD-SYMBOLS", SymbolKind::VARIABLE),
+    ("PARAMETERS", SymbolKind::VARIABLE),
+    ("PARAMETER", SymbolKind::VARIABLE),
+    ("SELECT-OPTIONS", SymbolKind::VARIABLE),
+    ("RANGES", SymbolKind::VARIABLE),
+    ("CONSTANTS", SymbolKind::CONSTANT),
+    ("TYPES", SymbolKind::TYPE_PARAMETER),
+];
+
+/// How the block of an open symbol is ended.
+#[derive(Debug, PartialEq, Eq)]
+enum Terminator {
+    /// By an explicit statement, e.g `ENDCLASS`
+    Keyword(&'static str),
+    /// By an `END OF` of a structure declaration.
+    EndOf,
+    /// By the start of the next processing block, e.g. event blocks.
+    Implicit,
+}
+
+struct Block {
+    symbol: DocumentSymbol,
+    terminator: Terminator,
+}
+
+/// Builds the outline of a source from its statements.
+///
+/// Classes, interfaces and processing blocks contain the symbols declared within,
+/// structures declared with `BEGIN OF` contain their components.
+pub fn document_symbols(
+    statements: &[Statement],
+    rope: &Rope,
+    encoding: PositionEncoding,
+) -> Vec<DocumentSymbol> {
+    let mut outline = Outline {
+        rope,
+        encoding,
+        stack: vec![],
+        roots: vec![],
+        section: None,
+        prev_end: 0,
+        start: 0,
+    };
+    for statement in statements {
+        let prefix = statement
+            .tokens
+            .iter()
+            .position(|t| t.kind == TokenKind::Colon)
+            .unwrap_or_default();
+        let parts = statement.parts();
+        let count = parts.len();
+        for (i, part) in parts.into_iter().enumerate() {
+            // Subsequent parts of a chained statement start after the preceding comma.
+            outline.start = match i {
+                0 => statement.start_byte,
+                _ => part
+                    .get(prefix)
+                    .map_or(statement.start_byte, |t| t.start_byte),
+            };
+            // The last part of the statement also covers its period.
+            let end = if i + 1 == count {
+                statement.end_byte
+            } else {
+                part.last().map_or(statement.end_byte, |t| t.end_byte)
+            };
+            outline.visit(&part, end);
+        }
+        outline.prev_end = statement.end_byte;
+    }
+    outline.finish()
+}
+
+struct Outline<'a> {
+    rope: &'a Rope,
+    encoding: PositionEncoding,
+    stack: Vec<Block>,
+    roots: Vec<DocumentSymbol>,
+    /// The visibility section of the class definition the statements are in.
+    section: Option<&'static str>,
+    /// The end of the previous statement, where implicitly ended blocks end.
+    prev_end: usize,
+    /// The start of the statement (part) that is visited.
+    start: usize,
+}
+
+impl Outline<'_> {
+    fn visit(&mut self, part: &[&Token], end: usize) {
+        let words: Vec<String> = part.iter().map(|t| t.text.to_uppercase()).collect();
+        let word = |i: usize| words.get(i).map(String::as_str).unwrap_or_default();
+        let Some(first) = words.first() else { return };
+
+        match first.as_str() {
+            "ENDCLASS" => {
+                self.close("ENDCLASS", end);
+                self.section = None;
+            }
+            keyword @ ("ENDINTERFACE" | "ENDMETHOD" | "ENDFORM" | "ENDMODULE" | "ENDFUNCTION") => {
+                self.close(keyword, end)
+            }
+            "CLASS" if matches!(word(2), "DEFINITION" | "IMPLEMENTATION") => {
+                let detail = word(2).to_lowercase();
+                if words.iter().any(|w| w == "DEFERRED" || w == "LOAD") {
+                    self.leaf(part, 1, end, SymbolKind::CLASS, Some(detail));
+                } else {
+                    self.close_implicit();
+                    self.open(part, 1, SymbolKind::CLASS, Some(detail), "ENDCLASS");
+                }
+            }
+            "INTERFACE" if part.len() > 1 => {
+                if words.iter().any(|w| w == "DEFERRED" || w == "LOAD") {
+                    self.leaf(part, 1, end, SymbolKind::INTERFACE, None);
+                } else {
+                    self.close_implicit();
+                    self.open(part, 1, SymbolKind::INTERFACE, None, "ENDINTERFACE");
+                }
+            }
+            "METHOD" => self.open(part, 1, SymbolKind::METHOD, None, "ENDMETHOD"),
+            "FORM" => {
+                self.close_implicit();
+                self.open(
+                    part,
+                    1,
+                    SymbolKind::FUNCTION,
+                    Some("form".into()),
+                    "ENDFORM",
+                );
+            }
+            "MODULE" if part.len() > 1 => {
+                self.close_implicit();
+                let detail = match word(2) {
+                    "OUTPUT" => "module output",
+                    _ => "module input",
+                };
+                self.open(
+                    part,
+                    1,
+                    SymbolKind::FUNCTION,
+                    Some(detail.into()),
+                    "ENDMODULE",
+                );
+            }
+            "FUNCTION" if part.len() > 1 => {
+                self.close_implicit();
+                let detail = Some("function module".into());
+                self.open(part, 1, SymbolKind::FUNCTION, detail, "ENDFUNCTION");
+            }
+            "PUBLIC" | "PROTECTED" | "PRIVATE" if word(1) == "SECTION" => {
+                self.section = match first.as_str() {
+                    "PUBLIC" => Some("public"),
+                    "PROTECTED" => Some("protected"),
+                    _ => Some("private"),
+                };
+            }
+            "METHODS" | "CLASS-METHODS" => {
+                let detail = self.section.map(String::from);
+                self.leaf(part, 1, end, SymbolKind::METHOD, detail);
+            }
+            "EVENTS" | "CLASS-EVENTS" => {
+                let detail = self.section.map(String::from);
+                self.leaf(part, 1, end, SymbolKind::EVENT, detail);
+            }
+            _ if is_event(&words) => {
+                self.close_implicit();
+                self.open_event(part, end);
+            }
+            keyword => {
+                let Some((_, kind)) = DECLARATIONS.iter().find(|(k, _)| *k == keyword) else {
+                    return;
+                };
+                self.declaration(part, &words, *kind, end);
+            }
+        }
+    }
+
+    fn declaration(&mut self, part: &[&Token], words: &[String], kind: SymbolKind, end: usize) {
+        let word = |i: usize| words.get(i).map(String::as_str).unwrap_or_default();
+        let detail = self.section.map(String::from);
+
+        match (word(1), word(2)) {
+            ("BEGIN", "OF") => {
+                let name = if word(3) == "ENUM" { 4 } else { 3 };
+                if let Some(symbol) = self.symbol(part, name, SymbolKind::STRUCT, detail) {
+                    self.stack.push(Block {
+                        symbol,
+                        terminator: Terminator::EndOf,
+                    });
+                }
+            }
+            ("END", "OF") => {
+                if self
+                    .stack
+                    .last()
+                    .is_some_and(|b| b.terminator == Terminator::EndOf)
+                {
+                    let block = self.stack.pop().unwrap();
+                    self.attach(block.symbol, end);
+                }
+            }
+            _ => self.leaf(part, 1, end, kind, detail),
+        }
+    }
+
+    /// Creates the symbol for the statement, named by the token at the given index.
+    fn symbol(
+        &self,
+        part: &[&Token],
+        name: usize,
+        kind: SymbolKind,
+        detail: Option<String>,
+    ) -> Option<DocumentSymbol> {
+        let token = part.get(name)?;
+
+        // Strip the length of declarations like `DATA text(10) TYPE c.`
+        let text = token.text.split('(').next().unwrap_or_default();
+        if text.is_empty() {
+            return None;
+        }
+        let selection_range = self.range(token.start_byte, token.start_byte + text.len());
+
+        #[allow(deprecated)]
+        Some(DocumentSymbol {
+            name: text.to_owned(),
+            detail,
+            kind,
+            tags: None,
+            deprecated: None,
+            range: self.range(self.start, token.end_byte),
+            selection_range,
+            children: None,
+        })
+    }
+
+    fn leaf(
+        &mut self,
+        part: &[&Token],
+        name: usize,
+        end: usize,
+        kind: SymbolKind,
+        detail: Option<String>,
+    ) {
+        if let Some(symbol) = self.symbol(part, name, kind, detail) {
+            self.attach(symbol, end);
+        }
+    }
+
+    fn open(
+        &mut self,
+        part: &[&Token],
+        name: usize,
+        kind: SymbolKind,
+        detail: Option<String>,
+        terminator: &'static str,
+    ) {
+        if let Some(symbol) = self.symbol(part, name, kind, detail) {
+            self.stack.push(Block {
+                symbol,
+                terminator: Terminator::Keyword(terminator),
+            });
+        }
+    }
+
+    /// Opens an event block, which is named by its entire statement, e.g `AT SELECTION-SCREEN`
+    fn open_event(&mut self, part: &[&Token], end: usize) {
+        let (Some(first), Some(last)) = (part.first(), part.last()) else {
+            return;
+        };
+        let name = part
+            .iter()
+            .map(|t| t.text.to_uppercase())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let range = self.range(first.start_byte, end);
+
+        #[allow(deprecated)]
+        let symbol = DocumentSymbol {
+            name,
+            detail: Some("event".into()),
+            kind: SymbolKind::EVENT,
+            tags: None,
+            deprecated: None,
+            range,
+            selection_range: self.range(first.start_byte, last.end_byte),
+            children: None,
+        };
+        self.stack.push(Block {
+            symbol,
+            terminator: Terminator::Implicit,
+        });
+    }
+
+    /// Closes the innermost block ended by the keyword, including any blocks nested
+    /// within that lack their own end, e.g. a `METHOD` without `ENDMETHOD`.
+    fn close(&mut self, keyword: &str, end: usize) {
+        let Some(index) = self
+            .stack
+            .iter()
+            .rposition(|b| matches!(b.terminator, Terminator::Keyword(k) if k == keyword))
+        else {
+            return;
+        };
+        while self.stack.len() > index {
+            let block = self.stack.pop().unwrap();
+            self.attach(block.symbol, end);
+        }
+    }
+
+    /// Closes the event blocks at the start of the next processing block.
+    fn close_implicit(&mut self) {
+        let Some(index) = self
+            .stack
+            .iter()
+            .position(|b| b.terminator == Terminator::Implicit)
+        else {
+            return;
+        };
+        while self.stack.len() > index {
+            let block = self.stack.pop().unwrap();
+            self.attach(block.symbol, self.prev_end);
+        }
+    }
+
+    /// Ends the symbol at the byte and adds it to the enclosing block.
+    fn attach(&mut self, mut symbol: DocumentSymbol, end: usize) {
+        let end = byte_offset_to_position(self.rope, end, self.encoding);
+        if end > symbol.range.end {
+            symbol.range.end = end;
+        }
+        match self.stack.last_mut() {
+            Some(parent) => parent.symbol.children.get_or_insert_default().push(symbol),
+            None => self.roots.push(symbol),
+        }
+    }
+
+    fn finish(mut self) -> Vec<DocumentSymbol> {
+        while let Some(block) = self.stack.pop() {
+            self.attach(block.symbol, self.prev_end);
+        }
+        self.roots
+    }
+
+    fn range(&self, start: usize, end: usize) -> Range {
+        Range::new(
+            byte_offset_to_position(self.rope, start, self.encoding),
+            byte_offset_to_position(self.rope, end, self.encoding),
+        )
+    }
+}
+
+fn is_event(words: &[String]) -> bool {
+    match words {
+        [at, event, ..] if at == "AT" => {
+            AT_EVENTS.contains(&event.as_str()) || event.starts_with("PF")
+        }
+        [keyword, ..] => EVENTS.contains(&keyword.as_str()),
+        [] => false,
+    }
+}
+
+/// Searches the repository for objects whose name matches the query, `*` matches any
+/// characters and one is implied at the end, e.g. `ZCL_*ORDER` finds `ZCL_ORDER_SERVICE`
+/// as well as `ZCL_SALES_ORDER`.
+pub async fn search_objects(
+    client: &AdtClient,
+    query: &str,
+) -> Result<Vec<ObjectReference>, OperationError> {
+    let mut query = query.trim().to_uppercase();
+    if !query.ends_with('*') {
+        query.push('*');
+    }
+    let operation = QuickSearchBuilder::default()
+        .query(query)
+        .build()
+        .map_err(|_| OperationError::UninitializedField("query"))?;
+
+    let response = operation.dispatch(client).await?;
+    Ok(response.take().into_body().objects)
+}
+
+/// A repository object as symbol of the workspace, located at the start of its document.
+pub fn workspace_symbol(object: &ObjectReference, uri: Url) -> WorkspaceSymbol {
+    let object_type = object.object_type.as_deref().unwrap_or_default();
+
+    WorkspaceSymbol {
+        name: object.name.clone(),
+        kind: object_symbol_kind(object_type),
+        tags: None,
+        container_name: object.package_name.clone(),
+        location: OneOf::Left(Location::new(uri, Range::default())),
+        data: None,
+    }
+}
+
+/// A repository object as symbol of the workspace whose document is only located once
+/// the symbol is resolved, as that requires the properties of the object.
+///
+/// Until then, the symbol refers to the object on the system by its ADT uri, e.g.
+/// `adt://A4H/sap/bc/adt/oo/classes/zcl_order_service`, and keeps the object as its data.
+pub fn unlocated_workspace_symbol(
+    object: &ObjectReference,
+    system: &str,
+) -> Option<WorkspaceSymbol> {
+    let uri = Url::parse(&format!("{URI_SCHEME}://{system}{}", object.uri)).ok()?;
+    let object_type = object.object_type.as_deref().unwrap_or_default();
+
+    Some(WorkspaceSymbol {
+        name: object.name.clone(),
+        kind: object_symbol_kind(object_type),
+        tags: None,
+        container_name: object.package_name.clone(),
+        location: OneOf::Right(WorkspaceLocation { uri }),
+        data: serde_json::to_value(object).ok(),
+    })
+}
+
+fn object_symbol_kind(object_type: &str) -> SymbolKind {
+    match object_type.split('/').next().unwrap_or_default() {
+        "CLAS" => SymbolKind::CLASS,
+        "INTF" => SymbolKind::INTERFACE,
+        "FUGR" if object_type == "FUGR/FF" => SymbolKind::FUNCTION,
+        "FUGR" => SymbolKind::MODULE,
+        "TABL" | "VIEW" | "DDLS" => SymbolKind::STRUCT,
+        "DTEL" | "DOMA" | "TTYP" => SymbolKind::TYPE_PARAMETER,
+        _ => SymbolKind::FILE,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::statements::{lex, statements};
+
+    fn outline(source: &str) -> Vec<DocumentSymbol> {
+        let rope = Rope::from_str(source);
+        document_symbols(&statements(&lex(source)), &rope, PositionEncoding::Utf16)
+    }
+
+    #[test]
+    fn get_statements_do_not_start_events() {
+        let source = concat!(
+            "REPORT ztest.\n",
+            "START-OF-SELECTION.\n",
+            "  DATA ts TYPE timestampl.\n",
+            "  GET TIME STAMP FIELD ts.\n",
+            "  DATA later TYPE i.\n",
+        );
+        let symbols = outline(source);
+        let names: Vec<&str> = symbols.iter().map(|s| s.name.as_str()).collect();
+        assert!(
+            !names.iter().any(|name| name.starts_with("GET")),
+            "{names:?}"
+        );
+
+        let event = symbols
+            .iter()
+            .find(|s| s.name == "START-OF-SELECTION")
+            .expect("event is part of the outline");
+        let children: Vec<&str> = event
+            .children
+            .iter()
+            .flatten()
+            .map(|s| s.name.as_str())
+            .collect();
+        assert_eq!(children, ["ts", "later"]);
+        assert_eq!(event.range.end.line, 4);
+    }
+}
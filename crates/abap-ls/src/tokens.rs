@@ -1,3 +1,18 @@
+use std::sync::LazyLock;
+
+use ropey::{Rope, iter::Chunks};
+use tower_lsp::lsp_types::{self, SemanticTokensEdit};
+use tree_sitter::{Node, Query, TextProvider};
+
+/// The highlights query of the grammar, compiled once as it is fairly expensive.
+pub static HIGHLIGHTS_QUERY: LazyLock<Query> = LazyLock::new(|| {
+    Query::new(
+        &tree_sitter_abap::LANGUAGE.into(),
+        tree_sitter_abap::HIGHLIGHTS_QUERY,
+    )
+    .expect("Invalid highlights query.")
+});
+
 #[repr(u32)]
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum TokenType {
@@ -8,8 +23,52 @@ pub enum TokenType {
     Type = 4,
     Comment = 5,
     Operator = 6,
+    Class = 7,
+    Interface = 8,
+    Method = 9,
+    Parameter = 10,
+    Property = 11,
+    EnumMember = 12,
+    Function = 13,
+    Macro = 14,
+    Namespace = 15,
+    Label = 16,
 }
 
+/// Maps the capture names of the highlights query to the token types.
+///
+/// Capture names are hierarchical, `keyword.control` falls back to `keyword` if
+/// there is no more specific entry.
+const CAPTURES: &[(&str, TokenType)] = &[
+    ("variable", TokenType::Variable),
+    ("variable.parameter", TokenType::Parameter),
+    ("variable.member", TokenType::Property),
+    ("parameter", TokenType::Parameter),
+    ("property", TokenType::Property),
+    ("attribute", TokenType::Property),
+    ("field", TokenType::Property),
+    ("keyword", TokenType::Keyword),
+    ("number", TokenType::Number),
+    ("string", TokenType::String),
+    ("type", TokenType::Type),
+    ("type.class", TokenType::Class),
+    ("type.interface", TokenType::Interface),
+    ("class", TokenType::Class),
+    ("interface", TokenType::Interface),
+    ("comment", TokenType::Comment),
+    ("operator", TokenType::Operator),
+    ("function", TokenType::Function),
+    ("function.method", TokenType::Method),
+    ("function.macro", TokenType::Macro),
+    ("method", TokenType::Method),
+    ("macro", TokenType::Macro),
+    ("constant", TokenType::EnumMember),
+    ("enumMember", TokenType::EnumMember),
+    ("module", TokenType::Namespace),
+    ("namespace", TokenType::Namespace),
+    ("label", TokenType::Label),
+];
+
 impl TokenType {
     pub const fn index(&self) -> u32 {
         *self as u32
@@ -23,25 +82,92 @@ impl TokenType {
         TokenType::Type,
         TokenType::Comment,
         TokenType::Operator,
+        TokenType::Class,
+        TokenType::Interface,
+        TokenType::Method,
+        TokenType::Parameter,
+        TokenType::Property,
+        TokenType::EnumMember,
+        TokenType::Function,
+        TokenType::Macro,
+        TokenType::Namespace,
+        TokenType::Label,
     ];
 
     pub const fn names() -> &'static [&'static str] {
         &[
-            "variable", "keyword", "number", "string", "type", "comment", "operator",
+            "variable",
+            "keyword",
+            "number",
+            "string",
+            "type",
+            "comment",
+            "operator",
+            "class",
+            "interface",
+            "method",
+            "parameter",
+            "property",
+            "enumMember",
+            "function",
+            "macro",
+            "namespace",
+            "label",
         ]
     }
 
-    pub fn from_name(name: &str) -> Self {
-        let idx = Self::names().iter().position(|n| *n == name).unwrap();
-        Self::ALL[idx]
+    /// The token type of a capture of the highlights query, `None` for captures
+    /// that are not highlighted semantically, e.g. `punctuation.delimiter`
+    pub fn from_name(name: &str) -> Option<Self> {
+        let mut name = name;
+        loop {
+            if let Some((_, token_type)) = CAPTURES.iter().find(|(n, _)| *n == name) {
+                return Some(*token_type);
+            }
+            name = name.rsplit_once('.')?.0;
+        }
     }
 }
 
+/// The modifiers of a token, each being a bit of the modifiers bitset.
 #[repr(u32)]
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum TokenModifier {
-    None = 0,
-    Declaration = 1,
+    Declaration = 0,
+    Readonly = 1,
+    Static = 2,
+    Deprecated = 3,
+}
+
+impl TokenModifier {
+    pub const fn bit(&self) -> u32 {
+        1 << (*self as u32)
+    }
+
+    pub const ALL: &'static [TokenModifier] = &[
+        TokenModifier::Declaration,
+        TokenModifier::Readonly,
+        TokenModifier::Static,
+        TokenModifier::Deprecated,
+    ];
+
+    pub const fn names() -> &'static [&'static str] {
+        &["declaration", "readonly", "static", "deprecated"]
+    }
+
+    /// The modifiers bitset of a capture of the highlights query, derived from
+    /// the segments of its name, e.g `variable.member.static.definition`
+    pub fn bitset_from_name(name: &str) -> u32 {
+        name.split('.')
+            .filter_map(|segment| match segment {
+                "definition" | "declaration" => Some(TokenModifier::Declaration),
+                "readonly" | "constant" => Some(TokenModifier::Readonly),
+                "static" => Some(TokenModifier::Static),
+                "deprecated" => Some(TokenModifier::Deprecated),
+                _ => None,
+            })
+            .fold(0, |bitset, modifier| bitset | modifier.bit())
+    }
 }
 
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
@@ -52,5 +178,184 @@ pub struct SemanticToken {
     pub length: u32,
 
     pub token_type: TokenType,
-    pub token_modifiers_bitset: TokenModifier,
+    pub token_modifiers_bitset: u32,
+}
+
+/// Provides the text of nodes to a query cursor straight from the chunks of a rope,
+/// predicates of the query can be evaluated without copying the entire document.
+pub struct RopeProvider<'a>(pub &'a Rope);
+
+impl<'a> TextProvider<&'a [u8]> for RopeProvider<'a> {
+    type I = std::iter::Map<Chunks<'a>, fn(&'a str) -> &'a [u8]>;
+
+    fn text(&mut self, node: Node) -> Self::I {
+        self.0
+            .byte_slice(node.byte_range())
+            .chunks()
+            .map(str::as_bytes as fn(&'a str) -> &'a [u8])
+    }
+}
+
+/// The text of the rope from a byte offset to the end of the chunk it is in, which is
+/// how the parser reads the text. Empty at the end of the rope.
+pub fn chunk_at(rope: &Rope, byte: usize) -> &[u8] {
+    if byte >= rope.len_bytes() {
+        return &[];
+    }
+    let (chunk, start, _, _) = rope.chunk_at_byte(byte);
+    &chunk.as_bytes()[byte - start..]
+}
+
+/// Encodes the tokens relative to their previous token as required by the protocol.
+///
+/// The tokens must be sorted by their position.
+pub fn encode(tokens: &[SemanticToken]) -> Vec<lsp_types::SemanticToken> {
+    let mut result = Vec::with_capacity(tokens.len());
+    let mut prev: Option<&SemanticToken> = None;
+    for curr in tokens {
+        let (delta_line, delta_start) = match prev {
+            Some(prev) if curr.row == prev.row => (0, curr.column - prev.column),
+            Some(prev) => (curr.row - prev.row, curr.column),
+            None => (curr.row, curr.column),
+        };
+        result.push(lsp_types::SemanticToken {
+            delta_line,
+            delta_start,
+            length: curr.length,
+            token_type: curr.token_type.index(),
+            token_modifiers_bitset: curr.token_modifiers_bitset,
+        });
+        prev = Some(curr);
+    }
+    result
+}
+
+/// Computes the edit to get from the previous to the current encoded tokens.
+///
+/// Edits typically only affect a small region of a document, so a single edit that
+/// replaces everything between the common prefix and suffix is sufficient.
+pub fn diff(
+    previous: &[lsp_types::SemanticToken],
+    current: &[lsp_types::SemanticToken],
+) -> Vec<SemanticTokensEdit> {
+    let prefix = previous
+        .iter()
+        .zip(current)
+        .take_while(|(a, b)| a == b)
+        .count();
+    let suffix = previous[prefix..]
+        .iter()
+        .rev()
+        .zip(current[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let deleted = previous.len() - prefix - suffix;
+    let inserted = &current[prefix..current.len() - suffix];
+    if deleted == 0 && inserted.is_empty() {
+        return vec![];
+    }
+
+    // Each token is encoded as five integers in the flat array of the protocol.
+    vec![SemanticTokensEdit {
+        start: (prefix * 5) as u32,
+        delete_count: (deleted * 5) as u32,
+        data: Some(inserted.to_vec()),
+    }]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token(delta_line: u32, delta_start: u32, token_type: TokenType) -> lsp_types::SemanticToken {
+        lsp_types::SemanticToken {
+            delta_line,
+            delta_start,
+            length: 4,
+            token_type: token_type.index(),
+            token_modifiers_bitset: 0,
+        }
+    }
+
+    #[test]
+    fn captures_fall_back_to_their_parent() {
+        assert_eq!(
+            TokenType::from_name("function.method"),
+            Some(TokenType::Method)
+        );
+        assert_eq!(
+            TokenType::from_name("keyword.control.conditional"),
+            Some(TokenType::Keyword)
+        );
+        assert_eq!(
+            TokenType::from_name("variable.member.static"),
+            Some(TokenType::Property)
+        );
+        assert_eq!(TokenType::from_name("punctuation.delimiter"), None);
+    }
+
+    #[test]
+    fn unchanged_tokens_need_no_edit() {
+        let tokens = vec![
+            token(0, 0, TokenType::Keyword),
+            token(0, 5, TokenType::Variable),
+        ];
+        assert!(diff(&tokens, &tokens).is_empty());
+    }
+
+    #[test]
+    fn inserted_tokens_are_a_single_edit() {
+        let previous = vec![
+            token(0, 0, TokenType::Keyword),
+            token(1, 0, TokenType::Keyword),
+        ];
+        let current = vec![
+            token(0, 0, TokenType::Keyword),
+            token(0, 5, TokenType::Variable),
+            token(0, 5, TokenType::Type),
+            token(1, 0, TokenType::Keyword),
+        ];
+
+        let edits = diff(&previous, &current);
+        assert_eq!(
+            edits,
+            vec![SemanticTokensEdit {
+                start: 5,
+                delete_count: 0,
+                data: Some(current[1..3].to_vec()),
+            }]
+        );
+    }
+
+    #[test]
+    fn deleted_tokens_are_a_single_edit() {
+        let previous = vec![
+            token(0, 0, TokenType::Keyword),
+            token(0, 5, TokenType::Variable),
+            token(1, 0, TokenType::Keyword),
+            token(0, 5, TokenType::String),
+        ];
+        let current = vec![
+            token(0, 0, TokenType::Keyword),
+            token(0, 5, TokenType::String),
+        ];
+
+        let edits = diff(&previous, &current);
+        assert_eq!(
+            edits,
+            vec![SemanticTokensEdit {
+                start: 5,
+                delete_count: 10,
+                data: Some(vec![]),
+            }]
+        );
+    }
+
+    #[test]
+    fn chunks_are_read_from_any_offset() {
+        let rope = Rope::from_str("REPORT ztest.");
+        assert_eq!(chunk_at(&rope, 7), b"ztest.");
+        assert!(chunk_at(&rope, rope.len_bytes()).is_empty());
+    }
 }
@@ -1,4 +1,6 @@
-use crate::nodes::{FacetNode, Group, GroupNode, VirtualNode, VirtualNodeData};
+use crate::nodes::{
+    FacetNode, Group, GroupNode, RepositoryObjectNode, VirtualNode, VirtualNodeData,
+};
 use adt_query::{
     RequestDispatch,
     api::repository::{RepositoryContent, RepositoryContentBuilder},
@@ -44,15 +46,53 @@ impl VirtualFileTree {
     }
 
     pub fn uri(&self, id: DefaultKey) -> String {
-        let mut segments = vec![];
+        self.path(id)
+            .iter()
+            .map(|node| node.name())
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    /// Returns the nodes from the root down to (and including) the node.
+    pub fn path(&self, id: DefaultKey) -> Vec<&VirtualNode> {
+        let mut path = vec![];
 
         let mut next = self.lookup(id);
         while let Some(curr) = next {
-            segments.push(curr.name());
+            path.push(curr);
             next = curr.parent.and_then(|v| self.lookup(v));
         }
-        segments.reverse();
-        segments.join("/")
+        path.reverse();
+        path
+    }
+
+    /// The repository object nodes expanded so far.
+    pub fn objects(&self) -> impl Iterator<Item = (DefaultKey, &RepositoryObjectNode)> {
+        self.nodes.iter().filter_map(|(id, node)| match &node.data {
+            VirtualNodeData::RepositoryObject(obj) => Some((id, obj)),
+            _ => None,
+        })
+    }
+
+    /// Finds a repository object node by its ADT uri among the nodes expanded so far.
+    pub fn find_object(&self, adt_uri: &str) -> Option<DefaultKey> {
+        self.objects()
+            .find(|(_, obj)| obj.adt_uri.eq_ignore_ascii_case(adt_uri))
+            .map(|(id, _)| id)
+    }
+
+    /// Inserts a node as the last child of another node.
+    pub fn insert<T>(&mut self, parent: DefaultKey, data: T) -> DefaultKey
+    where
+        T: Into<VirtualNodeData>,
+    {
+        let id = self
+            .nodes
+            .insert_with_key(|k| VirtualNode::new(k, data).parent(parent));
+        if let Some(node) = self.nodes.get_mut(parent) {
+            node.children.get_or_insert_default().push(id);
+        }
+        id
     }
 
     pub async fn expand<T>(&mut self, id: DefaultKey, client: &AdtClient<T>) -> Vec<&VirtualNode>
@@ -78,25 +118,34 @@ impl VirtualFileTree {
             }
         };
 
-        let mut ids: Vec<DefaultKey> = vec![];
-
-        for child in nodes {
-            let new_id = self
-                .nodes
-                .insert_with_key(|k| VirtualNode::new(k, child).parent(id));
-            let node = self.nodes.get_mut(id).unwrap();
-            node.children.get_or_insert_default().push(id);
-            ids.push(new_id);
-        }
+        let ids: Vec<DefaultKey> = nodes
+            .into_iter()
+            .map(|child| self.insert(id, child))
+            .collect();
 
         self.lookup_all(&ids)
     }
 
-    pub async fn refresh<T>(&mut self, _node: VirtualNode, _client: AdtClient<T>)
+    /// Drops the nodes expanded below a node and expands it again, e.g. once objects
+    /// were created or deleted on the system.
+    pub async fn refresh<T>(&mut self, id: DefaultKey, client: &AdtClient<T>) -> Vec<&VirtualNode>
     where
         T: RequestDispatch,
     {
-        todo!()
+        self.remove_descendants(id);
+        self.expand(id, client).await
+    }
+
+    fn remove_descendants(&mut self, id: DefaultKey) {
+        let children = self
+            .nodes
+            .get_mut(id)
+            .and_then(|node| node.children.take())
+            .unwrap_or_default();
+        for child in children {
+            self.remove_descendants(child);
+            self.nodes.remove(child);
+        }
     }
 
     async fn execute_queries<T>(
@@ -50,14 +50,17 @@ export class VirtualFilesystem implements FileSystemProvider {
 	 * @returns Metadata about the requested file, see {@link toFileStat}.
 	 */
 
-	stat(uri: Uri): FileStat {
-		const node = this.lookup(uri);
+	async stat(uri: Uri): Promise<FileStat> {
+		const node = await this.resolve(uri);
 		if (node) {
 			return this.toFileStat(node);
 		}
 		throw FileSystemError.FileNotFound(uri);
 	}
 
+	/**
+	 * Looks up the node at the given uri among the nodes expanded so far.
+	 */
 	public lookup(uri: Uri): FilesystemNode | undefined {
 		const root = this.roots.get(uri.authority.toUpperCase());
 		if (root) {
@@ -65,6 +68,30 @@ export class VirtualFilesystem implements FileSystemProvider {
 		}
 	}
 
+	/**
+	 * Looks up the node at the given uri, expanding the nodes along its path that
+	 * were not expanded yet. This way objects the server locates, e.g. through a
+	 * search or the definition of a symbol, can be opened without browsing to them.
+	 *
+	 * @param uri The vscode file/folder URI
+	 *
+	 * @returns The node at the uri, `undefined` if there is none.
+	 */
+	public async resolve(uri: Uri): Promise<FilesystemNode | undefined> {
+		const system = uri.authority.toUpperCase();
+		let node: FilesystemNode | undefined = this.roots.get(system);
+		for (const segment of this.breakIntoParts(uri)) {
+			if (!node || isObject(node) || this.isInaccessibleSystem(node)) {
+				return undefined;
+			}
+			if (!node.children) {
+				node.children = await this.expand(node, system);
+			}
+			node = node.children.find((child) => child.name === segment);
+		}
+		return node;
+	}
+
 	/**
 	 * Expands the directory structure at the given uri, how exactly the nodes
 	 * are expanded is dependent on the concrete type of node.
@@ -78,11 +105,7 @@ export class VirtualFilesystem implements FileSystemProvider {
 	 * @returns A list of tuples mapping the sub-objects to their filetype.
 	 */
 	async readDirectory(uri: Uri): Promise<[string, FileType][]> {
-		const root = this.roots.get(uri.authority.toUpperCase());
-		if (!root) {
-			throw FileSystemError.FileNotFound(uri);
-		}
-		const node = walk(root, this.breakIntoParts(uri));
+		const node = await this.resolve(uri);
 		if (!node || isObject(node)) {
 			throw FileSystemError.FileNotFound(uri);
 		}
@@ -158,7 +181,7 @@ export class VirtualFilesystem implements FileSystemProvider {
 
 	public async readFile(uri: Uri): Promise<Uint8Array> {
 		const system = getTargetSystem(uri);
-		const node = this.lookup(uri);
+		const node = await this.resolve(uri);
 		if (!node || !system) {
 			throw FileSystemError.FileNotFound(uri);
 		}
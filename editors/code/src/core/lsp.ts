@@ -23,3 +23,32 @@ export type LanguageServerMethods = {
 		result: { kind: 'alreadyConnected' | 'created' | 'restored' };
 	};
 };
+
+/**
+ * Typedefs for the custom requests the language server sends to the editor
+ */
+export type LanguageClientMethods = {
+	'abap/selectTransport': {
+		params: {
+			objectUri: string;
+			package: string;
+			requests: TransportOption[];
+		};
+		result: TransportSelection | null;
+	};
+	'abap/documentContent': {
+		params: { uri: string };
+		result: string | null;
+	};
+};
+
+export type TransportOption = {
+	number: string;
+	description: string;
+	owner: string;
+	target: string;
+};
+
+export type TransportSelection =
+	| { kind: 'existing'; number: string }
+	| { kind: 'new'; description: string; target?: string };
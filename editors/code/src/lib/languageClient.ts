@@ -8,7 +8,11 @@ import {
 } from 'vscode-languageclient/node';
 import { window, workspace } from 'vscode';
 import { type ConnectionData } from 'core';
-import type { LanguageServerMethods } from 'core/lsp';
+import type {
+	LanguageClientMethods,
+	LanguageServerMethods,
+	TransportSelection,
+} from 'core/lsp';
 import { establishServerConnection } from 'core/client';
 import { ADT_URI_SCHEME } from './uri';
 import type { Socket } from 'net';
@@ -63,10 +67,14 @@ export class AbapLanguageClient extends LanguageClient {
 			outputChannel: ch,
 			errorHandler: new ClientErrorHandler(),
 			documentSelector: [{ scheme: ADT_URI_SCHEME, language: 'abap' }],
+			initializationOptions: () => workspace.getConfiguration('abap'),
 			synchronize: {
+				configurationSection: 'abap',
 				fileEvents: workspace.createFileSystemWatcher('**/*.clas'),
 			},
 		});
+		this.onRequest('abap/selectTransport', selectTransport);
+		this.onRequest('abap/documentContent', documentContent);
 	}
 
 	public async invokeCustom<T extends keyof LanguageServerMethods>(
@@ -87,3 +95,47 @@ export class AbapLanguageClient extends LanguageClient {
 		this.socket;
 	}
 }
+
+/**
+ * The current content of an open document, for the server to resynchronize it.
+ */
+function documentContent(
+	params: LanguageClientMethods['abap/documentContent']['params'],
+): LanguageClientMethods['abap/documentContent']['result'] {
+	const document = workspace.textDocuments.find(
+		(doc) => doc.uri.toString() === params.uri,
+	);
+	return document?.getText() ?? null;
+}
+
+/**
+ * Lets the user pick the transport request to record a change in, or create a new one.
+ */
+async function selectTransport(
+	params: LanguageClientMethods['abap/selectTransport']['params'],
+): Promise<TransportSelection | null> {
+	const create = { label: '$(add) Create a new request', number: undefined };
+	const picked = await window.showQuickPick(
+		[
+			...params.requests.map((r) => ({
+				label: r.number,
+				description: r.description,
+				detail: `${r.owner} → ${r.target}`,
+				number: r.number,
+			})),
+			create,
+		],
+		{ title: `Transport request for ${params.objectUri} (${params.package})` },
+	);
+	if (!picked) {
+		return null;
+	}
+	if (picked.number) {
+		return { kind: 'existing', number: picked.number };
+	}
+
+	const description = await window.showInputBox({
+		title: 'Description of the new request',
+	});
+	return description ? { kind: 'new', description } : null;
+}
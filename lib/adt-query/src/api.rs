@@ -1,5 +1,15 @@
+pub mod abapsource;
+pub mod activation;
+pub mod atc;
+pub mod aunit;
 pub mod checkruns;
+pub mod classes;
 pub mod core;
+pub mod cts;
+pub mod functions;
+pub mod interfaces;
+pub mod navigation;
 pub mod object;
 pub mod programs;
 pub mod repository;
+pub mod usagereferences;
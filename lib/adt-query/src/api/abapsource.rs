@@ -0,0 +1,324 @@
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderValue, header};
+use std::borrow::Cow;
+
+use crate::QueryParameters;
+use crate::models::{abapsource, asx::AsxData, serialize::IntoXmlRoot};
+use crate::operation::{Operation, Stateless};
+use crate::response::{Plain, Success};
+
+/// Retrieves information about the element at a position of a source, e.g. the
+/// description of a data element or the methods of a class.
+///
+/// The (unsaved) source is sent along, so the position refers to what is in the editor.
+#[derive(Builder, Debug, Clone)]
+pub struct ElementInfoRequest<'a> {
+    /// The URI of the source, for example `/sap/bc/adt/programs/programs/zdemo1/source/main`
+    #[builder(setter(into))]
+    source_uri: Cow<'a, str>,
+
+    /// The current content of the source.
+    #[builder(setter(into))]
+    source: Cow<'a, str>,
+
+    /// The 1-based line of the position.
+    line: u32,
+
+    /// The 0-based column of the position.
+    column: u32,
+}
+
+impl Operation for ElementInfoRequest<'_> {
+    type Response = Success<abapsource::ElementInfo>;
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    fn url(&self) -> Cow<'static, str> {
+        "abapsource/codecompletion/elementinfo".into()
+    }
+
+    fn parameters(&self) -> QueryParameters {
+        let mut params = QueryParameters::default();
+        params.push(
+            "uri",
+            format!("{}#start={},{}", self.source_uri, self.line, self.column),
+        );
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::CONTENT_TYPE, HeaderValue::from_static("text/plain"));
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static("application/vnd.sap.adt.elementinfo+xml"),
+        );
+        Some(headers)
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(Ok(self.source.clone().into_owned()))
+    }
+}
+
+/// Retrieves the code completion proposals at a position of a source.
+///
+/// The (unsaved) source is sent along, so the position refers to what is in the editor.
+#[derive(Builder, Debug, Clone)]
+pub struct CodeCompletionProposals<'a> {
+    /// The URI of the source, for example `/sap/bc/adt/programs/programs/zdemo1/source/main`
+    #[builder(setter(into))]
+    source_uri: Cow<'a, str>,
+
+    /// The current content of the source.
+    #[builder(setter(into))]
+    source: Cow<'a, str>,
+
+    /// The 1-based line of the position.
+    line: u32,
+
+    /// The 0-based column of the position.
+    column: u32,
+}
+
+impl Operation for CodeCompletionProposals<'_> {
+    type Response = Success<AsxData<abapsource::CompletionProposals>>;
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    fn url(&self) -> Cow<'static, str> {
+        "abapsource/codecompletion/proposal".into()
+    }
+
+    fn parameters(&self) -> QueryParameters {
+        let mut params = QueryParameters::default();
+        params
+            .push(
+                "uri",
+                format!("{}#start={},{}", self.source_uri, self.line, self.column),
+            )
+            .push("signalCompleteness", true);
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::CONTENT_TYPE, HeaderValue::from_static("text/plain"));
+        headers.insert(header::ACCEPT, HeaderValue::from_static("application/*"));
+        Some(headers)
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(Ok(self.source.clone().into_owned()))
+    }
+}
+
+/// Retrieves the full pattern to insert for a completion proposal, e.g. the
+/// `CALL FUNCTION` statement of a function module along with its parameters.
+///
+/// Only available for proposals that [`has_insertion`](abapsource::CompletionProposal::has_insertion).
+#[derive(Builder, Debug, Clone)]
+pub struct CodeCompletionInsertion<'a> {
+    /// The URI of the source, for example `/sap/bc/adt/programs/programs/zdemo1/source/main`
+    #[builder(setter(into))]
+    source_uri: Cow<'a, str>,
+
+    /// The current content of the source.
+    #[builder(setter(into))]
+    source: Cow<'a, str>,
+
+    /// The 1-based line of the position.
+    line: u32,
+
+    /// The 0-based column of the position.
+    column: u32,
+
+    /// The [`identifier`](abapsource::CompletionProposal::identifier) of the proposal.
+    #[builder(setter(into))]
+    pattern_key: Cow<'a, str>,
+}
+
+impl<'a> Operation for CodeCompletionInsertion<'a> {
+    type Response = Success<Plain<'a>>;
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    fn url(&self) -> Cow<'static, str> {
+        "abapsource/codecompletion/insertion".into()
+    }
+
+    fn parameters(&self) -> QueryParameters {
+        let mut params = QueryParameters::default();
+        params
+            .push(
+                "uri",
+                format!("{}#start={},{}", self.source_uri, self.line, self.column),
+            )
+            .push("patternKey", &self.pattern_key);
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::CONTENT_TYPE, HeaderValue::from_static("text/plain"));
+        headers.insert(header::ACCEPT, HeaderValue::from_static("text/plain"));
+        Some(headers)
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(Ok(self.source.clone().into_owned()))
+    }
+}
+
+/// Formats a source with the pretty printer of the backend, according to the
+/// [`PrettyPrinterSettings`](abapsource::PrettyPrinterSettings) of the user.
+#[derive(Builder, Debug, Clone)]
+pub struct PrettyPrint<'a> {
+    /// The content of the source to format.
+    #[builder(setter(into))]
+    source: Cow<'a, str>,
+}
+
+impl<'a> Operation for PrettyPrint<'a> {
+    type Response = Success<Plain<'a>>;
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    fn url(&self) -> Cow<'static, str> {
+        "abapsource/prettyprinter".into()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("text/plain; charset=utf-8"),
+        );
+        headers.insert(header::ACCEPT, HeaderValue::from_static("text/plain"));
+        Some(headers)
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(Ok(self.source.clone().into_owned()))
+    }
+}
+
+/// Retrieves the settings of the pretty printer of the user.
+pub struct PrettyPrinterSettingsRequest {}
+
+impl Operation for PrettyPrinterSettingsRequest {
+    type Response = Success<abapsource::PrettyPrinterSettings>;
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        "abapsource/prettyprinter/settings".into()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static("application/vnd.sap.adt.ppsettings.v2+xml"),
+        );
+        Some(headers)
+    }
+}
+
+/// Changes the settings of the pretty printer of the user, these apply to all
+/// editors of the user, including the SAP GUI.
+#[derive(Builder, Debug, Clone)]
+pub struct UpdatePrettyPrinterSettings {
+    settings: abapsource::PrettyPrinterSettings,
+}
+
+impl Operation for UpdatePrettyPrinterSettings {
+    type Response = Success<()>;
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::PUT;
+
+    fn url(&self) -> Cow<'static, str> {
+        "abapsource/prettyprinter/settings".into()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/vnd.sap.adt.ppsettings.v2+xml"),
+        );
+        Some(headers)
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(self.settings.into_xml_root())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn element_info_uri_contains_position() {
+        let op = ElementInfoRequestBuilder::default()
+            .source_uri("/sap/bc/adt/programs/programs/zdemo1/source/main")
+            .source("REPORT zdemo1.")
+            .line(1)
+            .column(7)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            op.parameters().query_pairs(),
+            vec![(
+                "uri".to_string(),
+                "/sap/bc/adt/programs/programs/zdemo1/source/main#start=1,7".to_string()
+            ),]
+        );
+    }
+
+    #[test]
+    fn completion_insertion_passes_pattern_key() {
+        let op = CodeCompletionInsertionBuilder::default()
+            .source_uri("/sap/bc/adt/programs/programs/zdemo1/source/main")
+            .source("REPORT zdemo1.\nCALL FUNCTION 'RFC_PING'")
+            .line(2)
+            .column(15)
+            .pattern_key("RFC_PING")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            op.parameters().query_pairs(),
+            vec![
+                (
+                    "uri".to_string(),
+                    "/sap/bc/adt/programs/programs/zdemo1/source/main#start=2,15".to_string()
+                ),
+                ("patternKey".to_string(), "RFC_PING".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn update_pretty_printer_settings_body() {
+        let op = UpdatePrettyPrinterSettingsBuilder::default()
+            .settings(abapsource::PrettyPrinterSettings::new(
+                true,
+                abapsource::PrettyPrinterStyle::KeywordUpper,
+            ))
+            .build()
+            .unwrap();
+
+        let body = op.body().unwrap().unwrap();
+        assert!(body.contains(r#"abapformatter:indentation="true""#));
+        assert!(body.contains(r#"abapformatter:style="keywordUpper""#));
+    }
+}
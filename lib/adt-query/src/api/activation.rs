@@ -0,0 +1,129 @@
+use std::borrow::Cow;
+
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderValue, header};
+
+use crate::operation::{Operation, Stateless};
+use crate::response::Success;
+use crate::{
+    QueryParameters,
+    api::object::ObjectAction,
+    models::{
+        activation::{ActivationResult, InactiveObjects},
+        adtcore::{ObjectReference, ObjectReferences},
+        serialize::IntoXmlRoot,
+    },
+};
+
+/// Activates one or more objects, making their inactive version the active one.
+///
+/// ## Example:
+/// ```
+/// use adt_query::{api::activation::ActivateBuilder, models::adtcore::ObjectReferenceBuilder};
+///
+/// ActivateBuilder::default()
+///     .object(
+///         ObjectReferenceBuilder::default()
+///             .uri("/sap/bc/adt/programs/programs/z_my_program")
+///             .name("Z_MY_PROGRAM")
+///             .build()
+///             .unwrap(),
+///     )
+///     .build();
+/// ```
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct Activate {
+    /// The objects to activate together.
+    #[builder(setter(each(name = "object")))]
+    objects: Vec<ObjectReference>,
+
+    /// Whether the server should check for other inactive objects that have to
+    /// be activated together with the objects, see [`ActivationResult::PendingObjects`]
+    #[builder(default = true)]
+    preaudit: bool,
+}
+
+impl Operation for Activate {
+    type Response = Success<ActivationResult>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    fn url(&self) -> Cow<'static, str> {
+        "activation".into()
+    }
+
+    fn parameters(&self) -> QueryParameters {
+        let mut params = QueryParameters::default();
+        params.push("method", ObjectAction::Activate.as_str().to_lowercase());
+        params.push("preauditRequested", self.preaudit);
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/xml"),
+        );
+        headers.insert(header::ACCEPT, HeaderValue::from_static("application/xml"));
+        Some(headers)
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(ObjectReferences::from(self.objects.clone()).into_xml_root())
+    }
+}
+
+/// Fetches the inactive objects of the current user, i.e. changes that were saved
+/// but not activated yet.
+///
+/// The content type of the response is `inactivectrlobjects`, hence the common name.
+#[derive(Debug, Default)]
+pub struct InactiveObjectList {}
+
+impl Operation for InactiveObjectList {
+    type Response = Success<InactiveObjects>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        "activation/inactiveobjects".into()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static("application/vnd.sap.adt.inactivectrlobjects.v1+xml"),
+        );
+        Some(headers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::adtcore::ObjectReferenceBuilder;
+
+    #[test]
+    fn activation_requests_preaudit_by_default() {
+        let op = ActivateBuilder::default()
+            .object(
+                ObjectReferenceBuilder::default()
+                    .uri("/sap/bc/adt/programs/programs/ztest")
+                    .name("ZTEST")
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+
+        assert!(op.preaudit);
+        assert!(op.body().unwrap().unwrap().contains("ZTEST"));
+    }
+}
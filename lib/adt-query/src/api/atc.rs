@@ -0,0 +1,316 @@
+use std::borrow::Cow;
+
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderValue, header};
+
+use crate::{
+    QueryParameters,
+    models::{
+        adtcore::ObjectReference,
+        atc::{Customizing, ExemptionRequest, ExemptionStatus, Run, Worklist, WorklistRun},
+        serialize::IntoXmlRoot,
+    },
+    operation::{Operation, Stateless},
+    response::{Plain, Success},
+};
+
+/// The object set of a worklist that holds the findings of all its runs.
+const ALL_OBJECTS: &str = "99999999999999999999999999999999";
+
+const EXEMPTION: &str = "application/atc.xmptapply.v1+xml";
+
+/// Retrieves the ATC customizing of the system, e.g. the check variant to run and
+/// the reasons exemptions can be requested for.
+#[derive(Debug, Builder)]
+pub struct AtcCustomizing {}
+
+impl Operation for AtcCustomizing {
+    type Response = Success<Customizing>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        "atc/customizing".into()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static("application/xml, application/vnd.sap.atc.customizing-v1+xml"),
+        );
+        Some(headers)
+    }
+}
+
+/// Creates a worklist for the runs of a check variant, responding with its id.
+///
+/// ## Example:
+/// ```
+/// use adt_query::api::atc::CreateWorklistBuilder;
+///
+/// CreateWorklistBuilder::default().check_variant("DEFAULT").build();
+/// ```
+#[derive(Debug, Builder)]
+pub struct CreateWorklist<'a> {
+    /// The check variant to run, e.g. the
+    /// [`system_check_variant`](crate::models::atc::Customizing::system_check_variant)
+    #[builder(setter(into))]
+    check_variant: Cow<'a, str>,
+}
+
+impl Operation for CreateWorklist<'_> {
+    type Response = Success<Plain<'static>>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    fn url(&self) -> Cow<'static, str> {
+        "atc/worklists".into()
+    }
+
+    fn parameters(&self) -> QueryParameters {
+        let mut params = QueryParameters::default();
+        params.push("checkVariant", &self.check_variant);
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::ACCEPT, HeaderValue::from_static("text/plain"));
+        Some(headers)
+    }
+}
+
+/// Runs the checks of a worklist for a set of objects.
+///
+/// The findings are added to the worklist, which is complete once all objects have
+/// been checked, see [`AtcWorklist`].
+///
+/// ## Example:
+/// ```
+/// use adt_query::{api::atc::RunAtcBuilder, models::adtcore::ObjectReferenceBuilder};
+///
+/// RunAtcBuilder::default()
+///     .worklist_id("0242AC1100021EDFB3D1E4B9A3F6C0F2")
+///     .object(
+///         ObjectReferenceBuilder::default()
+///             .uri("/sap/bc/adt/oo/classes/zcl_order")
+///             .name("ZCL_ORDER")
+///             .build()
+///             .unwrap(),
+///     )
+///     .build();
+/// ```
+#[derive(Debug, Builder)]
+pub struct RunAtc<'a> {
+    /// The id of the worklist, see [`CreateWorklist`].
+    #[builder(setter(into))]
+    worklist_id: Cow<'a, str>,
+
+    /// The objects to check.
+    #[builder(setter(each(name = "object")))]
+    objects: Vec<ObjectReference>,
+
+    /// The maximum number of findings to report.
+    #[builder(default = 100)]
+    maximum_verdicts: u32,
+}
+
+impl Operation for RunAtc<'_> {
+    type Response = Success<WorklistRun>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    fn url(&self) -> Cow<'static, str> {
+        "atc/runs".into()
+    }
+
+    fn parameters(&self) -> QueryParameters {
+        let mut params = QueryParameters::default();
+        params.push("worklistId", &self.worklist_id);
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/xml"),
+        );
+        headers.insert(header::ACCEPT, HeaderValue::from_static("application/xml"));
+        Some(headers)
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(Run::new(self.objects.clone(), self.maximum_verdicts).into_xml_root())
+    }
+}
+
+/// Retrieves the findings of a worklist, polled until the worklist is
+/// [`complete`](Worklist::complete) while a run is still in progress.
+///
+/// ## Example:
+/// ```
+/// use adt_query::api::atc::AtcWorklistBuilder;
+///
+/// AtcWorklistBuilder::default()
+///     .id("0242AC1100021EDFB3D1E4B9A3F6C0F2")
+///     .timestamp("2025-03-07T10:12:45Z")
+///     .build();
+/// ```
+#[derive(Debug, Builder)]
+pub struct AtcWorklist<'a> {
+    /// The id of the worklist, see [`CreateWorklist`].
+    #[builder(setter(into))]
+    id: Cow<'a, str>,
+
+    /// The timestamp of the run whose findings to retrieve, see [`WorklistRun`].
+    #[builder(setter(into, strip_option), default)]
+    timestamp: Option<Cow<'a, str>>,
+
+    /// Whether to include findings that are exempted already.
+    #[builder(default = false)]
+    include_exempted: bool,
+}
+
+impl Operation for AtcWorklist<'_> {
+    type Response = Success<Worklist>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!("atc/worklists/{}", self.id).into()
+    }
+
+    fn parameters(&self) -> QueryParameters {
+        let mut params = QueryParameters::default();
+        params
+            .push_opt("timestamp", self.timestamp.as_ref())
+            .push("usedObjectSet", ALL_OBJECTS)
+            .push("includeExemptedFindings", self.include_exempted);
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static("application/atc.worklist.v1+xml"),
+        );
+        Some(headers)
+    }
+}
+
+/// Requests an exemption of a finding, which takes effect once it is approved.
+///
+/// ## Example:
+/// ```
+/// use adt_query::api::atc::RequestExemptionBuilder;
+///
+/// RequestExemptionBuilder::default()
+///     .finding_uri("/sap/bc/adt/atc/findings/itemid/0242AC1100021EDFB3D1E4B9A3F6C0F2/1")
+///     .package("ZORDERS")
+///     .reason("FPOS")
+///     .justification("The variable is used dynamically.")
+///     .approver("QUALITY")
+///     .build();
+/// ```
+#[derive(Debug, Builder)]
+pub struct RequestExemption<'a> {
+    /// The [`uri`](crate::models::atc::Finding::uri) of the finding.
+    #[builder(setter(into))]
+    finding_uri: Cow<'a, str>,
+
+    /// The package of the object of the finding.
+    #[builder(setter(into))]
+    package: Cow<'a, str>,
+
+    /// The id of one of the
+    /// [`exemption_reasons`](crate::models::atc::Customizing::exemption_reasons), e.g. `FPOS`
+    #[builder(setter(into))]
+    reason: Cow<'a, str>,
+
+    #[builder(setter(into), default)]
+    justification: Cow<'a, str>,
+
+    /// The user to approve the exemption.
+    #[builder(setter(into))]
+    approver: Cow<'a, str>,
+}
+
+impl Operation for RequestExemption<'_> {
+    type Response = Success<ExemptionStatus>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    fn url(&self) -> Cow<'static, str> {
+        "atc/exemptions/apply".into()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::CONTENT_TYPE, HeaderValue::from_static(EXEMPTION));
+        headers.insert(header::ACCEPT, HeaderValue::from_static(EXEMPTION));
+        Some(headers)
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        let request = ExemptionRequest::new(
+            &self.finding_uri,
+            &self.package,
+            &self.reason,
+            &self.justification,
+            &self.approver,
+        );
+        Some(request.into_xml_root())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::adtcore::ObjectReferenceBuilder;
+
+    #[test]
+    fn worklist_is_created_for_check_variant() {
+        let op = CreateWorklistBuilder::default()
+            .check_variant("DEFAULT")
+            .build()
+            .unwrap();
+
+        assert_eq!(op.url(), "atc/worklists");
+        assert_eq!(
+            op.parameters().query_pairs(),
+            vec![("checkVariant".to_string(), "DEFAULT".to_string()),]
+        );
+    }
+
+    #[test]
+    fn run_checks_objects_of_worklist() {
+        let op = RunAtcBuilder::default()
+            .worklist_id("0242AC1100021EDFB3D1E4B9A3F6C0F2")
+            .object(
+                ObjectReferenceBuilder::default()
+                    .uri("/sap/bc/adt/programs/programs/zdemo1")
+                    .name("ZDEMO1")
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+
+        let body = op.body().unwrap().unwrap();
+        assert!(body.contains(r#"maximumVerdicts="100""#));
+        assert!(body.contains("/sap/bc/adt/programs/programs/zdemo1"));
+    }
+}
@@ -0,0 +1,108 @@
+use std::borrow::Cow;
+
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderValue, header};
+
+use crate::{
+    models::{
+        adtcore::ObjectReference,
+        aunit::{Durations, RiskLevels, RunConfiguration, RunResult, TestScope},
+        serialize::IntoXmlRoot,
+    },
+    operation::{Operation, Stateless},
+    response::Success,
+};
+
+/// Runs the ABAP Unit tests of one or more objects and waits for their results.
+///
+/// ## Example:
+/// ```
+/// use adt_query::{api::aunit::RunUnitTestsBuilder, models::adtcore::ObjectReferenceBuilder};
+///
+/// RunUnitTestsBuilder::default()
+///     .object(
+///         ObjectReferenceBuilder::default()
+///             .uri("/sap/bc/adt/oo/classes/zcl_order")
+///             .name("ZCL_ORDER")
+///             .build()
+///             .unwrap(),
+///     )
+///     .coverage(true)
+///     .build();
+/// ```
+#[derive(Debug, Builder)]
+pub struct RunUnitTests {
+    /// The objects whose tests to run.
+    #[builder(setter(each(name = "object")))]
+    objects: Vec<ObjectReference>,
+
+    #[builder(default)]
+    scope: TestScope,
+
+    #[builder(default)]
+    risk_levels: RiskLevels,
+
+    #[builder(default)]
+    durations: Durations,
+
+    /// Whether to measure the code coverage of the tests.
+    #[builder(default = false)]
+    coverage: bool,
+}
+
+impl Operation for RunUnitTests {
+    type Response = Success<RunResult>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    fn url(&self) -> Cow<'static, str> {
+        "abapunit/testruns".into()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/*"),
+        );
+        headers.insert(header::ACCEPT, HeaderValue::from_static("application/*"));
+        Some(headers)
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        let configuration = RunConfiguration::new(
+            self.objects.clone(),
+            self.scope,
+            self.risk_levels,
+            self.durations,
+            self.coverage,
+        );
+        Some(configuration.into_xml_root())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::adtcore::ObjectReferenceBuilder;
+
+    #[test]
+    fn unit_tests_are_run_without_coverage_by_default() {
+        let op = RunUnitTestsBuilder::default()
+            .object(
+                ObjectReferenceBuilder::default()
+                    .uri("/sap/bc/adt/programs/programs/ztest")
+                    .name("ZTEST")
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+
+        let body = op.body().unwrap().unwrap();
+        assert!(body.contains(r#"<coverage active="false""#));
+        assert!(body.contains("/sap/bc/adt/programs/programs/ztest"));
+    }
+}
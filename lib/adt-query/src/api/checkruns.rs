@@ -4,6 +4,7 @@ use std::borrow::Cow;
 
 use crate::QueryParameters;
 use crate::models::checkrun::{ObjectList, Reports};
+use crate::models::serialize::IntoXmlRoot;
 use crate::operation::{Operation, Stateless};
 use crate::response::Success;
 
@@ -37,7 +38,15 @@ impl<'a> Operation for RunCheck<'a> {
             header::CONTENT_TYPE,
             HeaderValue::from_static("application/vnd.sap.adt.checkobjects+xml"),
         );
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static("application/vnd.sap.adt.checkmessages+xml"),
+        );
 
         Some(headers)
     }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(self.objects.into_xml_root())
+    }
 }
@@ -0,0 +1,185 @@
+use std::borrow::Cow;
+
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderValue, header};
+
+use crate::operation::{Operation, Stateless, cache_headers};
+use crate::response::{CacheControlled, Plain, Success};
+use crate::{
+    QueryParameters,
+    models::{
+        adtcore,
+        atom::VersionFeed,
+        class::{AbapClass, ClassInclude},
+    },
+};
+
+/// Fetches the metadata of a global class, including its includes.
+///
+/// Responsible ABAP REST Handler: `CL_OO_ADT_RES_CLASS`
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct Class<'a> {
+    /// The name of the class, for example `zcl_order_service`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The version of the class to get the data of, see [`adtcore::Version`]
+    /// If not specified in the query, the inactive version is the default if one exists.
+    #[builder(default = None)]
+    version: Option<adtcore::Version>,
+
+    /// Etag of the class used for caching purposes.
+    #[builder(setter(into), default = None)]
+    etag: Option<Cow<'a, str>>,
+}
+
+impl Operation for Class<'_> {
+    type Response = CacheControlled<AbapClass>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!("oo/classes/{}", self.name).into()
+    }
+
+    fn parameters(&self) -> QueryParameters {
+        let mut params = QueryParameters::default();
+        params.push_opt("version", self.version.clone());
+        params
+    }
+
+    /// Headers need to handle whether we have a cached version locally and provide the ETag.
+    fn headers(&self) -> Option<http::HeaderMap> {
+        Some(cache_headers(
+            self.etag.as_deref(),
+            "application/vnd.sap.adt.oo.classes.v4+xml",
+        ))
+    }
+}
+
+/// Fetches the source code of an include of a global class.
+///
+/// Writing the source is done through [`crate::api::object::UpdateSourceCode`]
+/// with [`crate::api::object::SourceCodeObject::ClassInclude`].
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct ClassSource<'a> {
+    /// The name of the class, for example `zcl_order_service`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The include of the class to get the source of, defaults to [`ClassInclude::Main`]
+    #[builder(default = ClassInclude::Main)]
+    include: ClassInclude,
+
+    /// The version of the include to get the source of, e.g. `inactive`
+    #[builder(default)]
+    version: Option<adtcore::Version>,
+
+    #[builder(setter(into), default)]
+    etag: Option<Cow<'a, str>>,
+}
+
+impl<'a> Operation for ClassSource<'a> {
+    type Response = CacheControlled<Plain<'a>>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!("oo/classes/{}/{}", self.name, self.include.source_path()).into()
+    }
+
+    fn parameters(&self) -> QueryParameters {
+        let mut params = QueryParameters::default();
+        params.push_opt("version", self.version.clone());
+        params
+    }
+
+    /// Headers need to handle whether we have a cached version locally and provide the ETag.
+    fn headers(&self) -> Option<http::HeaderMap> {
+        Some(cache_headers(self.etag.as_deref(), "text/plain"))
+    }
+}
+
+/// Fetches the versions of an include of a global class.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct ClassVersions<'a> {
+    /// The name of the class, for example `zcl_order_service`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The include of the class to get the versions of, defaults to [`ClassInclude::Main`]
+    #[builder(default = ClassInclude::Main)]
+    include: ClassInclude,
+}
+
+impl Operation for ClassVersions<'_> {
+    type Response = Success<VersionFeed>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!(
+            "oo/classes/{}/{}/versions",
+            self.name,
+            self.include.source_path()
+        )
+        .into()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static("application/atom+xml;type=feed"),
+        );
+        Some(headers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn class_source_defaults_to_main_include() {
+        let op = ClassSourceBuilder::default()
+            .name("zcl_order_service")
+            .build()
+            .unwrap();
+
+        assert_eq!(op.url(), "oo/classes/zcl_order_service/source/main");
+    }
+
+    #[test]
+    fn class_source_targets_local_include() {
+        let op = ClassSourceBuilder::default()
+            .name("zcl_order_service")
+            .include(ClassInclude::Implementations)
+            .version(adtcore::Version::Inactive)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            op.url(),
+            "oo/classes/zcl_order_service/includes/implementations"
+        );
+    }
+
+    #[test]
+    fn class_name_is_mandatory() {
+        let result = ClassBuilder::default()
+            .version(adtcore::Version::Active)
+            .build();
+
+        assert!(matches!(result, Err(_)), "Name should not be optional");
+    }
+}
@@ -0,0 +1,407 @@
+use std::borrow::Cow;
+
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderValue, header};
+
+use crate::{
+    QueryParameters,
+    models::{
+        asx::AsxData,
+        cts::{
+            self, CorrectionRequest, RequestType, TransportCheckObject, TransportOrganizer,
+            UserAction, UserActionResult,
+        },
+        serialize::IntoXmlRoot,
+    },
+    operation::{Operation, Stateless},
+    response::{Plain, Success},
+};
+
+const TRANSPORT_ORGANIZER: &str = "application/vnd.sap.adt.transportorganizer.v1+xml";
+
+const CHECK_DATA: &str =
+    "application/vnd.sap.as+xml; charset=UTF-8; dataname=com.sap.adt.transport.service.checkData";
+
+const CORRECTION_REQUEST: &str =
+    "application/vnd.sap.as+xml; charset=UTF-8; dataname=com.sap.adt.CreateCorrectionRequest";
+
+/// Retrieves the transport requests of a user along with their tasks and the objects
+/// recorded in them, as the "Transport Organizer" view does.
+///
+/// ## Example:
+/// ```
+/// use adt_query::api::cts::TransportRequestsBuilder;
+///
+/// TransportRequestsBuilder::default().user("DEVELOPER").build();
+/// ```
+#[derive(Debug, Builder)]
+pub struct TransportRequests<'a> {
+    /// The owner of the requests, or of at least one of their tasks.
+    #[builder(setter(into))]
+    user: Cow<'a, str>,
+
+    /// Whether to group the requests by their target.
+    #[builder(default = true)]
+    targets: bool,
+}
+
+impl Operation for TransportRequests<'_> {
+    type Response = Success<TransportOrganizer>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        "cts/transportrequests".into()
+    }
+
+    fn parameters(&self) -> QueryParameters {
+        let mut params = QueryParameters::default();
+        params
+            .push("user", &self.user)
+            .push("targets", self.targets);
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static(TRANSPORT_ORGANIZER),
+        );
+        Some(headers)
+    }
+}
+
+/// Creates a transport request with a task for its owner.
+///
+/// ## Example:
+/// ```
+/// use adt_query::api::cts::CreateTransportRequestBuilder;
+///
+/// CreateTransportRequestBuilder::default()
+///     .description("Order management")
+///     .target("LOCAL")
+///     .owner("DEVELOPER")
+///     .build();
+/// ```
+#[derive(Debug, Builder)]
+pub struct CreateTransportRequest<'a> {
+    #[builder(setter(into))]
+    description: Cow<'a, str>,
+
+    #[builder(default)]
+    request_type: RequestType,
+
+    /// The system the request is transported to, `LOCAL` if it is not transported.
+    #[builder(setter(into))]
+    target: Cow<'a, str>,
+
+    /// The owner of the task of the request.
+    #[builder(setter(into))]
+    owner: Cow<'a, str>,
+}
+
+impl Operation for CreateTransportRequest<'_> {
+    type Response = Success<UserActionResult>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    fn url(&self) -> Cow<'static, str> {
+        "cts/transportrequests".into()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static(TRANSPORT_ORGANIZER),
+        );
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static(TRANSPORT_ORGANIZER),
+        );
+        Some(headers)
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        let action = UserAction::new_request(
+            &self.description,
+            self.request_type,
+            &self.target,
+            &self.owner,
+        );
+        Some(action.into_xml_root())
+    }
+}
+
+/// Records a whole object in a request (or task), e.g. to transport it without
+/// changing it.
+///
+/// Objects are recorded automatically when they are saved in a transportable package,
+/// see [`CheckTransport`].
+#[derive(Debug, Builder)]
+pub struct AddTransportObject<'a> {
+    /// The number of the request, e.g. `A4HK900089`
+    #[builder(setter(into))]
+    number: Cow<'a, str>,
+
+    /// The type of the object, e.g. `PROG` or `CLAS`.
+    #[builder(setter(into))]
+    object_type: Cow<'a, str>,
+
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+}
+
+impl Operation for AddTransportObject<'_> {
+    type Response = Success<UserActionResult>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::PUT;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!("cts/transportrequests/{}", self.number).into()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static(TRANSPORT_ORGANIZER),
+        );
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static(TRANSPORT_ORGANIZER),
+        );
+        Some(headers)
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(UserAction::new_object(&self.object_type, &self.name).into_xml_root())
+    }
+}
+
+/// Releases a task or request, tasks have to be released before their request.
+///
+/// The result of the release is reported in the
+/// [`release_reports`](UserActionResult::release_reports) of the response.
+#[derive(Debug, Builder)]
+pub struct ReleaseTransportRequest<'a> {
+    /// The number of the request or task, e.g. `A4HK900089`
+    #[builder(setter(into))]
+    number: Cow<'a, str>,
+
+    /// Whether to release the request even if objects in it are still locked.
+    #[builder(default = false)]
+    ignore_locks: bool,
+}
+
+impl Operation for ReleaseTransportRequest<'_> {
+    type Response = Success<UserActionResult>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!(
+            "cts/transportrequests/{}/{}",
+            self.number,
+            cts::release_action(self.ignore_locks)
+        )
+        .into()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/xml"),
+        );
+        headers.insert(header::ACCEPT, HeaderValue::from_static("application/*"));
+        Some(headers)
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(UserAction::release(self.ignore_locks).into_xml_root())
+    }
+}
+
+/// Checks whether changes to an object have to be recorded in a transport and which
+/// requests of the user it can be recorded in.
+///
+/// ## Example:
+/// ```
+/// use adt_query::api::cts::CheckTransportBuilder;
+///
+/// CheckTransportBuilder::default()
+///     .object_uri("/sap/bc/adt/programs/programs/z_my_program")
+///     .build();
+/// ```
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct CheckTransport<'a> {
+    /// The absolute uri of the object, e.g. `/sap/bc/adt/programs/programs/z_my_program`
+    #[builder(setter(into))]
+    object_uri: Cow<'a, str>,
+
+    /// The package of the object, only required for objects that do not exist yet.
+    #[builder(setter(into), default)]
+    package: Option<Cow<'a, str>>,
+}
+
+impl Operation for CheckTransport<'_> {
+    type Response = Success<AsxData<cts::TransportCheck>>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    fn url(&self) -> Cow<'static, str> {
+        "cts/transportchecks".into()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::CONTENT_TYPE, HeaderValue::from_static(CHECK_DATA));
+        headers.insert(header::ACCEPT, HeaderValue::from_static(CHECK_DATA));
+        Some(headers)
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        let object = TransportCheckObject::new(&self.object_uri, self.package.as_deref());
+        Some(AsxData::new(object).into_xml_root())
+    }
+}
+
+/// Creates a workbench request to record the change of an object in, the target of the
+/// request is derived from the transport layer of the package.
+///
+/// The response is the uri of the request, which ends with its number, e.g.
+/// `/com.sap.cts/object_record/A4HK900089`
+///
+/// ## Example:
+/// ```
+/// use adt_query::api::cts::CreateObjectTransportBuilder;
+///
+/// CreateObjectTransportBuilder::default()
+///     .object_uri("/sap/bc/adt/programs/programs/z_my_program")
+///     .package("ZORDERS")
+///     .description("Order management")
+///     .build();
+/// ```
+#[derive(Debug, Builder)]
+pub struct CreateObjectTransport<'a> {
+    /// The absolute uri of the object, e.g. `/sap/bc/adt/programs/programs/z_my_program`
+    #[builder(setter(into))]
+    object_uri: Cow<'a, str>,
+
+    /// The package of the object, see [`TransportCheck`](cts::TransportCheck).
+    #[builder(setter(into))]
+    package: Cow<'a, str>,
+
+    #[builder(setter(into))]
+    description: Cow<'a, str>,
+}
+
+impl Operation for CreateObjectTransport<'_> {
+    type Response = Success<Plain<'static>>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    fn url(&self) -> Cow<'static, str> {
+        "cts/transports".into()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static(CORRECTION_REQUEST),
+        );
+        headers.insert(header::ACCEPT, HeaderValue::from_static("text/plain"));
+        Some(headers)
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        let request = CorrectionRequest::new(&self.object_uri, &self.package, &self.description);
+        Some(AsxData::new(request).into_xml_root())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn release_ignoring_locks_uses_its_own_resource() {
+        let op = ReleaseTransportRequestBuilder::default()
+            .number("A4HK900090")
+            .ignore_locks(true)
+            .build()
+            .unwrap();
+
+        assert_eq!(op.url(), "cts/transportrequests/A4HK900090/relwithignlock");
+        assert!(op.body().unwrap().unwrap().contains("relwithignlock"));
+    }
+
+    #[test]
+    fn object_is_added_to_request() {
+        let op = AddTransportObjectBuilder::default()
+            .number("A4HK900090")
+            .object_type("PROG")
+            .name("ZDEMO1")
+            .build()
+            .unwrap();
+
+        assert_eq!(op.url(), "cts/transportrequests/A4HK900090");
+        assert!(op.body().unwrap().unwrap().contains(r#"tm:name="ZDEMO1""#));
+    }
+
+    #[test]
+    fn transport_check_passes_object_uri() {
+        let op = CheckTransportBuilder::default()
+            .object_uri("/sap/bc/adt/programs/programs/zdemo1")
+            .build()
+            .unwrap();
+
+        let body = op.body().unwrap().unwrap();
+        assert!(body.contains("<URI>/sap/bc/adt/programs/programs/zdemo1</URI>"));
+        assert!(!body.contains("<DEVCLASS"));
+    }
+
+    #[test]
+    fn transport_check_passes_package_of_new_object() {
+        let op = CheckTransportBuilder::default()
+            .object_uri("/sap/bc/adt/programs/programs/zdemo1")
+            .package("ZORDERS")
+            .build()
+            .unwrap();
+
+        let body = op.body().unwrap().unwrap();
+        assert!(body.contains("<DEVCLASS>ZORDERS</DEVCLASS>"));
+    }
+
+    #[test]
+    fn object_transport_passes_package_and_object() {
+        let op = CreateObjectTransportBuilder::default()
+            .object_uri("/sap/bc/adt/programs/programs/zdemo1")
+            .package("ZORDERS")
+            .description("Order management")
+            .build()
+            .unwrap();
+
+        assert_eq!(op.url(), "cts/transports");
+        let body = op.body().unwrap().unwrap();
+        assert!(body.contains("<DEVCLASS>ZORDERS</DEVCLASS>"));
+        assert!(body.contains("<REQUEST_TEXT>Order management</REQUEST_TEXT>"));
+        assert!(body.contains("<REF>/sap/bc/adt/programs/programs/zdemo1</REF>"));
+    }
+}
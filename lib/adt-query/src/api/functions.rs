@@ -0,0 +1,329 @@
+use std::borrow::Cow;
+
+use derive_builder::Builder;
+
+use crate::operation::{Operation, Stateless, cache_headers};
+use crate::response::{CacheControlled, Plain};
+use crate::{
+    QueryParameters,
+    models::{
+        adtcore,
+        function::{
+            FunctionGroup as FunctionGroupData, FunctionGroupInclude as IncludeData,
+            FunctionModule as FunctionModuleData,
+        },
+    },
+};
+
+/// Fetches the metadata of a function group.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct FunctionGroup<'a> {
+    /// The name of the function group, for example `zorder`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The version of the function group to get the data of, see [`adtcore::Version`]
+    #[builder(default = None)]
+    version: Option<adtcore::Version>,
+
+    /// Etag of the function group used for caching purposes.
+    #[builder(setter(into), default = None)]
+    etag: Option<Cow<'a, str>>,
+}
+
+impl Operation for FunctionGroup<'_> {
+    type Response = CacheControlled<FunctionGroupData>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!("functions/groups/{}", self.name).into()
+    }
+
+    fn parameters(&self) -> QueryParameters {
+        let mut params = QueryParameters::default();
+        params.push_opt("version", self.version.clone());
+        params
+    }
+
+    fn headers(&self) -> Option<http::HeaderMap> {
+        Some(cache_headers(
+            self.etag.as_deref(),
+            "application/vnd.sap.adt.functions.groups.v3+xml",
+        ))
+    }
+}
+
+/// Fetches the source code of the main program (`SAPL...`) of a function group.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct FunctionGroupSource<'a> {
+    /// The name of the function group, for example `zorder`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The version of the source to get, e.g. `inactive`
+    #[builder(default)]
+    version: Option<adtcore::Version>,
+
+    #[builder(setter(into), default)]
+    etag: Option<Cow<'a, str>>,
+}
+
+impl<'a> Operation for FunctionGroupSource<'a> {
+    type Response = CacheControlled<Plain<'a>>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!("functions/groups/{}/source/main", self.name).into()
+    }
+
+    fn parameters(&self) -> QueryParameters {
+        let mut params = QueryParameters::default();
+        params.push_opt("version", self.version.clone());
+        params
+    }
+
+    fn headers(&self) -> Option<http::HeaderMap> {
+        Some(cache_headers(self.etag.as_deref(), "text/plain"))
+    }
+}
+
+/// Fetches the metadata of a function module, e.g. its processing type.
+///
+/// The parameters of the function module are part of its source code, see
+/// [`FunctionModuleSource`].
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct FunctionModule<'a> {
+    /// The name of the function group the module belongs to, for example `zorder`
+    #[builder(setter(into))]
+    group: Cow<'a, str>,
+
+    /// The name of the function module, for example `z_order_create`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The version of the function module to get the data of, see [`adtcore::Version`]
+    #[builder(default = None)]
+    version: Option<adtcore::Version>,
+
+    /// Etag of the function module used for caching purposes.
+    #[builder(setter(into), default = None)]
+    etag: Option<Cow<'a, str>>,
+}
+
+impl Operation for FunctionModule<'_> {
+    type Response = CacheControlled<FunctionModuleData>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!("functions/groups/{}/fmodules/{}", self.group, self.name).into()
+    }
+
+    fn parameters(&self) -> QueryParameters {
+        let mut params = QueryParameters::default();
+        params.push_opt("version", self.version.clone());
+        params
+    }
+
+    fn headers(&self) -> Option<http::HeaderMap> {
+        Some(cache_headers(
+            self.etag.as_deref(),
+            "application/vnd.sap.adt.functions.fmodules.v3+xml",
+        ))
+    }
+}
+
+/// Fetches the source code of a function module.
+///
+/// Writing the source is done through [`crate::api::object::UpdateSourceCode`]
+/// with [`crate::api::object::SourceCodeObject::FunctionModule`].
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct FunctionModuleSource<'a> {
+    /// The name of the function group the module belongs to, for example `zorder`
+    #[builder(setter(into))]
+    group: Cow<'a, str>,
+
+    /// The name of the function module, for example `z_order_create`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The version of the source to get, e.g. `inactive`
+    #[builder(default)]
+    version: Option<adtcore::Version>,
+
+    #[builder(setter(into), default)]
+    etag: Option<Cow<'a, str>>,
+}
+
+impl<'a> Operation for FunctionModuleSource<'a> {
+    type Response = CacheControlled<Plain<'a>>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!(
+            "functions/groups/{}/fmodules/{}/source/main",
+            self.group, self.name
+        )
+        .into()
+    }
+
+    fn parameters(&self) -> QueryParameters {
+        let mut params = QueryParameters::default();
+        params.push_opt("version", self.version.clone());
+        params
+    }
+
+    fn headers(&self) -> Option<http::HeaderMap> {
+        Some(cache_headers(self.etag.as_deref(), "text/plain"))
+    }
+}
+
+/// Fetches the metadata of an include of a function group, e.g. `lzordertop`
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct FunctionGroupInclude<'a> {
+    /// The name of the function group the include belongs to, for example `zorder`
+    #[builder(setter(into))]
+    group: Cow<'a, str>,
+
+    /// The name of the include, for example `lzordertop`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The version of the include to get the data of, see [`adtcore::Version`]
+    #[builder(default = None)]
+    version: Option<adtcore::Version>,
+
+    /// Etag of the include used for caching purposes.
+    #[builder(setter(into), default = None)]
+    etag: Option<Cow<'a, str>>,
+}
+
+impl Operation for FunctionGroupInclude<'_> {
+    type Response = CacheControlled<IncludeData>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!("functions/groups/{}/includes/{}", self.group, self.name).into()
+    }
+
+    fn parameters(&self) -> QueryParameters {
+        let mut params = QueryParameters::default();
+        params.push_opt("version", self.version.clone());
+        params
+    }
+
+    fn headers(&self) -> Option<http::HeaderMap> {
+        Some(cache_headers(
+            self.etag.as_deref(),
+            "application/vnd.sap.adt.functions.fincludes.v2+xml",
+        ))
+    }
+}
+
+/// Fetches the source code of an include of a function group.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct FunctionGroupIncludeSource<'a> {
+    /// The name of the function group the include belongs to, for example `zorder`
+    #[builder(setter(into))]
+    group: Cow<'a, str>,
+
+    /// The name of the include, for example `lzordertop`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The version of the source to get, e.g. `inactive`
+    #[builder(default)]
+    version: Option<adtcore::Version>,
+
+    #[builder(setter(into), default)]
+    etag: Option<Cow<'a, str>>,
+}
+
+impl<'a> Operation for FunctionGroupIncludeSource<'a> {
+    type Response = CacheControlled<Plain<'a>>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!(
+            "functions/groups/{}/includes/{}/source/main",
+            self.group, self.name
+        )
+        .into()
+    }
+
+    fn parameters(&self) -> QueryParameters {
+        let mut params = QueryParameters::default();
+        params.push_opt("version", self.version.clone());
+        params
+    }
+
+    fn headers(&self) -> Option<http::HeaderMap> {
+        Some(cache_headers(self.etag.as_deref(), "text/plain"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http::header;
+
+    #[test]
+    fn function_module_source_url() {
+        let op = FunctionModuleSourceBuilder::default()
+            .group("zorder")
+            .name("z_order_create")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            op.url(),
+            "functions/groups/zorder/fmodules/z_order_create/source/main"
+        );
+    }
+
+    #[test]
+    fn function_module_requires_group() {
+        let result = FunctionModuleBuilder::default()
+            .name("z_order_create")
+            .build();
+
+        assert!(matches!(result, Err(_)), "Group should not be optional");
+    }
+
+    #[test]
+    fn cached_include_sends_etag() {
+        let op = FunctionGroupIncludeSourceBuilder::default()
+            .group("zorder")
+            .name("lzordertop")
+            .etag("202509201012440011")
+            .build()
+            .unwrap();
+
+        let headers = op.headers().unwrap();
+        assert_eq!(headers[header::IF_NONE_MATCH], "202509201012440011");
+        assert!(headers.get(header::CACHE_CONTROL).is_none());
+    }
+}
@@ -0,0 +1,126 @@
+use std::borrow::Cow;
+
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderValue, header};
+
+use crate::operation::{Operation, Stateless};
+use crate::response::{CacheControlled, Plain};
+use crate::{
+    QueryParameters,
+    models::{adtcore, interface::AbapInterface},
+};
+
+/// Fetches the metadata of a global interface.
+///
+/// Responsible ABAP REST Handler: `CL_OO_ADT_RES_INTERFACE`
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct Interface<'a> {
+    /// The name of the interface, for example `zif_order_repository`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The version of the interface to get the data of, see [`adtcore::Version`]
+    /// If not specified in the query, the inactive version is the default if one exists.
+    #[builder(default = None)]
+    version: Option<adtcore::Version>,
+
+    /// Etag of the interface used for caching purposes.
+    #[builder(setter(into), default = None)]
+    etag: Option<Cow<'a, str>>,
+}
+
+impl Operation for Interface<'_> {
+    type Response = CacheControlled<AbapInterface>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!("oo/interfaces/{}", self.name).into()
+    }
+
+    fn parameters(&self) -> QueryParameters {
+        let mut params = QueryParameters::default();
+        params.push_opt("version", self.version.clone());
+        params
+    }
+
+    /// Headers need to handle whether we have a cached version locally and provide the ETag.
+    fn headers(&self) -> Option<http::HeaderMap> {
+        let mut map = HeaderMap::new();
+        match &self.etag {
+            None => map.insert(header::CACHE_CONTROL, HeaderValue::from_static("no-cache")),
+            Some(etag) => map.insert(header::IF_NONE_MATCH, HeaderValue::from_str(etag).unwrap()),
+        };
+        map.insert(
+            header::ACCEPT,
+            HeaderValue::from_static("application/vnd.sap.adt.oo.interfaces.v5+xml"),
+        );
+        Some(map)
+    }
+}
+
+/// Fetches the source code of a global interface.
+///
+/// Writing the source is done through [`crate::api::object::UpdateSourceCode`]
+/// with [`crate::api::object::SourceCodeObject::Interface`].
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct InterfaceSource<'a> {
+    /// The name of the interface, for example `zif_order_repository`
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The version of the interface to get the source of, e.g. `inactive`
+    #[builder(default)]
+    version: Option<adtcore::Version>,
+
+    #[builder(setter(into), default)]
+    etag: Option<Cow<'a, str>>,
+}
+
+impl<'a> Operation for InterfaceSource<'a> {
+    type Response = CacheControlled<Plain<'a>>;
+
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        format!("oo/interfaces/{}/source/main", self.name).into()
+    }
+
+    fn parameters(&self) -> QueryParameters {
+        let mut params = QueryParameters::default();
+        params.push_opt("version", self.version.clone());
+        params
+    }
+
+    /// Headers need to handle whether we have a cached version locally and provide the ETag.
+    fn headers(&self) -> Option<http::HeaderMap> {
+        let mut map = HeaderMap::new();
+        match &self.etag {
+            None => map.insert(header::CACHE_CONTROL, HeaderValue::from_static("no-cache")),
+            Some(etag) => map.insert(header::IF_NONE_MATCH, HeaderValue::from_str(etag).unwrap()),
+        };
+        map.insert(header::ACCEPT, HeaderValue::from_static("text/plain"));
+        Some(map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interface_source_url() {
+        let op = InterfaceSourceBuilder::default()
+            .name("zif_order_repository")
+            .build()
+            .unwrap();
+
+        assert_eq!(op.url(), "oo/interfaces/zif_order_repository/source/main");
+    }
+}
@@ -0,0 +1,133 @@
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderValue, header};
+use std::borrow::Cow;
+
+use crate::QueryParameters;
+use crate::models::adtcore::ObjectReference;
+use crate::operation::{Operation, Stateless};
+use crate::response::Success;
+
+/// What to navigate to from the element at the position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NavigationFilter {
+    /// The declaration of the element, e.g. the `METHODS` statement of a method.
+    #[default]
+    Definition,
+
+    /// The implementation of the element, e.g. the `METHOD` statement of a method.
+    Implementation,
+
+    /// The matching statement of a block, e.g the `ENDIF` of an `IF`
+    MatchingStatement,
+}
+
+impl NavigationFilter {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Definition => "definition",
+            Self::Implementation => "implementation",
+            Self::MatchingStatement => "matchingStatement",
+        }
+    }
+}
+
+/// Resolves the target of the element at a position of a source.
+///
+/// The URI of the returned reference contains the position of the target in its
+/// fragment, e.g. `/sap/bc/adt/programs/includes/zinc/source/main#start=12,5`,
+/// see [`SourceLocation`](crate::models::adtcore::SourceLocation).
+#[derive(Builder, Debug, Clone)]
+#[builder(setter(strip_option))]
+pub struct NavigationTarget<'a> {
+    /// The URI of the source, for example `/sap/bc/adt/programs/programs/zdemo1/source/main`
+    #[builder(setter(into))]
+    source_uri: Cow<'a, str>,
+
+    /// The current content of the source, if it differs from the saved one.
+    #[builder(setter(into), default)]
+    source: Option<Cow<'a, str>>,
+
+    /// The 1-based line of the position.
+    line: u32,
+
+    /// The 0-based column of the position.
+    column: u32,
+
+    #[builder(default)]
+    filter: NavigationFilter,
+}
+
+impl Operation for NavigationTarget<'_> {
+    type Response = Success<ObjectReference>;
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    fn url(&self) -> Cow<'static, str> {
+        "navigation/target".into()
+    }
+
+    fn parameters(&self) -> QueryParameters {
+        let mut params = QueryParameters::default();
+        params
+            .push(
+                "uri",
+                format!("{}#start={},{}", self.source_uri, self.line, self.column),
+            )
+            .push("filter", self.filter.as_str());
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::CONTENT_TYPE, HeaderValue::from_static("text/plain"));
+        headers.insert(header::ACCEPT, HeaderValue::from_static("application/xml"));
+        Some(headers)
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        self.source
+            .as_ref()
+            .map(|source| Ok(source.clone().into_owned()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn navigation_defaults_to_definition() {
+        let op = NavigationTargetBuilder::default()
+            .source_uri("/sap/bc/adt/programs/programs/zdemo1/source/main")
+            .line(3)
+            .column(10)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            op.parameters().query_pairs(),
+            vec![
+                (
+                    "uri".to_string(),
+                    "/sap/bc/adt/programs/programs/zdemo1/source/main#start=3,10".to_string()
+                ),
+                ("filter".to_string(), "definition".to_string()),
+            ]
+        );
+        assert!(op.body().is_none());
+    }
+
+    #[test]
+    fn navigation_target_is_deserialized() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+                       <adtcore:objectReference xmlns:adtcore="http://www.sap.com/adt/core" adtcore:uri="/sap/bc/adt/programs/includes/zdemo1_forms/source/main#start=12,5" adtcore:type="PROG/PU" adtcore:name="SUB" adtcore:parentUri="/sap/bc/adt/programs/includes/zdemo1_forms"/>"#;
+        let result: ObjectReference = serde_xml_rs::from_str(plain).unwrap();
+
+        assert_eq!(result.name, "SUB");
+        assert_eq!(
+            result.parent_uri.as_deref(),
+            Some("/sap/bc/adt/programs/includes/zdemo1_forms")
+        );
+    }
+}
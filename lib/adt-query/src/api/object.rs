@@ -10,6 +10,7 @@ use crate::{
     models::{
         adtcore,
         asx::{self, LockResult},
+        class::ClassInclude,
     },
     operation::{Operation, Stateful, Stateless},
     response::{CacheControlled, Plain, Success},
@@ -30,29 +31,83 @@ pub enum ObjectAction {
 pub enum SourceCodeObject<'a> {
     Program(Cow<'a, str>),
     Include(Cow<'a, str>),
+    /// The main include of a global class, see [`SourceCodeObject::ClassInclude`] for the others.
     GlobalClass(Cow<'a, str>),
-    TestClass(Cow<'a, str>),
+    /// Any include of a global class, the lock is always obtained on the class itself.
+    ClassInclude(Cow<'a, str>, ClassInclude),
+    Interface(Cow<'a, str>),
+    /// The main program of a function group (`SAPL...`)
+    FunctionGroup(Cow<'a, str>),
+    /// A function module by its function group and name.
+    FunctionModule(Cow<'a, str>, Cow<'a, str>),
+    /// An include of a function group by its function group and name.
+    FunctionGroupInclude(Cow<'a, str>, Cow<'a, str>),
     Structure(Cow<'a, str>),
 }
 
 impl SourceCodeObject<'_> {
+    /// Determines the object from its ADT uri, e.g `/sap/bc/adt/programs/programs/zdemo1`
+    /// or the uri of a class include like `/sap/bc/adt/oo/classes/zcl_x/includes/testclasses`.
+    ///
+    /// Returns `None` if the uri does not refer to an object with source code.
+    pub fn from_object_uri(uri: &str) -> Option<SourceCodeObject<'static>> {
+        let path = uri.trim_start_matches("/sap/bc/adt/").trim_end_matches('/');
+        let segments: Vec<&str> = path.split('/').collect();
+        let owned = |s: &str| Cow::Owned(s.to_owned());
+
+        let object = match segments.as_slice() {
+            ["programs", "programs", name] => SourceCodeObject::Program(owned(name)),
+            ["programs", "includes", name] => SourceCodeObject::Include(owned(name)),
+            ["oo", "classes", name] | ["oo", "classes", name, "source", "main"] => {
+                SourceCodeObject::GlobalClass(owned(name))
+            }
+            ["oo", "classes", name, "includes", include] => {
+                let include = ClassInclude::ALL
+                    .iter()
+                    .find(|i| i.as_str() == *include && **i != ClassInclude::Main)?;
+                SourceCodeObject::ClassInclude(owned(name), *include)
+            }
+            ["oo", "interfaces", name] => SourceCodeObject::Interface(owned(name)),
+            ["functions", "groups", name] => SourceCodeObject::FunctionGroup(owned(name)),
+            ["functions", "groups", group, "fmodules", name] => {
+                SourceCodeObject::FunctionModule(owned(group), owned(name))
+            }
+            ["functions", "groups", group, "includes", name] => {
+                SourceCodeObject::FunctionGroupInclude(owned(group), owned(name))
+            }
+            ["ddic", "structures", name] => SourceCodeObject::Structure(owned(name)),
+            _ => return None,
+        };
+        Some(object)
+    }
+
     pub fn object_uri(&self) -> String {
         match &self {
             Self::Program(name) => format!("/sap/bc/adt/programs/programs/{name}"),
-            Self::GlobalClass(name) => format!("/sap/bc/adt/programs/includes/{name}"),
+            Self::GlobalClass(name) => format!("/sap/bc/adt/oo/classes/{name}"),
+            Self::ClassInclude(name, _) => format!("/sap/bc/adt/oo/classes/{name}"),
             Self::Include(name) => format!("/sap/bc/adt/programs/includes/{name}"),
-            Self::TestClass(name) => format!("/sap/bc/adt/programs/includes/{name}"),
+            Self::Interface(name) => format!("/sap/bc/adt/oo/interfaces/{name}"),
+            Self::FunctionGroup(name) => format!("/sap/bc/adt/functions/groups/{name}"),
+            Self::FunctionModule(group, name) => {
+                format!("/sap/bc/adt/functions/groups/{group}/fmodules/{name}")
+            }
+            Self::FunctionGroupInclude(group, name) => {
+                format!("/sap/bc/adt/functions/groups/{group}/includes/{name}")
+            }
             Self::Structure(name) => format!("/sap/bc/adt/ddic/structures/{name}"),
         }
     }
 
     pub fn source_code_uri(&self) -> String {
         match &self {
-            Self::Program(name) => format!("/sap/bc/adt/programs/programs/{name}/source/main"),
-            Self::GlobalClass(name) => format!("/sap/bc/adt/programs/includes/{name}/source/main"),
-            Self::Include(name) => format!("/sap/bc/adt/programs/includes/{name}/source/main"),
-            Self::TestClass(name) => format!("/sap/bc/adt/programs/includes/{name}"),
-            Self::Structure(name) => format!("/sap/bc/adt/ddic/structures/{name}/source/main"),
+            Self::GlobalClass(name) => {
+                Self::ClassInclude(name.clone(), ClassInclude::Main).source_code_uri()
+            }
+            Self::ClassInclude(_, include) => {
+                format!("{}/{}", self.object_uri(), include.source_path())
+            }
+            _ => format!("{}/source/main", self.object_uri()),
         }
     }
 }
@@ -217,6 +272,11 @@ pub struct UpdateSourceCode<'a> {
 
     #[builder(setter(into))]
     content: Cow<'a, str>,
+
+    /// The number of the transport request to record the change in, required for
+    /// objects that are not local, see [`CheckTransport`](crate::api::cts::CheckTransport).
+    #[builder(setter(into), default)]
+    corr_nr: Option<Cow<'a, str>>,
 }
 
 impl Operation for UpdateSourceCode<'_> {
@@ -231,7 +291,9 @@ impl Operation for UpdateSourceCode<'_> {
 
     fn parameters(&self) -> QueryParameters {
         let mut params = QueryParameters::default();
-        params.push("lockHandle", &self.lock_handle);
+        params
+            .push("lockHandle", &self.lock_handle)
+            .push_opt("corrNr", self.corr_nr.as_ref());
         params
     }
 
@@ -248,3 +310,82 @@ impl Operation for UpdateSourceCode<'_> {
         Some(Ok(self.content.clone().into_owned()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn source_code_object_from_object_uri() {
+        let object = SourceCodeObject::from_object_uri(
+            "/sap/bc/adt/functions/groups/zorder/fmodules/z_read",
+        )
+        .unwrap();
+
+        assert!(matches!(object, SourceCodeObject::FunctionModule(..)));
+        assert_eq!(
+            object.source_code_uri(),
+            "/sap/bc/adt/functions/groups/zorder/fmodules/z_read/source/main"
+        );
+        assert!(SourceCodeObject::from_object_uri("/sap/bc/adt/packages/%24tmp").is_none());
+    }
+
+    #[test]
+    fn class_source_is_written_to_main_include() {
+        let object = SourceCodeObject::from_object_uri("/sap/bc/adt/oo/classes/zcl_x").unwrap();
+        assert_eq!(
+            object.source_code_uri(),
+            "/sap/bc/adt/oo/classes/zcl_x/source/main"
+        );
+    }
+
+    #[test]
+    fn class_include_is_written_to_its_include() {
+        let object =
+            SourceCodeObject::from_object_uri("/sap/bc/adt/oo/classes/zcl_x/includes/testclasses")
+                .unwrap();
+        assert!(matches!(
+            object,
+            SourceCodeObject::ClassInclude(_, ClassInclude::TestClasses)
+        ));
+        assert_eq!(object.object_uri(), "/sap/bc/adt/oo/classes/zcl_x");
+        assert_eq!(
+            object.source_code_uri(),
+            "/sap/bc/adt/oo/classes/zcl_x/includes/testclasses"
+        );
+        assert!(
+            SourceCodeObject::from_object_uri("/sap/bc/adt/oo/classes/zcl_x/includes/unknown")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn source_update_passes_transport() {
+        let object = SourceCodeObject::from_object_uri("/sap/bc/adt/oo/classes/zcl_x").unwrap();
+        let op = UpdateSourceCodeBuilder::default()
+            .object(object.clone())
+            .lock_handle("HANDLE")
+            .content("CLASS zcl_x DEFINITION.")
+            .corr_nr("A4HK900089")
+            .build()
+            .unwrap();
+        assert_eq!(
+            op.parameters().query_pairs(),
+            vec![
+                ("lockHandle".to_string(), "HANDLE".to_string()),
+                ("corrNr".to_string(), "A4HK900089".to_string()),
+            ]
+        );
+
+        let op = UpdateSourceCodeBuilder::default()
+            .object(object)
+            .lock_handle("HANDLE")
+            .content("CLASS zcl_x DEFINITION.")
+            .build()
+            .unwrap();
+        assert_eq!(
+            op.parameters().query_pairs(),
+            vec![("lockHandle".to_string(), "HANDLE".to_string()),]
+        );
+    }
+}
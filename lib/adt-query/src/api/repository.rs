@@ -6,6 +6,7 @@ use http::{HeaderValue, header};
 use crate::{
     QueryParameters,
     models::{
+        adtcore::ObjectReferences,
         facets::Facets,
         objectproperties,
         serialize::IntoXmlRoot,
@@ -232,3 +233,70 @@ impl Operation for ObjectTransports<'_> {
         params
     }
 }
+
+/// Searches the repository for objects by their name, as the "Open ABAP Development
+/// Object" dialog does.
+///
+/// Responsible ABAP REST Handler: `CL_RIS_ADT_RES_SEARCH`
+#[derive(Debug, Builder)]
+pub struct QuickSearch<'a> {
+    /// The pattern of the names to search for, e.g. `ZCL_*ORDER*`
+    #[builder(setter(into))]
+    query: Cow<'a, str>,
+
+    /// The maximum number of objects to return.
+    #[builder(default = 51)]
+    max_results: u64,
+}
+
+impl Operation for QuickSearch<'_> {
+    type Kind = Stateless;
+
+    type Response = Success<ObjectReferences>;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn url(&self) -> Cow<'static, str> {
+        "repository/informationsystem/search".into()
+    }
+
+    fn headers(&self) -> Option<http::HeaderMap> {
+        let mut headers = http::HeaderMap::new();
+        headers.insert(
+            http::header::ACCEPT,
+            HeaderValue::from_static("application/xml"),
+        );
+        Some(headers)
+    }
+
+    fn parameters(&self) -> QueryParameters {
+        let mut params = QueryParameters::default();
+        params
+            .push("operation", "quickSearch")
+            .push("query", &self.query)
+            .push("maxResults", self.max_results);
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quick_search_passes_query() {
+        let op = QuickSearchBuilder::default()
+            .query("ZCL_*ORDER*")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            op.parameters().query_pairs(),
+            vec![
+                ("operation".to_string(), "quickSearch".to_string()),
+                ("query".to_string(), "ZCL_*ORDER*".to_string()),
+                ("maxResults".to_string(), "51".to_string()),
+            ]
+        );
+    }
+}
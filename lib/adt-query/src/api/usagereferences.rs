@@ -0,0 +1,158 @@
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderValue, header};
+use std::borrow::Cow;
+
+use crate::QueryParameters;
+use crate::models::serialize::IntoXmlRoot;
+use crate::models::usagereferences::{
+    UsageReferenceRequest, UsageReferenceResult, UsageSnippetRequest, UsageSnippetResult,
+};
+use crate::operation::{Operation, Stateless};
+use crate::response::Success;
+
+/// Searches the system for usages of an element (where-used list).
+///
+/// The element is either an object itself, e.g. `/sap/bc/adt/oo/classes/zcl_order`,
+/// or the element at a position of its source if a line and column are given.
+///
+/// The result only contains the objects using the element, the exact locations are
+/// retrieved through a follow-up [`UsageSnippets`] request.
+#[derive(Builder, Debug, Clone)]
+#[builder(setter(strip_option))]
+pub struct UsageReferences<'a> {
+    /// The URI of the object or source, for example `/sap/bc/adt/programs/programs/zdemo1/source/main`
+    #[builder(setter(into))]
+    uri: Cow<'a, str>,
+
+    /// The 1-based line of the element within the source.
+    #[builder(default)]
+    line: Option<u32>,
+
+    /// The 0-based column of the element within the source.
+    #[builder(default)]
+    column: Option<u32>,
+
+    #[builder(setter(skip))]
+    request: UsageReferenceRequest,
+}
+
+impl Operation for UsageReferences<'_> {
+    type Response = Success<UsageReferenceResult>;
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    fn url(&self) -> Cow<'static, str> {
+        "repository/informationsystem/usageReferences".into()
+    }
+
+    fn parameters(&self) -> QueryParameters {
+        let mut params = QueryParameters::default();
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => {
+                params.push("uri", format!("{}#start={line},{column}", self.uri))
+            }
+            _ => params.push("uri", &self.uri),
+        };
+        params
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static(
+                "application/vnd.sap.adt.repository.usagereferences.request.v1+xml",
+            ),
+        );
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static(
+                "application/vnd.sap.adt.repository.usagereferences.result.v1+xml",
+            ),
+        );
+        Some(headers)
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(self.request.into_xml_root())
+    }
+}
+
+/// Retrieves the code snippets of objects found by a [`UsageReferences`] search, i.e.
+/// the locations in their sources where the element is used.
+#[derive(Builder, Debug, Clone)]
+pub struct UsageSnippets {
+    request: UsageSnippetRequest,
+}
+
+impl Operation for UsageSnippets {
+    type Response = Success<UsageSnippetResult>;
+    type Kind = Stateless;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    fn url(&self) -> Cow<'static, str> {
+        "repository/informationsystem/usageSnippets".into()
+    }
+
+    fn headers(&self) -> Option<HeaderMap> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static(
+                "application/vnd.sap.adt.repository.usagesnippet.request.v1+xml",
+            ),
+        );
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static(
+                "application/vnd.sap.adt.repository.usagesnippet.result.v1+xml",
+            ),
+        );
+        Some(headers)
+    }
+
+    fn body(&self) -> Option<Result<String, serde_xml_rs::Error>> {
+        Some(self.request.into_xml_root())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn usage_references_of_position() {
+        let op = UsageReferencesBuilder::default()
+            .uri("/sap/bc/adt/oo/classes/zcl_order/source/main")
+            .line(12)
+            .column(8)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            op.parameters().query_pairs(),
+            vec![(
+                "uri".to_string(),
+                "/sap/bc/adt/oo/classes/zcl_order/source/main#start=12,8".to_string()
+            ),]
+        );
+    }
+
+    #[test]
+    fn usage_references_of_object() {
+        let op = UsageReferencesBuilder::default()
+            .uri("/sap/bc/adt/oo/classes/zcl_order")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            op.parameters().query_pairs(),
+            vec![(
+                "uri".to_string(),
+                "/sap/bc/adt/oo/classes/zcl_order".to_string()
+            ),]
+        );
+    }
+}
@@ -181,6 +181,11 @@ where
         &self.params.url()
     }
 
+    /// The name of the user the client is logged on as, e.g. `DEVELOPER`
+    pub fn username(&self) -> &str {
+        self.credentials.username()
+    }
+
     pub async fn session_id(&self) -> Option<String> {
         self.session
             .lock()
@@ -285,6 +285,16 @@ impl<'a> QueryParameters<'a> {
     }
 }
 
+#[cfg(test)]
+impl QueryParameters<'_> {
+    /// The decoded query pairs the parameters add to a url.
+    pub(crate) fn query_pairs(&self) -> Vec<(String, String)> {
+        let mut url = Url::parse("http://localhost").unwrap();
+        self.add_to_url(&mut url);
+        url.query_pairs().into_owned().collect()
+    }
+}
+
 /// A trait representing a parameter value.
 pub trait ParamValue<'a> {
     fn as_str(&self) -> Cow<'a, str>;
@@ -1,10 +1,16 @@
 use http::header::InvalidHeaderValue;
 use thiserror::Error;
 
+use crate::models::exception::AdtException;
+
 #[derive(Debug, Error)]
 pub enum ResponseError {
     #[error("unexpected status [{}]: {}", .0.status(), .0.body())]
     BadStatusCode(http::Response<String>),
+
+    /// The server responded with an `exc:exception` document describing the error.
+    #[error("{1} [{0}]")]
+    Exception(http::StatusCode, AdtException),
     #[error(transparent)]
     DeserializeError(#[from] serde_xml_rs::Error),
 }
@@ -52,3 +58,37 @@ pub enum OperationError {
     #[error("value for field '{0}' was not provided")]
     UninitializedField(&'static str),
 }
+
+impl ResponseError {
+    /// Creates the error for a response with an unexpected status code.
+    ///
+    /// If the body contains an `exc:exception` document, it is parsed into an
+    /// [`AdtException`], otherwise the raw response is retained.
+    pub fn from_bad_status(response: http::Response<String>) -> Self {
+        if !response.body().contains("exc:exception") {
+            return Self::BadStatusCode(response);
+        }
+        match serde_xml_rs::from_str(response.body()) {
+            Ok(exception) => Self::Exception(response.status(), exception),
+            Err(_) => Self::BadStatusCode(response),
+        }
+    }
+
+    /// The [`AdtException`] the server responded with, if any.
+    pub fn adt_exception(&self) -> Option<&AdtException> {
+        match self {
+            Self::Exception(_, exception) => Some(exception),
+            _ => None,
+        }
+    }
+}
+
+impl OperationError {
+    /// The [`AdtException`] the server responded with, if any.
+    pub fn adt_exception(&self) -> Option<&AdtException> {
+        match self {
+            Self::BadResponse(e) => e.adt_exception(),
+            _ => None,
+        }
+    }
+}
@@ -1,14 +1,23 @@
 pub mod abapsource;
+pub mod activation;
 pub mod adtcomp;
 pub mod adtcore;
 pub mod asx;
 pub mod atom;
+pub mod atc;
+pub mod aunit;
 pub mod checkrun;
+pub mod class;
+pub mod cts;
 pub mod discovery;
+pub mod exception;
 pub mod facets;
+pub mod function;
+pub mod interface;
 pub mod objectproperties;
 pub mod program;
 pub mod tpr;
+pub mod usagereferences;
 pub mod vfs;
 
 pub(crate) mod serialize;
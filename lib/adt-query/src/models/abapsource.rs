@@ -1,5 +1,6 @@
-use crate::models::atom;
-use serde::Deserialize;
+use crate::models::{atom, serialize::IntoXmlRoot};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::borrow::Cow;
 
 #[derive(Debug, Deserialize)]
 #[serde(rename = "abapsource:syntaxConfiguration")]
@@ -39,6 +40,172 @@ pub struct ObjectStructureElement {
     pub elements: Vec<Self>,
 }
 
+/// Information about the element at a position of the source, e.g. a type or a class.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "adtcore:elementInfo")]
+#[readonly::make]
+pub struct ElementInfo {
+    /// Name of the element, for example `CL_SALV_TABLE`
+    #[serde(rename = "@adtcore:name")]
+    pub name: String,
+
+    /// Type of the element e.g CLAS/OC for classes
+    #[serde(rename = "@adtcore:type")]
+    pub kind: String,
+
+    /// The URI of the object the element belongs to, if it is a repository object.
+    #[serde(rename = "@adtcore:uri", default)]
+    pub uri: Option<String>,
+
+    /// The short description of the element.
+    #[serde(rename = "adtcore:documentation", default)]
+    pub documentation: Option<String>,
+
+    #[serde(rename = "atom:link", default)]
+    pub links: Vec<atom::Link>,
+
+    /// Components of the element, e.g. the methods of a class or fields of a structure.
+    #[serde(rename = "adtcore:elementInfo", default)]
+    pub components: Vec<Self>,
+}
+
+impl ElementInfo {
+    /// The link to the (long) documentation of the element, if it has one.
+    pub fn documentation_link(&self) -> Option<&atom::Link> {
+        self.links.iter().find(|l| {
+            l.rel
+                .as_deref()
+                .is_some_and(|rel| rel.ends_with("/relations/documentation"))
+        })
+    }
+}
+
+/// A proposal of the code completion, reflects DDIC structure `SCC_COMPLETION`.
+///
+/// Proposals cover everything the compiler knows at the position, e.g. keywords,
+/// variables, DDIC types, class members and function modules.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "SCC_COMPLETION")]
+#[readonly::make]
+pub struct CompletionProposal {
+    /// The numeric kind of the proposal as categorized by the compiler.
+    #[serde(rename = "KIND", default)]
+    pub kind: u32,
+
+    /// The text to complete, e.g. `CL_ABAP_TYPEDESCR` or `describe_by_name(`
+    #[serde(rename = "IDENTIFIER")]
+    pub identifier: String,
+
+    /// Whether further information can be retrieved for the proposal.
+    #[serde(
+        rename = "QUICKINFO_EVENT",
+        deserialize_with = "deserialize_flag",
+        default
+    )]
+    pub has_quick_info: bool,
+
+    /// Whether a full pattern can be inserted for the proposal, e.g. the parameters of
+    /// a method call, see [`CodeCompletionInsertion`](crate::api::abapsource::CodeCompletionInsertion).
+    #[serde(
+        rename = "INSERT_EVENT",
+        deserialize_with = "deserialize_flag",
+        default
+    )]
+    pub has_insertion: bool,
+
+    /// Whether the proposal is a placeholder rather than a concrete element, e.g. `@end`
+    #[serde(rename = "IS_META", deserialize_with = "deserialize_flag", default)]
+    pub is_meta: bool,
+
+    /// The length of the prefix at the position that the proposal replaces.
+    #[serde(rename = "PREFIXLENGTH", default)]
+    pub prefix_length: u32,
+
+    /// The relevance of the proposal, higher is more relevant.
+    #[serde(rename = "GRADE", default)]
+    pub grade: u32,
+
+    /// Whether the element is inherited, e.g. a method of a super class.
+    #[serde(
+        rename = "IS_INHERITED",
+        deserialize_with = "deserialize_flag",
+        default
+    )]
+    pub is_inherited: bool,
+}
+
+/// Wraps a collection of [`CompletionProposal`]
+///
+/// Typically wrapped in [`AsxData`](crate::models::asx::AsxData).
+#[derive(Debug, Deserialize, Default)]
+#[readonly::make]
+pub struct CompletionProposals {
+    #[serde(rename = "SCC_COMPLETION", default)]
+    pub proposals: Vec<CompletionProposal>,
+}
+
+/// How the pretty printer converts the case of the source.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum PrettyPrinterStyle {
+    /// Everything in lower case.
+    ToLower,
+
+    /// Everything in upper case.
+    ToUpper,
+
+    /// Keywords in upper case, identifiers in lower case.
+    #[default]
+    KeywordUpper,
+
+    /// Keywords in lower case, identifiers in upper case.
+    KeywordLower,
+
+    /// Keywords in the case they are mostly written in within the source.
+    KeywordAuto,
+
+    /// The case is kept as written.
+    None,
+}
+
+/// The settings of the pretty printer of the user, also used by the SAP GUI editor.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename = "abapformatter:PrettyPrinterSettings")]
+#[readonly::make]
+pub struct PrettyPrinterSettings {
+    /// Whether statements are indented by their nesting in blocks.
+    #[serde(rename = "@abapformatter:indentation")]
+    pub indentation: bool,
+
+    /// How the case of keywords and identifiers is converted.
+    #[serde(rename = "@abapformatter:style")]
+    pub style: PrettyPrinterStyle,
+}
+
+impl PrettyPrinterSettings {
+    pub fn new(indentation: bool, style: PrettyPrinterStyle) -> Self {
+        Self { indentation, style }
+    }
+}
+
+impl IntoXmlRoot for PrettyPrinterSettings {
+    fn namespaces(&self) -> Vec<(Cow<'static, str>, Cow<'static, str>)> {
+        vec![(
+            "abapformatter".into(),
+            "http://www.sap.com/adt/prettyprintersettings".into(),
+        )]
+    }
+}
+
+/// Deserialize `1` and `X` to `true` and all other values to `false`.
+fn deserialize_flag<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    Ok(matches!(s.as_str(), "1" | "X"))
+}
+
 #[cfg(test)]
 
 mod tests {
@@ -73,4 +240,86 @@ mod tests {
         let result: ObjectStructureElement = serde_xml_rs::from_str(plain).unwrap();
         // println("{:?}", result);
     }
+
+    #[test]
+    fn deserialize_element_info() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+                        <adtcore:elementInfo xmlns:adtcore="http://www.sap.com/adt/core" adtcore:name="CL_SALV_TABLE" adtcore:type="CLAS/OC" adtcore:uri="/sap/bc/adt/oo/classes/cl_salv_table">
+                            <adtcore:documentation>Basis Class for Simple Tables</adtcore:documentation>
+                            <adtcore:elementInfo adtcore:name="FACTORY" adtcore:type="CLAS/OM">
+                                <adtcore:documentation>Create Instance</adtcore:documentation>
+                            </adtcore:elementInfo>
+                            <adtcore:elementInfo adtcore:name="DISPLAY" adtcore:type="CLAS/OM"/>
+                            <atom:link xmlns:atom="http://www.w3.org/2005/Atom" href="/sap/bc/adt/docu/abap/langu?object=cl_salv_table" rel="http://www.sap.com/adt/relations/documentation" type="text/html"/>
+                        </adtcore:elementInfo>"#;
+        let result: ElementInfo = serde_xml_rs::from_str(plain).unwrap();
+
+        assert_eq!(result.name, "CL_SALV_TABLE");
+        assert_eq!(result.kind, "CLAS/OC");
+        assert_eq!(
+            result.documentation.as_deref(),
+            Some("Basis Class for Simple Tables")
+        );
+        assert_eq!(result.components.len(), 2);
+        assert_eq!(result.components[1].documentation, None);
+        assert_eq!(
+            result.documentation_link().map(|l| l.href.as_str()),
+            Some("/sap/bc/adt/docu/abap/langu?object=cl_salv_table")
+        );
+    }
+
+    #[test]
+    fn deserialize_completion_proposals() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+            <asx:abap xmlns:asx="http://www.sap.com/abapxml" version="1.0">
+                <asx:values>
+                    <DATA>
+                        <SCC_COMPLETION>
+                            <KIND>2</KIND>
+                            <IDENTIFIER>describe_by_name(</IDENTIFIER>
+                            <ICON>0</ICON>
+                            <QUICKINFO_EVENT>1</QUICKINFO_EVENT>
+                            <INSERT_EVENT>1</INSERT_EVENT>
+                            <IS_META>0</IS_META>
+                            <PREFIXLENGTH>3</PREFIXLENGTH>
+                            <GRADE>0</GRADE>
+                            <IS_INHERITED>0</IS_INHERITED>
+                            <SYNTCNTXT/>
+                        </SCC_COMPLETION>
+                        <SCC_COMPLETION>
+                            <KIND>0</KIND>
+                            <IDENTIFIER>@end</IDENTIFIER>
+                            <IS_META>1</IS_META>
+                        </SCC_COMPLETION>
+                    </DATA>
+                </asx:values>
+            </asx:abap>"#;
+
+        let result: crate::models::asx::AsxData<CompletionProposals> =
+            serde_xml_rs::from_str(plain).unwrap();
+        assert_eq!(result.proposals.len(), 2);
+        assert!(result.proposals[0].has_insertion);
+        assert_eq!(result.proposals[0].prefix_length, 3);
+        assert!(result.proposals[1].is_meta);
+    }
+
+    #[test]
+    fn deserialize_pretty_printer_settings() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+            <abapformatter:PrettyPrinterSettings xmlns:abapformatter="http://www.sap.com/adt/prettyprintersettings" abapformatter:indentation="true" abapformatter:style="keywordLower"/>"#;
+
+        let result: PrettyPrinterSettings = serde_xml_rs::from_str(plain).unwrap();
+        assert!(result.indentation);
+        assert_eq!(result.style, PrettyPrinterStyle::KeywordLower);
+    }
+
+    #[test]
+    fn serialize_pretty_printer_settings() {
+        let settings = PrettyPrinterSettings::new(false, PrettyPrinterStyle::ToUpper);
+        let xml = settings.into_xml_root().unwrap();
+
+        assert!(xml.contains("abapformatter:PrettyPrinterSettings"));
+        assert!(xml.contains(r#"abapformatter:indentation="false""#));
+        assert!(xml.contains(r#"abapformatter:style="toUpper""#));
+    }
 }
@@ -0,0 +1,236 @@
+/// Activation - http://www.sap.com/adt/activation
+///
+/// - Messages (CHKL) - http://www.sap.com/abapxml/checklist
+/// - Inactive Objects (IOC) - http://www.sap.com/abapxml/inactiveCtsObjects
+use serde::Deserialize;
+
+use crate::{
+    error::ResponseError,
+    models::adtcore::{ObjectReference, SourceLocation},
+    response::DeserializeResponse,
+};
+
+/// The outcome of an activation request.
+///
+/// The server responds with an empty body if all objects were activated without
+/// messages, the list of messages if there were any, or, if a preaudit was requested,
+/// the other inactive objects that should be activated together with the objects.
+#[derive(Debug)]
+pub enum ActivationResult {
+    /// The objects were activated without any messages.
+    Activated,
+    /// The activation produced messages, if any of them are errors it failed.
+    Messages(Messages),
+    /// The preaudit found further inactive objects that need to be activated together.
+    PendingObjects(InactiveObjects),
+}
+
+impl ActivationResult {
+    /// Whether the objects were activated, i.e. there are no errors or pending objects.
+    pub fn is_activated(&self) -> bool {
+        match self {
+            Self::Activated => true,
+            Self::Messages(m) => !m.messages.iter().any(|m| m.is_error()),
+            Self::PendingObjects(_) => false,
+        }
+    }
+
+    /// The messages of the activation, empty if there are none.
+    pub fn messages(&self) -> &[Message] {
+        match self {
+            Self::Messages(m) => &m.messages,
+            _ => &[],
+        }
+    }
+}
+
+impl DeserializeResponse for ActivationResult {
+    fn deserialize_response(body: String) -> Result<Self, ResponseError> {
+        if body.trim().is_empty() {
+            return Ok(Self::Activated);
+        }
+        if body.contains("inactiveObjects") {
+            return Ok(Self::PendingObjects(serde_xml_rs::from_str(&body)?));
+        }
+        Ok(Self::Messages(serde_xml_rs::from_str(&body)?))
+    }
+}
+
+/// Wraps a collection of [`Message`]
+///
+/// Typically the root element of the related XML Response.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "chkl:messages")]
+#[readonly::make]
+pub struct Messages {
+    #[serde(rename = "msg", default)]
+    pub messages: Vec<Message>,
+}
+
+/// A message produced during the activation of an object.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "msg")]
+#[readonly::make]
+pub struct Message {
+    /// The description of the object the message refers to, e.g `Program ZTEST`
+    #[serde(rename = "@objDescr")]
+    pub object_description: String,
+
+    /// The kind of message, e.g `W` for **Warning**, or `E` for **Error**.
+    #[serde(rename = "@type")]
+    pub kind: String,
+
+    /// The line the message refers to, if any.
+    #[serde(rename = "@line")]
+    pub line: Option<u32>,
+
+    /// The location the message refers to, e.g `.../source/main#start=5,2`
+    #[serde(rename = "@href")]
+    pub href: Option<String>,
+
+    /// Whether the activation can be forced despite this message.
+    #[serde(rename = "@forceSupported", default)]
+    pub force_supported: bool,
+
+    #[serde(rename = "shortText")]
+    pub short_text: ShortText,
+}
+
+impl Message {
+    pub fn is_error(&self) -> bool {
+        matches!(self.kind.as_str(), "E" | "A" | "X")
+    }
+
+    /// The text of the message, multiple text lines are joined by spaces.
+    pub fn text(&self) -> String {
+        self.short_text.lines.join(" ")
+    }
+
+    /// The location the message refers to, parsed from its [`href`](Self::href).
+    pub fn location(&self) -> Option<SourceLocation> {
+        self.href.as_deref().map(SourceLocation::parse)
+    }
+}
+
+/// The text of a [`Message`], possibly spanning multiple lines.
+#[derive(Debug, Deserialize)]
+#[readonly::make]
+pub struct ShortText {
+    #[serde(rename = "txt", default)]
+    pub lines: Vec<String>,
+}
+
+/// Wraps a collection of [`InactiveObjectEntry`]
+///
+/// Typically the root element of the related XML Response.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "ioc:inactiveObjects")]
+#[readonly::make]
+pub struct InactiveObjects {
+    #[serde(rename = "ioc:entry", default)]
+    pub entries: Vec<InactiveObjectEntry>,
+}
+
+impl InactiveObjects {
+    /// References to all inactive objects, excluding the transports they are part of.
+    pub fn objects(&self) -> impl Iterator<Item = &ObjectReference> {
+        self.entries
+            .iter()
+            .filter_map(|e| e.object.as_ref())
+            .map(|o| &o.reference)
+    }
+}
+
+/// An entry of the inactive objects list, either an object or the transport it is recorded in.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "ioc:entry")]
+#[readonly::make]
+pub struct InactiveObjectEntry {
+    #[serde(rename = "ioc:object")]
+    pub object: Option<InactiveObject>,
+
+    #[serde(rename = "ioc:transport")]
+    pub transport: Option<InactiveObject>,
+}
+
+/// An inactive object or transport of the current user.
+#[derive(Debug, Deserialize)]
+#[readonly::make]
+pub struct InactiveObject {
+    /// The user the inactive version belongs to
+    #[serde(rename = "@ioc:user")]
+    pub user: Option<String>,
+
+    /// Whether the object is deleted once activated
+    #[serde(rename = "@ioc:deleted", default)]
+    pub deleted: bool,
+
+    #[serde(rename = "ioc:ref")]
+    pub reference: ObjectReference,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_activation_messages() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+            <chkl:messages xmlns:chkl="http://www.sap.com/abapxml/checklist">
+                <msg objDescr="Program ZTEST" type="E" line="1" href="/sap/bc/adt/programs/programs/ztest/source/main#start=5,2" forceSupported="true">
+                    <shortText>
+                        <txt>The statement "WRIT" is not defined.</txt>
+                    </shortText>
+                </msg>
+                <msg objDescr="Program ZTEST" type="W" line="1" href="/sap/bc/adt/programs/programs/ztest/source/main#start=9,0">
+                    <shortText>
+                        <txt>Variable LV_X is not used.</txt>
+                    </shortText>
+                </msg>
+            </chkl:messages>"#;
+
+        let result = ActivationResult::deserialize_response(plain.into()).unwrap();
+        assert!(!result.is_activated());
+        assert_eq!(result.messages().len(), 2);
+        assert_eq!(
+            result.messages()[0].location().and_then(|l| l.start),
+            Some((5, 2))
+        );
+        assert_eq!(
+            result.messages()[0].text(),
+            "The statement \"WRIT\" is not defined."
+        );
+    }
+
+    #[test]
+    fn empty_activation_response_is_success() {
+        let result = ActivationResult::deserialize_response(String::new()).unwrap();
+        assert!(result.is_activated());
+    }
+
+    #[test]
+    fn deserialize_inactive_objects() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+            <ioc:inactiveObjects xmlns:ioc="http://www.sap.com/abapxml/inactiveCtsObjects">
+                <ioc:entry>
+                    <ioc:object ioc:user="DEVELOPER" ioc:deleted="false">
+                        <ioc:ref xmlns:adtcore="http://www.sap.com/adt/core" adtcore:uri="/sap/bc/adt/programs/includes/ztest_top" adtcore:type="PROG/I" adtcore:name="ZTEST_TOP" adtcore:parentUri="/sap/bc/adt/packages/%24tmp"/>
+                    </ioc:object>
+                </ioc:entry>
+                <ioc:entry>
+                    <ioc:transport ioc:user="DEVELOPER">
+                        <ioc:ref xmlns:adtcore="http://www.sap.com/adt/core" adtcore:uri="/sap/bc/adt/cts/transportrequests/A4HK900001" adtcore:type="/RQ" adtcore:name="A4HK900001"/>
+                    </ioc:transport>
+                </ioc:entry>
+            </ioc:inactiveObjects>"#;
+
+        let result = ActivationResult::deserialize_response(plain.into()).unwrap();
+        match result {
+            ActivationResult::PendingObjects(objects) => {
+                let names: Vec<_> = objects.objects().map(|o| o.name.as_str()).collect();
+                assert_eq!(names, vec!["ZTEST_TOP"]);
+            }
+            _ => panic!("Expected pending inactive objects"),
+        }
+    }
+}
@@ -1,8 +1,9 @@
 use std::borrow::Cow;
 
+use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 
-use crate::ParamValue;
+use crate::{ParamValue, models::serialize::IntoXmlRoot};
 
 #[derive(Debug, Deserialize)]
 #[serde(rename = "adtcore:packageRef")]
@@ -59,3 +60,190 @@ impl<'a> ParamValue<'a> for Version {
         Cow::Borrowed(self.as_str())
     }
 }
+
+/// A reference to a repository object by its uri, e.g. for activation or as a result
+/// of a search.
+///
+/// ## Example:
+/// ```
+/// use adt_query::models::adtcore::ObjectReferenceBuilder;
+///
+/// ObjectReferenceBuilder::default()
+///     .uri("/sap/bc/adt/programs/programs/z_my_program")
+///     .name("Z_MY_PROGRAM")
+///     .build();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Builder, Serialize, Deserialize)]
+#[serde(rename = "adtcore:objectReference")]
+#[builder(setter(into, strip_option))]
+pub struct ObjectReference {
+    /// The ADT uri of the object, e.g `/sap/bc/adt/oo/classes/zcl_order_service`
+    #[serde(rename = "@adtcore:uri")]
+    pub uri: String,
+
+    /// The object type, e.g `CLAS/OC`
+    #[serde(rename = "@adtcore:type", skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub object_type: Option<String>,
+
+    /// The name of the object, e.g `ZCL_ORDER_SERVICE`
+    #[serde(rename = "@adtcore:name")]
+    pub name: String,
+
+    /// The uri of the parent of the object, e.g. its package
+    #[serde(rename = "@adtcore:parentUri", skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub parent_uri: Option<String>,
+
+    /// The package the object belongs to, e.g `ZORDERS`
+    #[serde(
+        rename = "@adtcore:packageName",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[builder(default)]
+    pub package_name: Option<String>,
+
+    /// The short description of the object.
+    #[serde(
+        rename = "@adtcore:description",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[builder(default)]
+    pub description: Option<String>,
+}
+
+/// Wraps a collection of [`ObjectReference`]
+///
+/// Typically the root element of a XML Body or the result of a search.
+#[derive(Debug, Clone, Default, Builder, Serialize, Deserialize)]
+#[serde(rename = "adtcore:objectReferences")]
+pub struct ObjectReferences {
+    #[serde(rename = "adtcore:objectReference", default)]
+    #[builder(setter(each(name = "object")))]
+    pub objects: Vec<ObjectReference>,
+}
+
+impl From<Vec<ObjectReference>> for ObjectReferences {
+    fn from(objects: Vec<ObjectReference>) -> Self {
+        Self { objects }
+    }
+}
+
+impl IntoXmlRoot for ObjectReferences {
+    fn namespaces(&self) -> Vec<(Cow<'static, str>, Cow<'static, str>)> {
+        vec![("adtcore".into(), "http://www.sap.com/adt/core".into())]
+    }
+}
+
+/// A location in the source code of an object as referenced by messages of the server.
+///
+/// The position is part of the uri fragment, e.g `.../source/main#start=193,19`, where
+/// the line is 1-based and the column is 0-based.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceLocation {
+    /// The uri of the source without fragment, e.g `/sap/bc/adt/oo/classes/zcl_x/source/main`
+    pub uri: String,
+
+    /// The `(line, column)` of the start of the location, if given.
+    pub start: Option<(u32, u32)>,
+
+    /// The `(line, column)` of the end of the location, if given.
+    pub end: Option<(u32, u32)>,
+}
+
+impl SourceLocation {
+    /// Parses a uri with an optional `#start=l,c;end=l,c` fragment.
+    pub fn parse(uri: &str) -> Self {
+        let (path, fragment) = uri.split_once('#').unwrap_or((uri, ""));
+
+        let mut location = Self {
+            uri: path.to_owned(),
+            start: None,
+            end: None,
+        };
+        for part in fragment.split(['&', ';']) {
+            let Some((key, value)) = part.split_once('=') else {
+                continue;
+            };
+            let position = value
+                .split_once(',')
+                .and_then(|(l, c)| Some((l.parse().ok()?, c.parse().ok()?)))
+                .or_else(|| Some((value.parse().ok()?, 0)));
+            match key {
+                "start" => location.start = position,
+                "end" => location.end = position,
+                _ => {}
+            }
+        }
+        location
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_source_location_with_start() {
+        let location =
+            SourceLocation::parse("/sap/bc/adt/oo/classes/z_syntax_test/source/main#start=193,19");
+        assert_eq!(
+            location.uri,
+            "/sap/bc/adt/oo/classes/z_syntax_test/source/main"
+        );
+        assert_eq!(location.start, Some((193, 19)));
+        assert_eq!(location.end, None);
+    }
+
+    #[test]
+    fn parse_source_location_with_range() {
+        let location = SourceLocation::parse(
+            "/sap/bc/adt/programs/programs/ztest/source/main#start=5,2;end=5,10",
+        );
+        assert_eq!(location.start, Some((5, 2)));
+        assert_eq!(location.end, Some((5, 10)));
+        assert_eq!(SourceLocation::parse("/sap/bc/adt/x").start, None);
+    }
+
+    #[test]
+    fn serialize_object_references() {
+        let references: ObjectReferences = vec![
+            ObjectReferenceBuilder::default()
+                .uri("/sap/bc/adt/programs/programs/ztest")
+                .name("ZTEST")
+                .build()
+                .unwrap(),
+        ]
+        .into();
+
+        let expected = r#"<?xml version="1.0" encoding="UTF-8"?><adtcore:objectReferences xmlns:adtcore="http://www.sap.com/adt/core"><adtcore:objectReference adtcore:uri="/sap/bc/adt/programs/programs/ztest" adtcore:name="ZTEST" /></adtcore:objectReferences>"#;
+        assert_eq!(references.into_xml_root().unwrap(), expected);
+    }
+
+    #[test]
+    fn deserialize_quick_search_result() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+            <adtcore:objectReferences xmlns:adtcore="http://www.sap.com/adt/core">
+                <adtcore:objectReference adtcore:uri="/sap/bc/adt/oo/classes/zcl_order_service" adtcore:type="CLAS/OC" adtcore:name="ZCL_ORDER_SERVICE" adtcore:packageName="ZORDERS" adtcore:description="Order Service"/>
+                <adtcore:objectReference adtcore:uri="/sap/bc/adt/oo/classes/zcl_order_repository" adtcore:type="CLAS/OC" adtcore:name="ZCL_ORDER_REPOSITORY" adtcore:packageName="ZORDERS"/>
+            </adtcore:objectReferences>"#;
+
+        let result: ObjectReferences = serde_xml_rs::from_str(plain).unwrap();
+        assert_eq!(result.objects.len(), 2);
+        assert_eq!(result.objects[0].package_name.as_deref(), Some("ZORDERS"));
+        assert_eq!(
+            result.objects[0].description.as_deref(),
+            Some("Order Service")
+        );
+        assert_eq!(result.objects[1].description, None);
+    }
+
+    #[test]
+    fn deserialize_empty_quick_search_result() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+            <adtcore:objectReferences xmlns:adtcore="http://www.sap.com/adt/core"/>"#;
+
+        let result: ObjectReferences = serde_xml_rs::from_str(plain).unwrap();
+        assert!(result.objects.is_empty());
+    }
+}
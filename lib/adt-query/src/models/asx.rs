@@ -3,26 +3,47 @@
 /// Essentially "uncategorized" models which do not fall under any special category.
 /// These are usually wrapped in 'asx:abap' root elements but will be translated to
 /// more intuitive structures to avoid name clashes and improve readability.
-use serde::{Deserialize, Deserializer};
-use std::ops::Deref;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::{borrow::Cow, ops::Deref};
+
+use crate::models::serialize::IntoXmlRoot;
 
 /// Helper to wrap the inner type to be extracted from the unstructured
 /// asx data and provide a deref to it.
-#[derive(Debug, Deserialize)]
+///
+/// Also wraps data to be sent in requests that expect asx data, see [`AsxData::new`].
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(rename = "asx:abap")]
 pub struct AsxData<T> {
+    #[serde(rename = "@version", default)]
+    version: String,
+
     #[serde(rename = "asx:values")]
     inner: AsxValuesInner<T>,
 }
 
 impl<T> AsxData<T> {
+    /// Wraps the data into the `asx:abap` envelope of the current version.
+    pub fn new(data: T) -> Self {
+        Self {
+            version: String::from("1.0"),
+            inner: AsxValuesInner { data },
+        }
+    }
+
     pub fn inner(self) -> T {
         self.inner.data
     }
 }
 
+impl<T: Serialize> IntoXmlRoot for AsxData<T> {
+    fn namespaces(&self) -> Vec<(Cow<'static, str>, Cow<'static, str>)> {
+        vec![("asx".into(), "http://www.sap.com/abapxml".into())]
+    }
+}
+
 /// Internal helper to wrap the inner asx data
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 struct AsxValuesInner<T> {
     #[serde(rename = "DATA")]
     data: T,
@@ -0,0 +1,567 @@
+/// ABAP Test Cockpit (ATC) - http://www.sap.com/adt/atc
+///
+/// Models of ATC runs, i.e. the customizing of the system, the worklists holding the
+/// findings of the runs and the exemptions requested for findings.
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::{
+    adtcore::{ObjectReference, ObjectReferences, SourceLocation},
+    serialize::IntoXmlRoot,
+};
+
+/// The ATC customizing of the system, e.g. the check variant used by default.
+///
+/// Typically the root element of the related XML Response.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "atc:customizing")]
+#[readonly::make]
+pub struct Customizing {
+    #[serde(rename = "properties")]
+    properties: Option<Properties>,
+
+    #[serde(rename = "exemption")]
+    exemption: Option<ExemptionCustomizing>,
+}
+
+impl Customizing {
+    /// The value of a property of the customizing, e.g. `systemCheckVariant`
+    pub fn property(&self, name: &str) -> Option<&str> {
+        self.properties
+            .iter()
+            .flat_map(|p| &p.properties)
+            .find(|p| p.name == name)
+            .map(|p| p.value.as_str())
+    }
+
+    /// The check variant that runs use unless another one is chosen, e.g. `DEFAULT`
+    pub fn system_check_variant(&self) -> Option<&str> {
+        self.property("systemCheckVariant")
+    }
+
+    /// The reasons an exemption can be requested for, see [`ExemptionRequest`].
+    pub fn exemption_reasons(&self) -> &[ExemptionReason] {
+        self.exemption
+            .as_ref()
+            .and_then(|e| e.reasons.as_ref())
+            .map_or(&[], |r| &r.reasons)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Properties {
+    #[serde(rename = "property", default)]
+    properties: Vec<Property>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Property {
+    #[serde(rename = "@name")]
+    name: String,
+
+    #[serde(rename = "@value", default)]
+    value: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ExemptionCustomizing {
+    #[serde(rename = "reasons")]
+    reasons: Option<ExemptionReasons>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ExemptionReasons {
+    #[serde(rename = "reason", default)]
+    reasons: Vec<ExemptionReason>,
+}
+
+#[derive(Debug, Deserialize)]
+#[readonly::make]
+pub struct ExemptionReason {
+    /// The id of the reason, e.g. `FPOS`
+    #[serde(rename = "@id")]
+    pub id: String,
+
+    /// e.g. `False Positive`
+    #[serde(rename = "@title", default)]
+    pub title: String,
+
+    /// Whether exemptions for this reason have to be justified.
+    #[serde(rename = "@justificationMandatory", default)]
+    pub justification_mandatory: bool,
+}
+
+/// A run of the ATC for a set of objects, its findings are collected in a worklist.
+///
+/// Typically the root element of the related XML Request.
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename = "atc:run")]
+pub struct Run {
+    #[serde(rename = "@maximumVerdicts")]
+    maximum_verdicts: u32,
+
+    #[serde(rename = "objectSets")]
+    object_sets: ObjectSets,
+}
+
+impl Run {
+    /// Runs the checks for the objects, reporting at most `maximum_verdicts` findings.
+    pub fn new(objects: Vec<ObjectReference>, maximum_verdicts: u32) -> Self {
+        Self {
+            maximum_verdicts,
+            object_sets: ObjectSets {
+                object_set: ObjectSet {
+                    kind: "inclusive",
+                    objects: ObjectReferences::from(objects),
+                },
+            },
+        }
+    }
+}
+
+impl IntoXmlRoot for Run {
+    fn namespaces(&self) -> Vec<(Cow<'static, str>, Cow<'static, str>)> {
+        vec![
+            ("atc".into(), "http://www.sap.com/adt/atc".into()),
+            ("adtcore".into(), "http://www.sap.com/adt/core".into()),
+        ]
+    }
+}
+
+#[derive(Debug, Serialize, Clone)]
+struct ObjectSets {
+    #[serde(rename = "objectSet")]
+    object_set: ObjectSet,
+}
+
+#[derive(Debug, Serialize, Clone)]
+struct ObjectSet {
+    #[serde(rename = "@kind")]
+    kind: &'static str,
+
+    #[serde(rename = "adtcore:objectReferences")]
+    objects: ObjectReferences,
+}
+
+/// The response to a [`Run`], referring to the worklist the findings were added to.
+///
+/// Typically the root element of the related XML Response.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "atcworklist:worklistRun")]
+#[readonly::make]
+pub struct WorklistRun {
+    #[serde(rename = "atcworklist:worklistId")]
+    pub worklist_id: String,
+
+    /// The time the run finished, e.g. `2025-03-07T10:12:45Z`
+    #[serde(rename = "atcworklist:worklistTimestamp", default)]
+    pub timestamp: String,
+
+    #[serde(rename = "atcworklist:infos")]
+    infos: Option<RunInfos>,
+}
+
+impl WorklistRun {
+    /// Information about the run, e.g. the number of findings per priority.
+    pub fn infos(&self) -> &[RunInfo] {
+        self.infos.as_ref().map_or(&[], |i| &i.infos)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct RunInfos {
+    #[serde(rename = "atcinfo:info", default)]
+    infos: Vec<RunInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+#[readonly::make]
+pub struct RunInfo {
+    /// The kind of the information, e.g. `FINDING_STATS`
+    #[serde(rename = "atcinfo:type")]
+    pub kind: String,
+
+    /// e.g. `0,2,1`, the number of findings of priority 1, 2 and 3.
+    #[serde(rename = "atcinfo:description", default)]
+    pub description: String,
+}
+
+/// The findings of the runs of a worklist, grouped by the objects they were found in.
+///
+/// Typically the root element of the related XML Response.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "atcworklist:worklist")]
+#[readonly::make]
+pub struct Worklist {
+    #[serde(rename = "@atcworklist:id")]
+    pub id: String,
+
+    #[serde(rename = "@atcworklist:timestamp", default)]
+    pub timestamp: String,
+
+    /// Whether all objects of the run have been checked, the worklist is incomplete
+    /// while the run is still in progress.
+    #[serde(rename = "@atcworklist:objectSetIsComplete", default)]
+    pub complete: bool,
+
+    #[serde(rename = "atcworklist:objects")]
+    objects: Option<WorklistObjects>,
+}
+
+impl Worklist {
+    pub fn objects(&self) -> &[WorklistObject] {
+        self.objects.as_ref().map_or(&[], |o| &o.objects)
+    }
+
+    /// The findings of all objects of the worklist.
+    pub fn findings(&self) -> impl Iterator<Item = &Finding> {
+        self.objects().iter().flat_map(WorklistObject::findings)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct WorklistObjects {
+    #[serde(rename = "atcobject:object", default)]
+    objects: Vec<WorklistObject>,
+}
+
+/// An object that was checked along with its findings.
+#[derive(Debug, Deserialize)]
+#[readonly::make]
+pub struct WorklistObject {
+    /// The uri of the object, e.g. `/sap/bc/adt/oo/classes/zcl_order`
+    #[serde(rename = "@adtcore:uri")]
+    pub uri: String,
+
+    /// The object type, e.g `CLAS/OC`
+    #[serde(rename = "@adtcore:type", default)]
+    pub object_type: String,
+
+    #[serde(rename = "@adtcore:name")]
+    pub name: String,
+
+    #[serde(rename = "@adtcore:packageName", default)]
+    pub package: String,
+
+    /// The user that last changed the object.
+    #[serde(rename = "@atcobject:author", default)]
+    pub author: String,
+
+    #[serde(rename = "atcobject:findings")]
+    findings: Option<Findings>,
+}
+
+impl WorklistObject {
+    pub fn findings(&self) -> &[Finding] {
+        self.findings.as_ref().map_or(&[], |f| &f.findings)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Findings {
+    #[serde(rename = "atcfinding:finding", default)]
+    findings: Vec<Finding>,
+}
+
+/// A finding of a check, e.g. a missing authority check.
+#[derive(Debug, Deserialize)]
+#[readonly::make]
+pub struct Finding {
+    /// The uri of the finding itself, required to request an exemption for it.
+    #[serde(rename = "@adtcore:uri")]
+    pub uri: String,
+
+    /// The location of the finding in the source code, e.g.
+    /// `/sap/bc/adt/oo/classes/zcl_order/source/main#start=21,2`
+    #[serde(rename = "@atcfinding:location")]
+    pub location: String,
+
+    /// The priority of the finding, from `1` (very high) to `4` (low).
+    #[serde(rename = "@atcfinding:priority")]
+    pub priority: u8,
+
+    /// The id of the check, e.g. `CL_CI_TEST_EXTENDED_PROG_CHECK`
+    #[serde(rename = "@atcfinding:checkId", default)]
+    pub check_id: String,
+
+    /// e.g. `Extended Program Check`
+    #[serde(rename = "@atcfinding:checkTitle", default)]
+    pub check_title: String,
+
+    #[serde(rename = "@atcfinding:messageId", default)]
+    pub message_id: String,
+
+    /// The message of the finding, e.g. `Variable LV_TEMP is not used`
+    #[serde(rename = "@atcfinding:messageTitle", default)]
+    pub message: String,
+
+    /// The state of an exemption of the finding, empty if none was requested.
+    #[serde(rename = "@atcfinding:exemptionApproval", default)]
+    pub exemption_approval: String,
+
+    #[serde(rename = "atcfinding:quickfixes")]
+    quickfixes: Option<Quickfixes>,
+}
+
+impl Finding {
+    /// The location of the finding, parsed from its [`location`](Self::location).
+    pub fn source_location(&self) -> SourceLocation {
+        SourceLocation::parse(&self.location)
+    }
+
+    /// Whether the finding can be resolved by a quickfix.
+    pub fn has_quickfix(&self) -> bool {
+        self.quickfixes
+            .as_ref()
+            .is_some_and(|q| q.automatic || q.manual)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Quickfixes {
+    #[serde(rename = "@atcfinding:manual", default)]
+    manual: bool,
+
+    #[serde(rename = "@atcfinding:automatic", default)]
+    automatic: bool,
+}
+
+/// A request to exempt a finding from the checks, e.g. because it is a false positive.
+///
+/// Exemptions take effect once the approver approved them.
+///
+/// Typically the root element of the related XML Request.
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename = "atcexmpt:exemptionApply")]
+pub struct ExemptionRequest {
+    #[serde(rename = "atcexmpt:exemptionProposal")]
+    proposal: ExemptionProposal,
+
+    #[serde(rename = "atcexmpt:requestNotification")]
+    notification: Notification,
+}
+
+impl ExemptionRequest {
+    /// Requests the exemption of a single finding, the reason being one of the
+    /// [`exemption_reasons`](Customizing::exemption_reasons) of the customizing.
+    pub fn new(
+        finding_uri: &str,
+        package: &str,
+        reason: &str,
+        justification: &str,
+        approver: &str,
+    ) -> Self {
+        Self {
+            proposal: ExemptionProposal {
+                finding: finding_uri.to_owned(),
+                package: package.to_owned(),
+                restriction: Restriction { this_finding: true },
+                approver: approver.to_uppercase(),
+                reason: reason.to_owned(),
+                justification: justification.to_owned(),
+                notify: "never",
+            },
+            notification: Notification {
+                approver: approver.to_uppercase(),
+            },
+        }
+    }
+}
+
+impl IntoXmlRoot for ExemptionRequest {
+    fn namespaces(&self) -> Vec<(Cow<'static, str>, Cow<'static, str>)> {
+        vec![(
+            "atcexmpt".into(),
+            "http://www.sap.com/adt/atc/exemption".into(),
+        )]
+    }
+}
+
+#[derive(Debug, Serialize, Clone)]
+struct ExemptionProposal {
+    #[serde(rename = "atcexmpt:finding")]
+    finding: String,
+
+    #[serde(rename = "atcexmpt:package")]
+    package: String,
+
+    #[serde(rename = "atcexmpt:restriction")]
+    restriction: Restriction,
+
+    #[serde(rename = "atcexmpt:approver")]
+    approver: String,
+
+    #[serde(rename = "atcexmpt:reason")]
+    reason: String,
+
+    #[serde(rename = "atcexmpt:justification")]
+    justification: String,
+
+    #[serde(rename = "atcexmpt:notify")]
+    notify: &'static str,
+}
+
+#[derive(Debug, Serialize, Clone)]
+struct Restriction {
+    /// Whether only this finding is exempted, rather than all findings of the check.
+    #[serde(rename = "atcexmpt:thisFinding")]
+    this_finding: bool,
+}
+
+#[derive(Debug, Serialize, Clone)]
+struct Notification {
+    #[serde(rename = "atcexmpt:approver")]
+    approver: String,
+}
+
+/// The response to an [`ExemptionRequest`]
+///
+/// Typically the root element of the related XML Response.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "atcexmpt:status")]
+#[readonly::make]
+pub struct ExemptionStatus {
+    /// e.g. `Exemption requested`
+    #[serde(rename = "atcexmpt:message", default)]
+    pub message: String,
+
+    /// The type of the message, e.g. `I` or `E`
+    #[serde(rename = "atcexmpt:type", default)]
+    pub kind: String,
+}
+
+impl ExemptionStatus {
+    pub fn is_requested(&self) -> bool {
+        !matches!(self.kind.as_str(), "E" | "A" | "X")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::adtcore::ObjectReferenceBuilder;
+
+    #[test]
+    fn deserialize_customizing() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+            <atc:customizing xmlns:atc="http://www.sap.com/adt/atc">
+                <properties>
+                    <property name="systemCheckVariant" value="DEFAULT"/>
+                    <property name="ciCheckFlavour" value="false"/>
+                </properties>
+                <exemption>
+                    <reasons>
+                        <reason id="FPOS" title="False Positive" justificationMandatory="true"/>
+                        <reason id="OTHR" title="Other" justificationMandatory="true"/>
+                    </reasons>
+                </exemption>
+            </atc:customizing>"#;
+
+        let customizing: Customizing = serde_xml_rs::from_str(plain).unwrap();
+        assert_eq!(customizing.system_check_variant(), Some("DEFAULT"));
+        assert_eq!(customizing.exemption_reasons()[0].id, "FPOS");
+        assert!(customizing.exemption_reasons()[1].justification_mandatory);
+    }
+
+    #[test]
+    fn serialize_run() {
+        let object = ObjectReferenceBuilder::default()
+            .uri("/sap/bc/adt/oo/classes/zcl_order")
+            .name("ZCL_ORDER")
+            .build()
+            .unwrap();
+        let body = Run::new(vec![object], 100).into_xml_root().unwrap();
+
+        assert!(body.contains(r#"maximumVerdicts="100"><objectSets>"#));
+        assert!(body.contains(r#"<objectSet kind="inclusive"><adtcore:objectReferences>"#));
+        assert!(body.contains(r#"adtcore:uri="/sap/bc/adt/oo/classes/zcl_order""#));
+    }
+
+    #[test]
+    fn deserialize_worklist_run() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+            <atcworklist:worklistRun xmlns:atcworklist="http://www.sap.com/adt/atc/worklist">
+                <atcworklist:worklistId>0242AC1100021EDFB3D1E4B9A3F6C0F2</atcworklist:worklistId>
+                <atcworklist:worklistTimestamp>2025-03-07T10:12:45Z</atcworklist:worklistTimestamp>
+                <atcworklist:infos>
+                    <atcinfo:info xmlns:atcinfo="http://www.sap.com/adt/atc/info">
+                        <atcinfo:type>FINDING_STATS</atcinfo:type>
+                        <atcinfo:description>0,1,0</atcinfo:description>
+                    </atcinfo:info>
+                </atcworklist:infos>
+            </atcworklist:worklistRun>"#;
+
+        let run: WorklistRun = serde_xml_rs::from_str(plain).unwrap();
+        assert_eq!(run.worklist_id, "0242AC1100021EDFB3D1E4B9A3F6C0F2");
+        assert_eq!(run.infos()[0].description, "0,1,0");
+    }
+
+    #[test]
+    fn deserialize_worklist() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+            <atcworklist:worklist atcworklist:id="0242AC1100021EDFB3D1E4B9A3F6C0F2" atcworklist:timestamp="2025-03-07T10:12:45Z" atcworklist:usedObjectSet="99999999999999999999999999999999" atcworklist:objectSetIsComplete="true" xmlns:atcworklist="http://www.sap.com/adt/atc/worklist">
+                <atcworklist:objectSets>
+                    <atcworklist:objectSet atcworklist:name="00000000000000000000000000000000" atcworklist:title="All Objects" atcworklist:kind="ALL"/>
+                </atcworklist:objectSets>
+                <atcworklist:objects>
+                    <atcobject:object adtcore:uri="/sap/bc/adt/oo/classes/zcl_order" adtcore:type="CLAS/OC" adtcore:name="ZCL_ORDER" adtcore:packageName="ZORDERS" atcobject:author="DEVELOPER" xmlns:adtcore="http://www.sap.com/adt/core" xmlns:atcobject="http://www.sap.com/adt/atc/object">
+                        <atcobject:findings>
+                            <atcfinding:finding adtcore:uri="/sap/bc/adt/atc/findings/itemid/0242AC1100021EDFB3D1E4B9A3F6C0F2/1" atcfinding:location="/sap/bc/adt/oo/classes/zcl_order/source/main#start=21,4" atcfinding:priority="2" atcfinding:checkId="003EB9F5A8D91EDA9DCD4BA6C0A2F7D1" atcfinding:checkTitle="Extended Program Check" atcfinding:messageId="0001" atcfinding:messageTitle="Variable LV_TEMP is not used" atcfinding:exemptionApproval="" atcfinding:exemptionKind="" xmlns:atcfinding="http://www.sap.com/adt/atc/finding">
+                                <atom:link href="/sap/bc/adt/documentation/atc/documents/itemid/1" rel="http://www.sap.com/adt/relations/documentation" type="text/html" xmlns:atom="http://www.w3.org/2005/Atom"/>
+                                <atcfinding:quickfixes atcfinding:manual="false" atcfinding:automatic="true" atcfinding:pseudo="false"/>
+                            </atcfinding:finding>
+                        </atcobject:findings>
+                    </atcobject:object>
+                    <atcobject:object adtcore:uri="/sap/bc/adt/programs/programs/zdemo1" adtcore:type="PROG/P" adtcore:name="ZDEMO1" xmlns:adtcore="http://www.sap.com/adt/core" xmlns:atcobject="http://www.sap.com/adt/atc/object"/>
+                </atcworklist:objects>
+            </atcworklist:worklist>"#;
+
+        let worklist: Worklist = serde_xml_rs::from_str(plain).unwrap();
+        assert!(worklist.complete);
+        assert_eq!(worklist.objects().len(), 2);
+        assert_eq!(worklist.objects()[0].package, "ZORDERS");
+
+        let findings: Vec<&Finding> = worklist.findings().collect();
+        let [finding] = findings.as_slice() else {
+            panic!("Expected a single finding");
+        };
+        assert_eq!(finding.priority, 2);
+        assert_eq!(finding.check_title, "Extended Program Check");
+        assert_eq!(finding.message, "Variable LV_TEMP is not used");
+        assert!(finding.has_quickfix());
+
+        let location = finding.source_location();
+        assert_eq!(location.uri, "/sap/bc/adt/oo/classes/zcl_order/source/main");
+        assert_eq!(location.start, Some((21, 4)));
+    }
+
+    #[test]
+    fn serialize_exemption_request() {
+        let body = ExemptionRequest::new(
+            "/sap/bc/adt/atc/findings/itemid/0242AC1100021EDFB3D1E4B9A3F6C0F2/1",
+            "ZORDERS",
+            "FPOS",
+            "The variable is used dynamically.",
+            "quality",
+        )
+        .into_xml_root()
+        .unwrap();
+
+        assert!(body.contains("<atcexmpt:reason>FPOS</atcexmpt:reason>"));
+        assert!(body.contains("<atcexmpt:approver>QUALITY</atcexmpt:approver>"));
+        assert!(body.contains("<atcexmpt:thisFinding>true</atcexmpt:thisFinding>"));
+    }
+
+    #[test]
+    fn deserialize_exemption_status() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+            <atcexmpt:status xmlns:atcexmpt="http://www.sap.com/adt/atc/exemption">
+                <atcexmpt:message>Exemption requested</atcexmpt:message>
+                <atcexmpt:type>I</atcexmpt:type>
+            </atcexmpt:status>"#;
+
+        let status: ExemptionStatus = serde_xml_rs::from_str(plain).unwrap();
+        assert!(status.is_requested());
+    }
+}
@@ -0,0 +1,576 @@
+/// ABAP Unit (AUNIT) - http://www.sap.com/adt/aunit
+///
+/// Models of test runs, i.e. the configuration of a run and its results per program,
+/// test class and test method.
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::{
+    adtcore::{ObjectReference, ObjectReferences, SourceLocation},
+    serialize::IntoXmlRoot,
+};
+
+/// Which tests are run for the objects of a test run.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TestScope {
+    /// The tests of the objects themselves, e.g. the local test classes of a class.
+    pub own_tests: bool,
+
+    /// The tests of other objects that are assigned to the objects.
+    pub assigned_tests: bool,
+}
+
+impl Default for TestScope {
+    fn default() -> Self {
+        Self {
+            own_tests: true,
+            assigned_tests: false,
+        }
+    }
+}
+
+/// The risk levels of the tests to run, as declared by `RISK LEVEL` of a test class.
+///
+/// Systems may restrict the risk levels that can be run, e.g. to `harmless` in production.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct RiskLevels {
+    #[serde(rename = "@harmless")]
+    pub harmless: bool,
+
+    #[serde(rename = "@dangerous")]
+    pub dangerous: bool,
+
+    #[serde(rename = "@critical")]
+    pub critical: bool,
+}
+
+impl Default for RiskLevels {
+    fn default() -> Self {
+        Self {
+            harmless: true,
+            dangerous: true,
+            critical: true,
+        }
+    }
+}
+
+/// The durations of the tests to run, as declared by `DURATION` of a test class.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Durations {
+    #[serde(rename = "@short")]
+    pub short: bool,
+
+    #[serde(rename = "@medium")]
+    pub medium: bool,
+
+    #[serde(rename = "@long")]
+    pub long: bool,
+}
+
+impl Default for Durations {
+    fn default() -> Self {
+        Self {
+            short: true,
+            medium: true,
+            long: true,
+        }
+    }
+}
+
+/// The configuration of a test run for a set of objects.
+///
+/// Typically the root element of the related XML Request.
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename = "aunit:runConfiguration")]
+pub struct RunConfiguration {
+    #[serde(rename = "external")]
+    external: External,
+
+    #[serde(rename = "options")]
+    options: Options,
+
+    #[serde(rename = "adtcore:objectSets")]
+    object_sets: ObjectSets,
+}
+
+impl RunConfiguration {
+    pub fn new(
+        objects: Vec<ObjectReference>,
+        scope: TestScope,
+        risk_levels: RiskLevels,
+        durations: Durations,
+        coverage: bool,
+    ) -> Self {
+        Self {
+            external: External {
+                coverage: Flag { active: coverage },
+            },
+            options: Options {
+                uri_type: Value { value: "semantic" },
+                determination: DeterminationStrategy {
+                    same_program: scope.own_tests,
+                    assigned_tests: scope.assigned_tests,
+                    append_assigned_tests_preview: true,
+                },
+                risk_levels,
+                durations,
+                navigation_uri: Enabled { enabled: true },
+            },
+            object_sets: ObjectSets {
+                object_set: ObjectSet {
+                    kind: "inclusive",
+                    objects: ObjectReferences::from(objects),
+                },
+            },
+        }
+    }
+}
+
+impl IntoXmlRoot for RunConfiguration {
+    fn namespaces(&self) -> Vec<(Cow<'static, str>, Cow<'static, str>)> {
+        vec![
+            ("aunit".into(), "http://www.sap.com/adt/aunit".into()),
+            ("adtcore".into(), "http://www.sap.com/adt/core".into()),
+        ]
+    }
+}
+
+#[derive(Debug, Serialize, Clone)]
+struct External {
+    #[serde(rename = "coverage")]
+    coverage: Flag,
+}
+
+#[derive(Debug, Serialize, Clone)]
+struct Flag {
+    #[serde(rename = "@active")]
+    active: bool,
+}
+
+#[derive(Debug, Serialize, Clone)]
+struct Value {
+    #[serde(rename = "@value")]
+    value: &'static str,
+}
+
+#[derive(Debug, Serialize, Clone)]
+struct Enabled {
+    #[serde(rename = "@enabled")]
+    enabled: bool,
+}
+
+#[derive(Debug, Serialize, Clone)]
+struct Options {
+    #[serde(rename = "uriType")]
+    uri_type: Value,
+
+    #[serde(rename = "testDeterminationStrategy")]
+    determination: DeterminationStrategy,
+
+    #[serde(rename = "testRiskLevels")]
+    risk_levels: RiskLevels,
+
+    #[serde(rename = "testDurations")]
+    durations: Durations,
+
+    /// Whether results contain uris to navigate to the tests in the source code.
+    #[serde(rename = "withNavigationUri")]
+    navigation_uri: Enabled,
+}
+
+#[derive(Debug, Serialize, Clone)]
+struct DeterminationStrategy {
+    #[serde(rename = "@sameProgram")]
+    same_program: bool,
+
+    #[serde(rename = "@assignedTests")]
+    assigned_tests: bool,
+
+    #[serde(rename = "@appendAssignedTestsPreview")]
+    append_assigned_tests_preview: bool,
+}
+
+#[derive(Debug, Serialize, Clone)]
+struct ObjectSets {
+    #[serde(rename = "objectSet")]
+    object_set: ObjectSet,
+}
+
+#[derive(Debug, Serialize, Clone)]
+struct ObjectSet {
+    #[serde(rename = "@kind")]
+    kind: &'static str,
+
+    #[serde(rename = "adtcore:objectReferences")]
+    objects: ObjectReferences,
+}
+
+/// The result of a test run, i.e. the programs whose tests were run.
+///
+/// Typically the root element of the related XML Response.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "aunit:runResult")]
+#[readonly::make]
+pub struct RunResult {
+    #[serde(rename = "program", default)]
+    pub programs: Vec<TestProgram>,
+}
+
+impl RunResult {
+    /// Whether all tests passed, i.e. no test raised an error.
+    pub fn is_successful(&self) -> bool {
+        self.programs.iter().all(|program| {
+            program.alerts().iter().all(|a| !a.is_error())
+                && program.test_classes().iter().all(TestClass::is_successful)
+        })
+    }
+}
+
+/// A program (or class) whose tests were run.
+#[derive(Debug, Deserialize)]
+#[readonly::make]
+pub struct TestProgram {
+    /// The uri of the object, e.g. `/sap/bc/adt/oo/classes/zcl_order`
+    #[serde(rename = "@adtcore:uri")]
+    pub uri: String,
+
+    /// The object type, e.g `CLAS/OC`
+    #[serde(rename = "@adtcore:type", default)]
+    pub object_type: String,
+
+    #[serde(rename = "@adtcore:name")]
+    pub name: String,
+
+    #[serde(rename = "testClasses")]
+    test_classes: Option<TestClasses>,
+
+    #[serde(rename = "alerts")]
+    alerts: Option<Alerts>,
+}
+
+impl TestProgram {
+    pub fn test_classes(&self) -> &[TestClass] {
+        self.test_classes
+            .as_ref()
+            .map_or(&[], |classes| &classes.classes)
+    }
+
+    /// Alerts of the program itself, e.g. if it has no tests.
+    pub fn alerts(&self) -> &[Alert] {
+        Alerts::slice(&self.alerts)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TestClasses {
+    #[serde(rename = "testClass", default)]
+    classes: Vec<TestClass>,
+}
+
+/// A test class, i.e. a class declared `FOR TESTING`
+#[derive(Debug, Deserialize)]
+#[readonly::make]
+pub struct TestClass {
+    #[serde(rename = "@adtcore:uri")]
+    pub uri: String,
+
+    #[serde(rename = "@adtcore:name")]
+    pub name: String,
+
+    /// The location of the test class in the source code, e.g.
+    /// `/sap/bc/adt/oo/classes/zcl_order/includes/testclasses#start=21,0`
+    #[serde(rename = "@navigationUri")]
+    pub navigation_uri: Option<String>,
+
+    /// The declared duration of the class, e.g. `short`.
+    #[serde(rename = "@durationCategory")]
+    pub duration: Option<String>,
+
+    /// The declared risk level of the class, e.g. `harmless`.
+    #[serde(rename = "@riskLevel")]
+    pub risk_level: Option<String>,
+
+    #[serde(rename = "testMethods")]
+    test_methods: Option<TestMethods>,
+
+    #[serde(rename = "alerts")]
+    alerts: Option<Alerts>,
+}
+
+impl TestClass {
+    pub fn test_methods(&self) -> &[TestMethod] {
+        self.test_methods
+            .as_ref()
+            .map_or(&[], |methods| &methods.methods)
+    }
+
+    /// Alerts of the class itself, e.g. if its `setup` failed.
+    pub fn alerts(&self) -> &[Alert] {
+        Alerts::slice(&self.alerts)
+    }
+
+    /// The location of the class in the source code, parsed from its
+    /// [`navigation_uri`](Self::navigation_uri).
+    pub fn location(&self) -> Option<SourceLocation> {
+        self.navigation_uri.as_deref().map(SourceLocation::parse)
+    }
+
+    pub fn is_successful(&self) -> bool {
+        self.alerts().iter().all(|a| !a.is_error())
+            && self.test_methods().iter().all(TestMethod::is_successful)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TestMethods {
+    #[serde(rename = "testMethod", default)]
+    methods: Vec<TestMethod>,
+}
+
+/// A test method of a [`TestClass`] along with the alerts it raised.
+#[derive(Debug, Deserialize)]
+#[readonly::make]
+pub struct TestMethod {
+    #[serde(rename = "@adtcore:uri")]
+    pub uri: String,
+
+    #[serde(rename = "@adtcore:name")]
+    pub name: String,
+
+    /// The location of the method in the source code, see [`TestClass::navigation_uri`]
+    #[serde(rename = "@navigationUri")]
+    pub navigation_uri: Option<String>,
+
+    /// How long the method took to run, in seconds.
+    #[serde(rename = "@executionTime", default)]
+    pub execution_time: f64,
+
+    #[serde(rename = "alerts")]
+    alerts: Option<Alerts>,
+}
+
+impl TestMethod {
+    pub fn alerts(&self) -> &[Alert] {
+        Alerts::slice(&self.alerts)
+    }
+
+    /// The location of the method in the source code, parsed from its
+    /// [`navigation_uri`](Self::navigation_uri).
+    pub fn location(&self) -> Option<SourceLocation> {
+        self.navigation_uri.as_deref().map(SourceLocation::parse)
+    }
+
+    pub fn is_successful(&self) -> bool {
+        self.alerts().iter().all(|a| !a.is_error())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Alerts {
+    #[serde(rename = "alert", default)]
+    alerts: Vec<Alert>,
+}
+
+impl Alerts {
+    fn slice(alerts: &Option<Alerts>) -> &[Alert] {
+        alerts.as_ref().map_or(&[], |alerts| &alerts.alerts)
+    }
+}
+
+/// A failure or warning raised by a test, e.g. a failed assertion.
+#[derive(Debug, Deserialize)]
+#[readonly::make]
+pub struct Alert {
+    /// The kind of the alert, e.g. `failedAssertion`, `exception` or `warning`.
+    #[serde(rename = "@kind")]
+    pub kind: String,
+
+    /// The severity of the alert, e.g. `critical`, `fatal` or `tolerable`.
+    #[serde(rename = "@severity")]
+    pub severity: String,
+
+    /// A short description, e.g. `Critical Assertion Error: 'First_Test: ASSERT_EQUALS'`
+    #[serde(rename = "title")]
+    pub title: String,
+
+    #[serde(rename = "details")]
+    details: Option<Details>,
+
+    #[serde(rename = "stack")]
+    stack: Option<Stack>,
+}
+
+impl Alert {
+    /// Whether the alert fails the test, rather than just warning about it.
+    pub fn is_error(&self) -> bool {
+        matches!(self.severity.as_str(), "critical" | "fatal")
+    }
+
+    /// The texts of the details of the alert, nested details follow their parent,
+    /// e.g. `Different Values:`, `Expected [1] Actual [2]`
+    pub fn details(&self) -> Vec<&str> {
+        let mut texts = vec![];
+        let mut pending: Vec<&Detail> = self
+            .details
+            .iter()
+            .flat_map(|d| d.details.iter().rev())
+            .collect();
+        while let Some(detail) = pending.pop() {
+            texts.push(detail.text.as_str());
+            pending.extend(detail.details.iter().flat_map(|d| d.details.iter().rev()));
+        }
+        texts
+    }
+
+    /// The call stack of the alert, starting with the innermost call.
+    pub fn stack(&self) -> &[StackEntry] {
+        self.stack.as_ref().map_or(&[], |stack| &stack.entries)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Details {
+    #[serde(rename = "detail", default)]
+    details: Vec<Detail>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Detail {
+    #[serde(rename = "@text", default)]
+    text: String,
+
+    #[serde(rename = "details")]
+    details: Option<Details>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Stack {
+    #[serde(rename = "stackEntry", default)]
+    entries: Vec<StackEntry>,
+}
+
+/// An entry of the call stack of an [`Alert`]
+#[derive(Debug, Deserialize)]
+#[readonly::make]
+pub struct StackEntry {
+    /// The location of the call, e.g.
+    /// `/sap/bc/adt/oo/classes/zcl_order/includes/testclasses#start=28,0`
+    #[serde(rename = "@adtcore:uri")]
+    pub uri: String,
+
+    #[serde(rename = "@adtcore:name", default)]
+    pub name: String,
+
+    /// A description of the call, e.g. `Include: <ZCL_ORDER=====CCAU> Line: <28> (FIRST_TEST)`
+    #[serde(rename = "@adtcore:description", default)]
+    pub description: String,
+}
+
+impl StackEntry {
+    /// The location of the call, parsed from its [`uri`](Self::uri).
+    pub fn location(&self) -> SourceLocation {
+        SourceLocation::parse(&self.uri)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::adtcore::ObjectReferenceBuilder;
+
+    #[test]
+    fn serialize_run_configuration() {
+        let object = ObjectReferenceBuilder::default()
+            .uri("/sap/bc/adt/oo/classes/zcl_order")
+            .name("ZCL_ORDER")
+            .build()
+            .unwrap();
+        let body = RunConfiguration::new(
+            vec![object],
+            TestScope::default(),
+            RiskLevels::default(),
+            Durations::default(),
+            false,
+        )
+        .into_xml_root()
+        .unwrap();
+
+        assert!(body.contains(r#"<coverage active="false""#));
+        assert!(body.contains(r#"sameProgram="true" assignedTests="false""#));
+        assert!(
+            body.contains(r#"<testRiskLevels harmless="true" dangerous="true" critical="true""#)
+        );
+        assert!(body.contains(r#"<objectSet kind="inclusive"><adtcore:objectReferences>"#));
+        assert!(body.contains(r#"adtcore:uri="/sap/bc/adt/oo/classes/zcl_order""#));
+    }
+
+    #[test]
+    fn deserialize_run_result() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+            <aunit:runResult xmlns:aunit="http://www.sap.com/adt/aunit">
+                <program adtcore:uri="/sap/bc/adt/oo/classes/zcl_order" adtcore:type="CLAS/OC" adtcore:name="ZCL_ORDER" uriType="semantic" xmlns:adtcore="http://www.sap.com/adt/core">
+                    <testClasses>
+                        <testClass adtcore:uri="/sap/bc/adt/oo/classes/zcl_order/includes/testclasses#type=CLAS%2FOCL;name=LTCL_ORDER" adtcore:type="CLAS/OCL" adtcore:name="LTCL_ORDER" uriType="semantic" navigationUri="/sap/bc/adt/oo/classes/zcl_order/includes/testclasses#start=21,0" durationCategory="short" riskLevel="harmless">
+                            <testMethods>
+                                <testMethod adtcore:uri="/sap/bc/adt/oo/classes/zcl_order/includes/testclasses#type=CLAS%2FOLI;name=LTCL_ORDER%20%20FIRST_TEST" adtcore:type="CLAS/OLI" adtcore:name="FIRST_TEST" executionTime="0.012" uriType="semantic" navigationUri="/sap/bc/adt/oo/classes/zcl_order/includes/testclasses#start=27,0" unit="s">
+                                    <alerts>
+                                        <alert kind="failedAssertion" severity="critical">
+                                            <title>Critical Assertion Error: 'First_Test: ASSERT_EQUALS'</title>
+                                            <details>
+                                                <detail text="Different Values:">
+                                                    <details>
+                                                        <detail text="Expected [1] Actual [2]"/>
+                                                    </details>
+                                                </detail>
+                                                <detail text="Test 'LTCL_ORDER-&gt;FIRST_TEST' in Main Program 'ZCL_ORDER=====================CP'."/>
+                                            </details>
+                                            <stack>
+                                                <stackEntry adtcore:uri="/sap/bc/adt/oo/classes/zcl_order/includes/testclasses#start=28,0" adtcore:type="CLAS/OCN/testclasses" adtcore:name="ZCL_ORDER" adtcore:description="Include: &lt;ZCL_ORDER=====================CCAU&gt; Line: &lt;28&gt; (FIRST_TEST)"/>
+                                            </stack>
+                                        </alert>
+                                    </alerts>
+                                </testMethod>
+                                <testMethod adtcore:uri="/sap/bc/adt/oo/classes/zcl_order/includes/testclasses#type=CLAS%2FOLI;name=LTCL_ORDER%20%20SECOND_TEST" adtcore:type="CLAS/OLI" adtcore:name="SECOND_TEST" executionTime="0" uriType="semantic" navigationUri="/sap/bc/adt/oo/classes/zcl_order/includes/testclasses#start=31,0" unit="s"/>
+                            </testMethods>
+                        </testClass>
+                    </testClasses>
+                </program>
+            </aunit:runResult>"#;
+
+        let result: RunResult = serde_xml_rs::from_str(plain).unwrap();
+        assert!(!result.is_successful());
+
+        let class = &result.programs[0].test_classes()[0];
+        assert_eq!(class.name, "LTCL_ORDER");
+        assert_eq!(class.location().unwrap().start, Some((21, 0)));
+
+        let [first, second] = class.test_methods() else {
+            panic!("Expected two test methods");
+        };
+        assert!(second.is_successful());
+        assert_eq!(first.execution_time, 0.012);
+
+        let alert = &first.alerts()[0];
+        assert!(alert.is_error());
+        assert_eq!(
+            alert.details(),
+            vec![
+                "Different Values:",
+                "Expected [1] Actual [2]",
+                "Test 'LTCL_ORDER->FIRST_TEST' in Main Program 'ZCL_ORDER=====================CP'."
+            ]
+        );
+        assert_eq!(alert.stack()[0].location().start, Some((28, 0)));
+    }
+
+    #[test]
+    fn deserialize_empty_run_result() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+            <aunit:runResult xmlns:aunit="http://www.sap.com/adt/aunit"/>"#;
+
+        let result: RunResult = serde_xml_rs::from_str(plain).unwrap();
+        assert!(result.programs.is_empty());
+        assert!(result.is_successful());
+    }
+}
@@ -1,6 +1,11 @@
+use std::borrow::Cow;
+
+use base64::{Engine, engine::general_purpose};
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 
+use crate::models::{adtcore::SourceLocation, serialize::IntoXmlRoot};
+
 /// A `Reporter` that can be used to check objects.
 ///
 /// Provides the name and supported object types of the reporter.
@@ -88,6 +93,13 @@ pub struct Message {
     pub quick_fix: Option<QuickFix>,
 }
 
+impl Message {
+    /// The location the message refers to, parsed from its [`location_uri`](Self::location_uri).
+    pub fn location(&self) -> SourceLocation {
+        SourceLocation::parse(&self.location_uri)
+    }
+}
+
 /// Wraps a collection of [`Message`]s.
 ///
 /// Typically the root element of the related XML Response.
@@ -95,7 +107,7 @@ pub struct Message {
 #[serde(rename = "chkrun:checkMessageList")]
 #[readonly::make]
 pub struct MessageList {
-    #[serde(rename = "chkrun:checkMessage")]
+    #[serde(rename = "chkrun:checkMessage", default)]
     pub messages: Vec<Message>,
 }
 
@@ -134,6 +146,54 @@ pub struct Object {
     #[serde(rename = "@chkrun:version")]
     #[builder(setter(into))]
     version: String,
+
+    /// Optional: sources to check instead of the ones saved in the system, e.g. unsaved changes.
+    #[serde(rename = "chkrun:artifacts", skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    artifacts: Option<Artifacts>,
+}
+
+/// Wraps a collection of [`Artifact`]
+#[derive(Debug, Serialize, Clone, Default)]
+#[serde(rename = "chkrun:artifacts")]
+pub struct Artifacts {
+    #[serde(rename = "chkrun:artifact")]
+    artifacts: Vec<Artifact>,
+}
+
+impl From<Artifact> for Artifacts {
+    fn from(value: Artifact) -> Self {
+        Self {
+            artifacts: vec![value],
+        }
+    }
+}
+
+/// The content of a source of an object to check, e.g. the `source/main` of a program.
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename = "chkrun:artifact")]
+pub struct Artifact {
+    #[serde(rename = "@chkrun:contentType")]
+    content_type: String,
+
+    /// The uri of the source the content replaces, e.g `.../programs/programs/ztest/source/main`
+    #[serde(rename = "@chkrun:uri")]
+    source_uri: String,
+
+    /// The base64 encoded content
+    #[serde(rename = "chkrun:content")]
+    content: String,
+}
+
+impl Artifact {
+    /// Creates an artifact from plain source code.
+    pub fn plain_text(source_uri: impl Into<String>, source: &str) -> Self {
+        Self {
+            content_type: "text/plain; charset=utf-8".into(),
+            source_uri: source_uri.into(),
+            content: general_purpose::STANDARD.encode(source),
+        }
+    }
 }
 
 /// Wraps a collection of [`Object`]
@@ -147,6 +207,15 @@ pub struct ObjectList {
     objects: Vec<Object>,
 }
 
+impl IntoXmlRoot for ObjectList {
+    fn namespaces(&self) -> Vec<(Cow<'static, str>, Cow<'static, str>)> {
+        vec![
+            ("chkrun".into(), "http://www.sap.com/adt/checkrun".into()),
+            ("adtcore".into(), "http://www.sap.com/adt/core".into()),
+        ]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -191,12 +260,39 @@ mod tests {
             objects: vec![Object {
                 object_uri: String::from("/sap/bc/adt/programs/programs/zwegwerf1"),
                 version: String::from("active"),
+                artifacts: None,
             }],
         };
 
         let result: String = config.to_string(&content).unwrap();
         assert_eq!(result, expected_result);
     }
+
+    #[test]
+    fn serialize_check_object_with_artifact() {
+        let content = ObjectListBuilder::default()
+            .object(
+                ObjectBuilder::default()
+                    .object_uri("/sap/bc/adt/programs/programs/ztest")
+                    .version("active")
+                    .artifacts(
+                        Artifact::plain_text(
+                            "/sap/bc/adt/programs/programs/ztest/source/main",
+                            "REPORT ztest.",
+                        )
+                        .into(),
+                    )
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+
+        let result = content.into_xml_root().unwrap();
+        assert!(result.contains(r#"chkrun:uri="/sap/bc/adt/programs/programs/ztest/source/main""#));
+        assert!(result.contains("<chkrun:content>UkVQT1JUIHp0ZXN0Lg==</chkrun:content>"));
+    }
+
     #[test]
     fn deserialize_check_report() {
         let plain_text = r#"<?xml version="1.0" encoding="UTF-8"?>
@@ -232,5 +328,8 @@ mod tests {
                 .map(|m| m.messages.len()),
             Some(8)
         );
+
+        let message = &result.reports[0].messages.as_ref().unwrap().messages[0];
+        assert_eq!(message.location().start, Some((193, 19)));
     }
 }
@@ -0,0 +1,271 @@
+/// Classes - http://www.sap.com/adt/oo/classes
+///
+/// ABAP ADT Responsible: `CL_OO_ADT_RES_CLASS`
+use crate::models::{adtcore, atom};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+/// The includes a global class is made up of.
+///
+/// Besides the main include (the global class pool), classes have local includes
+/// for local type definitions, local implementations, macros and test classes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ClassInclude {
+    /// The global class definition and implementation.
+    Main,
+    /// Class-relevant local types (`CCDEF`)
+    Definitions,
+    /// Local types and implementations (`CCIMP`)
+    Implementations,
+    /// Macros (`CCMAC`)
+    Macros,
+    /// Local test classes (`CCAU`)
+    TestClasses,
+    /// An include this client does not know of, e.g. of a newer release.
+    #[serde(other)]
+    Unknown,
+}
+
+impl ClassInclude {
+    pub const ALL: &'static [ClassInclude] = &[
+        Self::Main,
+        Self::Definitions,
+        Self::Implementations,
+        Self::Macros,
+        Self::TestClasses,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Main => "main",
+            Self::Definitions => "definitions",
+            Self::Implementations => "implementations",
+            Self::Macros => "macros",
+            Self::TestClasses => "testclasses",
+            Self::Unknown => "unknown",
+        }
+    }
+
+    /// The path of the include source relative to the class, e.g `includes/macros`.
+    ///
+    /// The main include is accessed through `source/main` rather than as include.
+    pub fn source_path(&self) -> String {
+        match self {
+            Self::Main => "source/main".into(),
+            other => format!("includes/{}", other.as_str()),
+        }
+    }
+}
+
+/// Visibility of a class or its components.
+///
+/// For classes, this refers to the instantiation (`CREATE PUBLIC`, ...).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Visibility {
+    Public,
+    Protected,
+    Private,
+    /// Package visibility, only available in ABAP Cloud (to be clarified)
+    Package,
+}
+
+/// Represents a global ABAP Class
+#[derive(Debug, Deserialize)]
+#[serde(rename = "class:abapClass")]
+#[readonly::make]
+pub struct AbapClass {
+    /// The name of the class
+    #[serde(rename = "@adtcore:name")]
+    pub name: String,
+
+    /// The object type of the class, should be `CLAS/OC`
+    #[serde(rename = "@adtcore:type")]
+    pub object_type: String,
+
+    /// The description of the class
+    #[serde(rename = "@adtcore:description")]
+    pub description: String,
+
+    /// The version of the class descriptor, e.g `active`
+    #[serde(rename = "@adtcore:version")]
+    pub version: String,
+
+    /// The datetime that the class was last changed at (UTC)
+    #[serde(rename = "@adtcore:changedAt")]
+    pub last_changed: DateTime<Utc>,
+
+    /// The user who last changed this class
+    #[serde(rename = "@adtcore:changedBy")]
+    pub changed_by: String,
+
+    /// The datetime that the class was created on (UTC)
+    #[serde(rename = "@adtcore:createdAt")]
+    pub created_at: DateTime<Utc>,
+
+    /// The user who is responsible for this class
+    #[serde(rename = "@adtcore:responsible")]
+    pub responsible: String,
+
+    /// Master language of the class
+    #[serde(rename = "@adtcore:masterLanguage")]
+    pub master_language: String,
+
+    /// The ABAP Version of the class
+    #[serde(rename = "@adtcore:abapLanguageVersion")]
+    pub abap_language_version: Option<String>,
+
+    /// Whether the class is `FINAL`
+    #[serde(rename = "@class:final")]
+    pub is_final: bool,
+
+    /// Whether the class is `ABSTRACT`
+    #[serde(rename = "@class:abstract")]
+    pub is_abstract: bool,
+
+    /// The instantiation of the class, e.g `CREATE PUBLIC`
+    #[serde(rename = "@class:visibility")]
+    pub visibility: Visibility,
+
+    /// The category of the class, e.g `generalObjectType` or `exceptionClass`
+    #[serde(rename = "@class:category")]
+    pub category: String,
+
+    /// Whether the class contains local test classes
+    #[serde(rename = "@class:hasTests", default)]
+    pub has_tests: bool,
+
+    /// Reference to the package the class belongs to
+    #[serde(rename = "adtcore:packageRef")]
+    pub package: adtcore::PackageRef,
+
+    /// Reference to the superclass, if the class inherits from another class
+    #[serde(rename = "class:superClassRef")]
+    pub superclass: Option<ClassRef>,
+
+    /// The includes of the class, see [`ClassInclude`]
+    #[serde(rename = "class:include", default)]
+    pub includes: Vec<ClassIncludeRef>,
+
+    /// Relative URLs to related class Operations
+    #[serde(rename = "atom:link", default)]
+    pub links: Vec<atom::Link>,
+}
+
+impl AbapClass {
+    /// Returns the reference to an include of the class, if it exists.
+    pub fn include(&self, include: ClassInclude) -> Option<&ClassIncludeRef> {
+        self.includes.iter().find(|i| i.include == include)
+    }
+}
+
+/// Reference to another class, e.g. the superclass.
+#[derive(Debug, Deserialize)]
+#[readonly::make]
+pub struct ClassRef {
+    #[serde(rename = "@adtcore:name")]
+    pub name: String,
+
+    #[serde(rename = "@adtcore:uri")]
+    pub uri: String,
+
+    #[serde(rename = "@adtcore:type")]
+    pub object_type: String,
+}
+
+/// Descriptor of an include of a class.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "class:include")]
+#[readonly::make]
+pub struct ClassIncludeRef {
+    /// The kind of include, see [`ClassInclude`]
+    #[serde(rename = "@class:includeType")]
+    pub include: ClassInclude,
+
+    /// The uri to the source code of the include, relative to the class
+    #[serde(rename = "@abapsource:sourceUri")]
+    pub source_uri: String,
+
+    /// The version of the include, e.g `active`, `inactive`
+    #[serde(rename = "@adtcore:version")]
+    pub version: String,
+
+    /// The datetime that the include was last changed at (UTC)
+    #[serde(rename = "@adtcore:changedAt")]
+    pub last_changed: Option<DateTime<Utc>>,
+
+    /// The user who last changed the include
+    #[serde(rename = "@adtcore:changedBy")]
+    pub changed_by: Option<String>,
+
+    /// Links to the source of the include, contains the etag of the source
+    #[serde(rename = "atom:link", default)]
+    pub links: Vec<atom::Link>,
+}
+
+impl ClassIncludeRef {
+    /// The etag of the source code of the include, if provided.
+    pub fn etag(&self) -> Option<&str> {
+        self.links.iter().find_map(|l| l.etag.as_deref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_abap_class_data() {
+        let plain = r#"<?xml version="1.0" encoding="UTF-8"?><class:abapClass xmlns:class="http://www.sap.com/adt/oo/classes" class:final="true" class:abstract="false" class:visibility="public" class:category="generalObjectType" class:hasTests="true" class:sharedMemoryEnabled="false" abapsource:fixPointArithmetic="true" abapsource:activeUnicodeCheck="true" adtcore:responsible="DEVELOPER" adtcore:masterLanguage="EN" adtcore:masterSystem="A4H" adtcore:abapLanguageVersion="X" adtcore:name="ZCL_ORDER_SERVICE" adtcore:type="CLAS/OC" adtcore:changedAt="2025-09-20T10:12:44Z" adtcore:version="active" adtcore:createdAt="2025-09-18T00:00:00Z" adtcore:changedBy="DEVELOPER" adtcore:createdBy="DEVELOPER" adtcore:description="Order service" adtcore:descriptionTextLimit="60" adtcore:language="EN" xmlns:abapsource="http://www.sap.com/adt/abapsource" xmlns:adtcore="http://www.sap.com/adt/core">
+                        <atom:link xmlns:atom="http://www.w3.org/2005/Atom" href="objectstructure" rel="http://www.sap.com/adt/relations/objectstructure" type="application/vnd.sap.adt.objectstructure.v2+xml"/>
+                        <atom:link xmlns:atom="http://www.w3.org/2005/Atom" href="source/main" rel="http://www.sap.com/adt/relations/source" type="text/plain" etag="202509201012440011"/>
+                        <adtcore:packageRef adtcore:uri="/sap/bc/adt/packages/%24tmp" adtcore:type="DEVC/K" adtcore:name="$TMP"/>
+                        <class:superClassRef adtcore:uri="/sap/bc/adt/oo/classes/zcl_service_base" adtcore:type="CLAS/OC" adtcore:name="ZCL_SERVICE_BASE"/>
+                        <class:include class:includeType="definitions" abapsource:sourceUri="includes/definitions" adtcore:name="ZCL_ORDER_SERVICE" adtcore:type="CLAS/I" adtcore:changedAt="2025-09-18T12:00:00Z" adtcore:version="active" adtcore:changedBy="DEVELOPER">
+                            <atom:link xmlns:atom="http://www.w3.org/2005/Atom" href="./zcl_order_service/includes/definitions" rel="http://www.sap.com/adt/relations/source" type="text/plain" etag="202509181200000011"/>
+                        </class:include>
+                        <class:include class:includeType="testclasses" abapsource:sourceUri="includes/testclasses" adtcore:name="ZCL_ORDER_SERVICE" adtcore:type="CLAS/I" adtcore:changedAt="2025-09-20T10:12:44Z" adtcore:version="active" adtcore:changedBy="DEVELOPER">
+                            <atom:link xmlns:atom="http://www.w3.org/2005/Atom" href="./zcl_order_service/includes/testclasses" rel="http://www.sap.com/adt/relations/source" type="text/plain" etag="202509201012440011"/>
+                        </class:include>
+                        <class:include class:includeType="main" abapsource:sourceUri="source/main" adtcore:name="ZCL_ORDER_SERVICE" adtcore:type="CLAS/I" adtcore:changedAt="2025-09-20T10:12:44Z" adtcore:version="active" adtcore:changedBy="DEVELOPER">
+                            <atom:link xmlns:atom="http://www.w3.org/2005/Atom" href="./zcl_order_service/source/main" rel="http://www.sap.com/adt/relations/source" type="text/plain" etag="202509201012440011"/>
+                        </class:include>
+                    </class:abapClass>"#;
+
+        let result: AbapClass = serde_xml_rs::from_str(plain).unwrap();
+        assert!(result.is_final);
+        assert!(result.has_tests);
+        assert_eq!(result.visibility, Visibility::Public);
+        assert_eq!(
+            result.superclass.as_ref().map(|s| s.name.as_str()),
+            Some("ZCL_SERVICE_BASE")
+        );
+        assert_eq!(result.includes.len(), 3);
+        assert_eq!(
+            result
+                .include(ClassInclude::TestClasses)
+                .and_then(|i| i.etag()),
+            Some("202509201012440011")
+        );
+        assert!(result.include(ClassInclude::Macros).is_none());
+    }
+
+    #[test]
+    fn deserialize_unknown_class_include() {
+        let plain = r#"<class:include xmlns:class="http://www.sap.com/adt/oo/classes" class:includeType="localtypes" abapsource:sourceUri="includes/localtypes" adtcore:version="active" xmlns:abapsource="http://www.sap.com/adt/abapsource" xmlns:adtcore="http://www.sap.com/adt/core"/>"#;
+
+        let result: ClassIncludeRef = serde_xml_rs::from_str(plain).unwrap();
+        assert_eq!(result.include, ClassInclude::Unknown);
+        assert_eq!(result.source_uri, "includes/localtypes");
+    }
+
+    #[test]
+    fn class_include_source_paths() {
+        assert_eq!(ClassInclude::Main.source_path(), "source/main");
+        assert_eq!(
+            ClassInclude::TestClasses.source_path(),
+            "includes/testclasses"
+        );
+    }
+}
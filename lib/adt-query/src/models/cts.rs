@@ -0,0 +1,717 @@
+/// Change and Transport System (CTS) - http://www.sap.com/cts/adt/tm
+///
+/// Models of the transport organizer, i.e. transport requests, their tasks and the
+/// objects recorded in them, as well as the transport check of an object.
+use std::borrow::Cow;
+
+use serde::{
+    Deserialize, Deserializer, Serialize,
+    de::{DeserializeOwned, IntoDeserializer},
+};
+
+use crate::models::{
+    asx::deserialize_abap_bool, checkrun::MessageList, serialize::IntoXmlRoot, tpr::TransportStatus,
+};
+
+/// The type of a transport request or task.
+///
+/// Refer to domain `TRFUNCTION` in SAP System.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum RequestType {
+    /// A request for changes to repository objects, e.g. programs and classes.
+    #[default]
+    #[serde(rename = "K")]
+    Workbench,
+    /// A request for changes to client specific customizing.
+    #[serde(rename = "W")]
+    Customizing,
+    /// A task of a workbench request that changes objects of the system itself.
+    #[serde(rename = "S")]
+    Development,
+    /// A task of a customizing request.
+    #[serde(rename = "Q")]
+    CustomizingTask,
+    /// A task that repairs objects of another system.
+    #[serde(rename = "R")]
+    Repair,
+    /// A task that has not been classified yet, it becomes a development or repair
+    /// task once the first object is recorded in it.
+    #[serde(rename = "X")]
+    Unclassified,
+    /// A transport of copies, i.e. objects transported without changing their origin.
+    #[serde(rename = "T")]
+    TransportOfCopies,
+    /// Any other type, e.g. relocations.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+/// The transport requests of a user, grouped by their category and target.
+///
+/// Typically the root element of the related XML Response.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "tm:root")]
+#[readonly::make]
+pub struct TransportOrganizer {
+    /// Requests for repository objects.
+    #[serde(rename = "tm:workbench")]
+    pub workbench: Option<Category>,
+
+    /// Requests for customizing.
+    #[serde(rename = "tm:customizing")]
+    pub customizing: Option<Category>,
+}
+
+impl TransportOrganizer {
+    /// The requests that objects can still be recorded in, across all targets.
+    pub fn modifiable_requests(&self) -> impl Iterator<Item = &Request> {
+        [&self.workbench, &self.customizing]
+            .into_iter()
+            .flatten()
+            .flat_map(|category| &category.targets)
+            .filter_map(|target| target.modifiable.as_ref())
+            .flat_map(|requests| &requests.requests)
+    }
+}
+
+/// The requests of a category (workbench or customizing) by their target.
+#[derive(Debug, Deserialize)]
+#[readonly::make]
+pub struct Category {
+    #[serde(rename = "tm:target", default)]
+    pub targets: Vec<Target>,
+}
+
+/// The requests that are transported to a target, e.g. the next system in the landscape.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "tm:target")]
+#[readonly::make]
+pub struct Target {
+    /// The name of the target, e.g. `/A4H/` or `LOCAL` for requests that are not transported.
+    #[serde(rename = "@tm:name")]
+    pub name: String,
+
+    #[serde(rename = "@tm:desc", default)]
+    pub description: String,
+
+    /// Requests that are not released yet.
+    #[serde(rename = "tm:modifiable")]
+    pub modifiable: Option<Requests>,
+
+    /// Requests that have been released, only if requested.
+    #[serde(rename = "tm:released")]
+    pub released: Option<Requests>,
+}
+
+/// Wraps a collection of [`Request`]
+#[derive(Debug, Deserialize)]
+#[readonly::make]
+pub struct Requests {
+    #[serde(rename = "tm:request", default)]
+    pub requests: Vec<Request>,
+}
+
+/// A transport request or one of its tasks.
+///
+/// Objects are recorded in the tasks of a request, each task belongs to one user.
+#[derive(Debug, Deserialize)]
+#[readonly::make]
+pub struct Request {
+    /// Number (or ID) of the request, e.g. `A4HK900089`
+    #[serde(rename = "@tm:number")]
+    pub number: String,
+
+    /// The request a task belongs to, `None` for requests.
+    #[serde(rename = "@tm:parent")]
+    pub parent: Option<String>,
+
+    #[serde(rename = "@tm:owner", default)]
+    pub owner: String,
+
+    #[serde(rename = "@tm:desc", default)]
+    pub description: String,
+
+    /// The type of the request, only provided by some responses.
+    #[serde(rename = "@tm:type")]
+    pub request_type: Option<RequestType>,
+
+    /// The status of the request, see [`TransportStatus`]
+    #[serde(rename = "@tm:status")]
+    pub status: Option<TransportStatus>,
+
+    #[serde(rename = "@tm:uri", default)]
+    pub uri: String,
+
+    /// The objects recorded directly in the request (or task).
+    #[serde(rename = "tm:abap_object", default)]
+    pub objects: Vec<TransportObject>,
+
+    /// The tasks of the request, empty for tasks.
+    #[serde(rename = "tm:task", default)]
+    pub tasks: Vec<Request>,
+}
+
+/// An object recorded in a request, e.g. `R3TR PROG Z_MY_PROGRAM`
+#[derive(Debug, Deserialize)]
+#[readonly::make]
+pub struct TransportObject {
+    /// The program id of the entry, `R3TR` for whole objects, `LIMU` for parts of them.
+    #[serde(rename = "@tm:pgmid")]
+    pub pgmid: String,
+
+    /// The type of the object, e.g. `PROG` or `CLAS`.
+    #[serde(rename = "@tm:type")]
+    pub object_type: String,
+
+    #[serde(rename = "@tm:name")]
+    pub name: String,
+
+    /// The workbench type of the object, e.g. `PROG/P`
+    #[serde(rename = "@tm:wbtype", default)]
+    pub workbench_type: String,
+
+    /// The uri of the object, if it can be opened in ADT.
+    #[serde(rename = "@tm:uri")]
+    pub uri: Option<String>,
+
+    #[serde(rename = "@tm:obj_desc", default)]
+    pub description: String,
+}
+
+/// An action on the transport organizer, e.g. creating a request.
+///
+/// Typically the root element of the related XML Request.
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename = "tm:root")]
+pub struct UserAction {
+    #[serde(rename = "@tm:useraction")]
+    action: &'static str,
+
+    #[serde(rename = "tm:request", skip_serializing_if = "Option::is_none")]
+    request: Option<NewRequest>,
+
+    #[serde(rename = "tm:abap_object", skip_serializing_if = "Option::is_none")]
+    object: Option<NewObject>,
+}
+
+impl UserAction {
+    /// Creates a request with a task for the owner.
+    ///
+    /// The target is the system the request is transported to, `LOCAL` if it is not.
+    pub fn new_request(
+        description: &str,
+        request_type: RequestType,
+        target: &str,
+        owner: &str,
+    ) -> Self {
+        Self {
+            action: "newrequest",
+            request: Some(NewRequest {
+                description: description.to_owned(),
+                request_type,
+                target: target.to_owned(),
+                cts_project: String::new(),
+                task: NewTask {
+                    owner: owner.to_owned(),
+                },
+            }),
+            object: None,
+        }
+    }
+
+    /// Records a whole object in a request, e.g. `PROG` `Z_MY_PROGRAM`
+    pub fn new_object(object_type: &str, name: &str) -> Self {
+        Self {
+            action: "newobject",
+            request: None,
+            object: Some(NewObject {
+                pgmid: "R3TR",
+                object_type: object_type.to_uppercase(),
+                name: name.to_uppercase(),
+            }),
+        }
+    }
+
+    /// Releases a request or task, optionally despite objects that are still locked.
+    pub fn release(ignore_locks: bool) -> Self {
+        Self {
+            action: release_action(ignore_locks),
+            request: None,
+            object: None,
+        }
+    }
+}
+
+/// The name of the action (and resource) to release a request.
+pub(crate) fn release_action(ignore_locks: bool) -> &'static str {
+    match ignore_locks {
+        true => "relwithignlock",
+        false => "newreleasejobs",
+    }
+}
+
+impl IntoXmlRoot for UserAction {
+    fn namespaces(&self) -> Vec<(Cow<'static, str>, Cow<'static, str>)> {
+        vec![("tm".into(), "http://www.sap.com/cts/adt/tm".into())]
+    }
+}
+
+#[derive(Debug, Serialize, Clone)]
+struct NewRequest {
+    #[serde(rename = "@tm:desc")]
+    description: String,
+
+    #[serde(rename = "@tm:type")]
+    request_type: RequestType,
+
+    #[serde(rename = "@tm:target")]
+    target: String,
+
+    #[serde(rename = "@tm:cts_project")]
+    cts_project: String,
+
+    #[serde(rename = "tm:task")]
+    task: NewTask,
+}
+
+#[derive(Debug, Serialize, Clone)]
+struct NewTask {
+    #[serde(rename = "@tm:owner")]
+    owner: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+struct NewObject {
+    #[serde(rename = "@tm:pgmid")]
+    pgmid: &'static str,
+
+    #[serde(rename = "@tm:type")]
+    object_type: String,
+
+    #[serde(rename = "@tm:name")]
+    name: String,
+}
+
+/// The response of the transport organizer to a [`UserAction`]
+///
+/// Typically the root element of the related XML Response.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "tm:root")]
+#[readonly::make]
+pub struct UserActionResult {
+    /// The request that was created or changed.
+    #[serde(rename = "tm:request")]
+    pub request: Option<Request>,
+
+    /// The reports of the checks run on release.
+    #[serde(rename = "tm:releasereports")]
+    pub release_reports: Option<ReleaseReports>,
+}
+
+/// Wraps a collection of [`ReleaseReport`]
+#[derive(Debug, Deserialize)]
+#[readonly::make]
+pub struct ReleaseReports {
+    #[serde(rename = "chkrun:checkReport", default)]
+    pub reports: Vec<ReleaseReport>,
+}
+
+/// The result of a check run on the release of a request, e.g. whether all objects
+/// are active.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "chkrun:checkReport")]
+#[readonly::make]
+pub struct ReleaseReport {
+    #[serde(rename = "@chkrun:reporter", default)]
+    pub reporter: String,
+
+    /// The status of the release, e.g `released` or `abortrelapifail`.
+    #[serde(rename = "@chkrun:status")]
+    pub status: String,
+
+    #[serde(rename = "@chkrun:statusText", default)]
+    pub status_text: String,
+
+    /// Optional, the problems that prevented the release.
+    #[serde(rename = "chkrun:checkMessageList")]
+    pub messages: Option<MessageList>,
+}
+
+impl ReleaseReport {
+    pub fn is_released(&self) -> bool {
+        self.status == "released"
+    }
+}
+
+/// The object to check for whether changes to it have to be recorded in a transport.
+///
+/// Content Type Version `com.sap.adt.transport.service.checkData`
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename = "DATA")]
+pub struct TransportCheckObject {
+    #[serde(rename = "PGMID")]
+    pgmid: String,
+
+    #[serde(rename = "OBJECT")]
+    object: String,
+
+    #[serde(rename = "OBJECTNAME")]
+    object_name: String,
+
+    /// The package of the object, omitted for existing objects.
+    #[serde(rename = "DEVCLASS", skip_serializing_if = "Option::is_none")]
+    package: Option<String>,
+
+    #[serde(rename = "SUPER_PACKAGE")]
+    super_package: String,
+
+    /// The operation on the object, `I` for inserting (or changing) it.
+    #[serde(rename = "OPERATION")]
+    operation: String,
+
+    #[serde(rename = "URI")]
+    uri: String,
+}
+
+impl TransportCheckObject {
+    /// Checks the object at the (absolute) uri, the package is only required for
+    /// objects that do not exist yet.
+    pub fn new(uri: &str, package: Option<&str>) -> Self {
+        Self {
+            pgmid: String::new(),
+            object: String::new(),
+            object_name: String::new(),
+            package: package.map(str::to_owned),
+            super_package: String::new(),
+            operation: String::from("I"),
+            uri: uri.to_owned(),
+        }
+    }
+}
+
+/// A transport request to create for the change of an object, the backend derives its
+/// target from the transport layer of the package.
+///
+/// Content Type Version `com.sap.adt.CreateCorrectionRequest`
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename = "DATA")]
+pub struct CorrectionRequest {
+    /// The operation on the object, `I` for inserting (or changing) it.
+    #[serde(rename = "OPERATION")]
+    operation: String,
+
+    #[serde(rename = "DEVCLASS")]
+    package: String,
+
+    #[serde(rename = "REQUEST_TEXT")]
+    description: String,
+
+    /// The (absolute) uri of the object the request is created for.
+    #[serde(rename = "REF")]
+    uri: String,
+}
+
+impl CorrectionRequest {
+    pub fn new(uri: &str, package: &str, description: &str) -> Self {
+        Self {
+            operation: String::from("I"),
+            package: package.to_owned(),
+            description: description.to_owned(),
+            uri: uri.to_owned(),
+        }
+    }
+}
+
+/// Contains the result of a transport check, i.e. whether changes to an object have
+/// to be recorded in a transport and which requests it may be recorded in.
+///
+/// Content Type Version `com.sap.adt.transport.service.checkData`
+#[derive(Debug, Deserialize)]
+#[serde(rename = "DATA")]
+#[readonly::make]
+pub struct TransportCheck {
+    /// The package of the object.
+    #[serde(rename = "DEVCLASS", default)]
+    pub package: String,
+
+    /// The description of the package.
+    #[serde(rename = "CTEXT", default)]
+    pub package_description: String,
+
+    /// The software component of the package, `LOCAL` for local packages like `$TMP`.
+    #[serde(rename = "DLVUNIT", default)]
+    pub software_component: String,
+
+    /// The result of the check, e.g. `S` if it succeeded.
+    #[serde(rename = "RESULT", default)]
+    pub result: String,
+
+    /// Whether changes to the object are recorded in a transport.
+    #[serde(
+        rename = "RECORDING",
+        default,
+        deserialize_with = "deserialize_abap_bool"
+    )]
+    pub recording: bool,
+
+    /// The requests of the user the object can be recorded in.
+    #[serde(rename = "TRANSPORTS")]
+    pub transports: Option<TransportHeaders>,
+
+    /// The locks of the object, if it is already recorded in a request.
+    #[serde(rename = "LOCKS")]
+    pub locks: Option<ObjectLocks>,
+}
+
+impl TransportCheck {
+    /// Whether saving the object requires the number of a request.
+    pub fn requires_transport(&self) -> bool {
+        self.recording && self.software_component != "LOCAL"
+    }
+
+    /// The request the object is already recorded in, changes have to go into it.
+    pub fn locking_request(&self) -> Option<&TransportHeader> {
+        self.locks
+            .as_ref()?
+            .locks
+            .first()
+            .map(|lock| &lock.holder.request)
+    }
+
+    /// The requests of the user the object can be recorded in.
+    pub fn transports(&self) -> &[TransportHeader] {
+        self.transports
+            .as_ref()
+            .map_or(&[], |transports| &transports.headers)
+    }
+}
+
+/// Wraps a collection of [`TransportHeader`]
+#[derive(Debug, Deserialize)]
+#[readonly::make]
+pub struct TransportHeaders {
+    #[serde(rename = "headers", default)]
+    pub headers: Vec<TransportHeader>,
+}
+
+/// The header of a request as stored in table `E070`.
+#[derive(Debug, Deserialize)]
+#[readonly::make]
+pub struct TransportHeader {
+    #[serde(rename = "TRKORR")]
+    pub number: String,
+
+    #[serde(rename = "TRFUNCTION", deserialize_with = "deserialize_text")]
+    pub request_type: RequestType,
+
+    #[serde(rename = "TRSTATUS", deserialize_with = "deserialize_text")]
+    pub status: TransportStatus,
+
+    /// The system the request is transported to, empty for local requests.
+    #[serde(rename = "TARSYSTEM", default)]
+    pub target: String,
+
+    #[serde(rename = "AS4USER", default)]
+    pub owner: String,
+
+    #[serde(rename = "AS4TEXT", default)]
+    pub description: String,
+}
+
+/// Wraps a collection of [`ObjectLock`]
+#[derive(Debug, Deserialize)]
+#[readonly::make]
+pub struct ObjectLocks {
+    #[serde(rename = "CTS_OBJECT_LOCK", default)]
+    pub locks: Vec<ObjectLock>,
+}
+
+/// The lock of an object by the request it is recorded in.
+#[derive(Debug, Deserialize)]
+#[readonly::make]
+pub struct ObjectLock {
+    #[serde(rename = "LOCK_HOLDER")]
+    pub holder: LockHolder,
+}
+
+#[derive(Debug, Deserialize)]
+#[readonly::make]
+pub struct LockHolder {
+    #[serde(rename = "REQ_HEADER")]
+    pub request: TransportHeader,
+}
+
+/// Deserialize the text of an element into an enum, e.g. `<TRSTATUS>D</TRSTATUS>`
+fn deserialize_text<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned,
+{
+    let text = String::deserialize(deserializer)?;
+    T::deserialize(text.into_deserializer())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::asx::AsxData;
+
+    #[test]
+    fn deserialize_transport_organizer() {
+        let plain = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <tm:root xmlns:tm="http://www.sap.com/cts/adt/tm">
+                <tm:workbench tm:category="Workbench">
+                    <tm:target tm:name="/A4H/" tm:desc="Target A4H">
+                        <tm:modifiable tm:status="Modifiable">
+                            <tm:request tm:number="A4HK900089" tm:owner="DEVELOPER" tm:desc="Test Transport" tm:status="D" tm:uri="/sap/bc/adt/cts/transportrequests/A4HK900089">
+                                <tm:long_desc/>
+                                <tm:task tm:number="A4HK900090" tm:parent="A4HK900089" tm:owner="DEVELOPER" tm:desc="Test Transport" tm:status="D" tm:uri="/sap/bc/adt/cts/transportrequests/A4HK900090">
+                                    <tm:abap_object tm:pgmid="R3TR" tm:type="PROG" tm:name="ZDEMO1" tm:wbtype="PROG/P" tm:uri="/sap/bc/adt/programs/programs/zdemo1" tm:obj_info="Program" tm:obj_desc="Demo"/>
+                                </tm:task>
+                            </tm:request>
+                        </tm:modifiable>
+                        <tm:released/>
+                    </tm:target>
+                </tm:workbench>
+            </tm:root>"#;
+
+        let result: TransportOrganizer = serde_xml_rs::from_str(plain).unwrap();
+        assert!(result.customizing.is_none());
+
+        let requests: Vec<&Request> = result.modifiable_requests().collect();
+        assert_eq!(requests.len(), 1, "Expected one modifiable request");
+        assert_eq!(requests[0].number, "A4HK900089");
+        assert_eq!(requests[0].status, Some(TransportStatus::Modifiable));
+
+        let task = &requests[0].tasks[0];
+        assert_eq!(task.parent.as_deref(), Some("A4HK900089"));
+        assert_eq!(task.objects[0].name, "ZDEMO1");
+        assert_eq!(task.objects[0].workbench_type, "PROG/P");
+    }
+
+    #[test]
+    fn serialize_new_request() {
+        let body = UserAction::new_request(
+            "Test Transport",
+            RequestType::Workbench,
+            "LOCAL",
+            "DEVELOPER",
+        )
+        .into_xml_root()
+        .unwrap();
+
+        assert!(body.contains(r#"tm:useraction="newrequest""#));
+        assert!(body.contains(r#"tm:desc="Test Transport""#));
+        assert!(body.contains(r#"tm:type="K""#));
+        assert!(body.contains(r#"<tm:task tm:owner="DEVELOPER""#));
+    }
+
+    #[test]
+    fn serialize_new_object() {
+        let body = UserAction::new_object("prog", "zdemo1")
+            .into_xml_root()
+            .unwrap();
+
+        assert!(body.contains(r#"tm:useraction="newobject""#));
+        assert!(body.contains(r#"tm:pgmid="R3TR""#));
+        assert!(body.contains(r#"tm:name="ZDEMO1""#));
+        assert!(!body.contains("tm:request"));
+    }
+
+    #[test]
+    fn deserialize_release_result() {
+        let plain = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <tm:root xmlns:tm="http://www.sap.com/cts/adt/tm" tm:useraction="newreleasejobs">
+                <tm:releasereports>
+                    <chkrun:checkReport xmlns:chkrun="http://www.sap.com/adt/checkrun" chkrun:reporter="transportrelease" chkrun:triggeringUri="/sap/bc/adt/cts/transportrequests/A4HK900090" chkrun:status="released" chkrun:statusText="Task A4HK900090 was released">
+                        <chkrun:checkMessageList/>
+                    </chkrun:checkReport>
+                </tm:releasereports>
+            </tm:root>"#;
+
+        let result: UserActionResult = serde_xml_rs::from_str(plain).unwrap();
+        let reports = result.release_reports.unwrap();
+        assert!(reports.reports[0].is_released());
+    }
+
+    #[test]
+    fn serialize_transport_check_object() {
+        let body = AsxData::new(TransportCheckObject::new(
+            "/sap/bc/adt/programs/programs/zdemo1",
+            Some("ZPACKAGE"),
+        ))
+        .into_xml_root()
+        .unwrap();
+
+        assert!(body.contains("<asx:values><DATA>"));
+        assert!(body.contains("<DEVCLASS>ZPACKAGE</DEVCLASS>"));
+        assert!(body.contains("<OPERATION>I</OPERATION>"));
+    }
+
+    #[test]
+    fn deserialize_transport_check() {
+        let plain = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <asx:abap xmlns:asx="http://www.sap.com/abapxml" version="1.0">
+                <asx:values>
+                    <DATA>
+                        <PGMID/>
+                        <OBJECT/>
+                        <OBJECTNAME/>
+                        <OPERATION>I</OPERATION>
+                        <DEVCLASS>ZPACKAGE</DEVCLASS>
+                        <CTEXT>Test Package</CTEXT>
+                        <KORRFLAG>X</KORRFLAG>
+                        <AS4USER>DEVELOPER</AS4USER>
+                        <PDEVCLASS>ZPACKAGE</PDEVCLASS>
+                        <DLVUNIT>HOME</DLVUNIT>
+                        <MESSAGES/>
+                        <NAMESPACE/>
+                        <RESULT>S</RESULT>
+                        <RECORDING>X</RECORDING>
+                        <EXISTING_REQ_ONLY/>
+                        <TRANSPORTS>
+                            <headers>
+                                <TRKORR>A4HK900089</TRKORR>
+                                <TRFUNCTION>K</TRFUNCTION>
+                                <TRSTATUS>D</TRSTATUS>
+                                <TARSYSTEM/>
+                                <AS4USER>DEVELOPER</AS4USER>
+                                <AS4DATE>2025-09-16</AS4DATE>
+                                <AS4TIME>20:15:34</AS4TIME>
+                                <AS4TEXT>Test Transport</AS4TEXT>
+                                <CLIENT>001</CLIENT>
+                            </headers>
+                        </TRANSPORTS>
+                        <LOCKS/>
+                    </DATA>
+                </asx:values>
+            </asx:abap>"#;
+
+        let result: AsxData<TransportCheck> = serde_xml_rs::from_str(plain).unwrap();
+        assert!(result.requires_transport());
+        assert!(result.locking_request().is_none());
+        assert_eq!(result.transports()[0].number, "A4HK900089");
+        assert_eq!(result.transports()[0].request_type, RequestType::Workbench);
+    }
+
+    #[test]
+    fn local_objects_require_no_transport() {
+        let plain = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <asx:abap xmlns:asx="http://www.sap.com/abapxml" version="1.0">
+                <asx:values>
+                    <DATA>
+                        <DEVCLASS>$TMP</DEVCLASS>
+                        <DLVUNIT>LOCAL</DLVUNIT>
+                        <RESULT>S</RESULT>
+                        <RECORDING/>
+                        <TRANSPORTS/>
+                        <LOCKS/>
+                    </DATA>
+                </asx:values>
+            </asx:abap>"#;
+
+        let result: AsxData<TransportCheck> = serde_xml_rs::from_str(plain).unwrap();
+        assert!(!result.requires_transport());
+        assert!(result.transports().is_empty());
+    }
+}
@@ -0,0 +1,211 @@
+/// Exceptions (EXC) - http://www.sap.com/abapxml/types/communicationframework
+///
+/// The document the ADT REST framework responds with when a request could not be
+/// processed, e.g. because the object is locked or does not exist.
+///
+/// ABAP ADT Responsible: `CX_ADT_REST` and its subclasses
+use serde::Deserialize;
+use std::fmt;
+
+/// The kind of exception that was raised on the server.
+///
+/// Corresponds to the `type` of the exception document, which is the name of the
+/// exception class without the `CX_ADT_` prefix, e.g. `ExceptionResourceNotFound`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub enum ExceptionKind {
+    /// The object is currently locked, typically by another user editing it.
+    #[serde(rename = "ExceptionResourceAlreadyLocked")]
+    ResourceAlreadyLocked,
+
+    /// The lock handle provided in the request is not (or no longer) valid.
+    #[serde(rename = "ExceptionResourceInvalidLockHandle")]
+    InvalidLockHandle,
+
+    /// The requested object does not exist.
+    #[serde(rename = "ExceptionResourceNotFound")]
+    ResourceNotFound,
+
+    /// An object with the same name already exists.
+    #[serde(rename = "ExceptionResourceAlreadyExists")]
+    ResourceAlreadyExists,
+
+    /// The user is not authorized to access the object.
+    #[serde(rename = "ExceptionResourceNoAccess")]
+    ResourceNoAccess,
+
+    /// The data sent to the server was rejected, e.g. because of an invalid object name.
+    #[serde(rename = "ExceptionInvalidData")]
+    InvalidData,
+
+    /// The user context the request was sent in does not exist anymore (to be clarified)
+    #[serde(rename = "ExceptionSessionNotFound")]
+    SessionNotFound,
+
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// Represents the `exc:exception` document returned by the server in case of an error.
+///
+/// ## Example:
+/// ```xml
+/// <exc:exception xmlns:exc="http://www.sap.com/abapxml/types/communicationframework">
+///     <namespace id="com.sap.adt"/>
+///     <type id="ExceptionResourceAlreadyLocked"/>
+///     <message lang="EN">User DEVELOPER is currently editing ZDEMO1</message>
+///     <localizedMessage lang="EN">User DEVELOPER is currently editing ZDEMO1</localizedMessage>
+///     <properties>
+///         <entry key="T100KEY-ID">EU</entry>
+///         <entry key="T100KEY-NO">510</entry>
+///     </properties>
+/// </exc:exception>
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename = "exc:exception")]
+#[readonly::make]
+pub struct AdtException {
+    /// The namespace of the exception, typically `com.sap.adt`
+    #[serde(rename = "namespace")]
+    pub namespace: Identifier<String>,
+
+    /// The kind of exception, see [`ExceptionKind`]
+    #[serde(rename = "type")]
+    pub kind: Identifier<ExceptionKind>,
+
+    /// The message of the exception, usually in the logon language.
+    #[serde(rename = "message")]
+    pub message: Message,
+
+    /// The message of the exception in the logon language.
+    #[serde(rename = "localizedMessage")]
+    pub localized_message: Option<Message>,
+
+    /// Additional properties, e.g. the T100 message key or a long text.
+    #[serde(rename = "properties", default)]
+    pub properties: Properties,
+}
+
+impl AdtException {
+    /// The kind of the exception, see [`ExceptionKind`]
+    pub fn kind(&self) -> &ExceptionKind {
+        &self.kind.id
+    }
+
+    /// The most suitable text to present to the user, prefers the localized message.
+    pub fn text(&self) -> &str {
+        self.localized_message
+            .as_ref()
+            .map_or(&self.message.text, |m| &m.text)
+    }
+
+    /// Returns the value of a property by its key, e.g. `T100KEY-ID`.
+    pub fn property(&self, key: &str) -> Option<&str> {
+        self.properties
+            .entries
+            .iter()
+            .find(|e| e.key == key)
+            .map(|e| e.value.as_str())
+    }
+
+    /// The long text of the exception, if the server provided one.
+    pub fn long_text(&self) -> Option<&str> {
+        self.property("LONGTEXT")
+    }
+
+    /// The message class and number of the underlying T100 message, e.g. `("EU", "510")`
+    pub fn t100_key(&self) -> Option<(&str, &str)> {
+        Some((self.property("T100KEY-ID")?, self.property("T100KEY-NO")?))
+    }
+}
+
+impl fmt::Display for AdtException {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.text())
+    }
+}
+
+/// Helper for elements whose only content is an `id` attribute, e.g. `<type id="..."/>`
+#[derive(Debug, Clone, Deserialize)]
+#[readonly::make]
+pub struct Identifier<T> {
+    #[serde(rename = "@id")]
+    pub id: T,
+}
+
+/// A (localized) message of an [`AdtException`].
+#[derive(Debug, Clone, Deserialize)]
+#[readonly::make]
+pub struct Message {
+    /// The language of the message, e.g. `EN`
+    #[serde(rename = "@lang")]
+    pub language: Option<String>,
+
+    #[serde(rename = "#text", default)]
+    pub text: String,
+}
+
+/// Wraps a collection of [`Property`] of an [`AdtException`].
+#[derive(Debug, Clone, Default, Deserialize)]
+#[readonly::make]
+pub struct Properties {
+    #[serde(rename = "entry", default)]
+    pub entries: Vec<Property>,
+}
+
+/// A key-value pair providing additional information about an [`AdtException`].
+#[derive(Debug, Clone, Deserialize)]
+#[readonly::make]
+pub struct Property {
+    /// The key of the property, e.g `T100KEY-ID`, `T100KEY-V1` or `LONGTEXT`
+    #[serde(rename = "@key")]
+    pub key: String,
+
+    #[serde(rename = "#text", default)]
+    pub value: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_already_locked_exception() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+                        <exc:exception xmlns:exc="http://www.sap.com/abapxml/types/communicationframework">
+                            <namespace id="com.sap.adt"/>
+                            <type id="ExceptionResourceAlreadyLocked"/>
+                            <message lang="EN">User DEVELOPER is currently editing ZDEMO1</message>
+                            <localizedMessage lang="EN">User DEVELOPER is currently editing ZDEMO1</localizedMessage>
+                            <properties>
+                                <entry key="T100KEY-ID">EU</entry>
+                                <entry key="T100KEY-NO">510</entry>
+                                <entry key="T100KEY-V1">DEVELOPER</entry>
+                                <entry key="T100KEY-V2">ZDEMO1</entry>
+                            </properties>
+                        </exc:exception>"#;
+
+        let result: AdtException = serde_xml_rs::from_str(plain).unwrap();
+        assert_eq!(result.kind(), &ExceptionKind::ResourceAlreadyLocked);
+        assert_eq!(result.text(), "User DEVELOPER is currently editing ZDEMO1");
+        assert_eq!(result.t100_key(), Some(("EU", "510")));
+        assert_eq!(result.property("T100KEY-V1"), Some("DEVELOPER"));
+    }
+
+    #[test]
+    fn deserialize_unknown_exception_without_properties() {
+        let plain = r#"<?xml version="1.0" encoding="utf-8"?>
+                        <exc:exception xmlns:exc="http://www.sap.com/abapxml/types/communicationframework">
+                            <namespace id="com.sap.adt"/>
+                            <type id="ExceptionSomethingNew"/>
+                            <message lang="EN">Something went wrong</message>
+                        </exc:exception>"#;
+
+        let result: AdtException = serde_xml_rs::from_str(plain).unwrap();
+        assert_eq!(
+            result.kind(),
+            &ExceptionKind::Unknown("ExceptionSomethingNew".into())
+        );
+        assert_eq!(result.text(), "Something went wrong");
+        assert_eq!(result.long_text(), None);
+    }
+}
@@ -0,0 +1,206 @@
+/// Function Groups, Function Modules and Function Group Includes
+///
+/// - Groups - http://www.sap.com/adt/functions/groups
+/// - Modules - http://www.sap.com/adt/functions/fmodules
+/// - Includes - http://www.sap.com/adt/functions/fincludes
+use crate::models::{adtcore, atom};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+/// How a function module is processed, see the attributes tab in SE37.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub enum ProcessingType {
+    /// A regular function module that can only be called locally.
+    #[serde(rename = "normal")]
+    Normal,
+    /// A remote-enabled function module (RFC)
+    #[serde(rename = "rfc")]
+    RemoteEnabled,
+    /// An update module, called `IN UPDATE TASK`
+    #[serde(rename = "update")]
+    Update,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// Reference to the object containing another object, e.g. the function group of a module.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "adtcore:containerRef")]
+#[readonly::make]
+pub struct ContainerRef {
+    #[serde(rename = "@adtcore:name")]
+    pub name: String,
+
+    #[serde(rename = "@adtcore:uri")]
+    pub uri: String,
+
+    #[serde(rename = "@adtcore:type")]
+    pub object_type: String,
+}
+
+/// Represents a Function Group
+#[derive(Debug, Deserialize)]
+#[serde(rename = "group:abapFunctionGroup")]
+#[readonly::make]
+pub struct FunctionGroup {
+    /// The name of the function group
+    #[serde(rename = "@adtcore:name")]
+    pub name: String,
+
+    /// The object type of the function group, should be `FUGR/F`
+    #[serde(rename = "@adtcore:type")]
+    pub object_type: String,
+
+    /// The description of the function group
+    #[serde(rename = "@adtcore:description")]
+    pub description: String,
+
+    /// The version of the function group descriptor, e.g `active`
+    #[serde(rename = "@adtcore:version")]
+    pub version: String,
+
+    /// The datetime that the function group was last changed at (UTC)
+    #[serde(rename = "@adtcore:changedAt")]
+    pub last_changed: DateTime<Utc>,
+
+    /// The user who last changed this function group
+    #[serde(rename = "@adtcore:changedBy")]
+    pub changed_by: String,
+
+    /// The user who is responsible for this function group
+    #[serde(rename = "@adtcore:responsible")]
+    pub responsible: String,
+
+    /// The relative uri to fetch the source code of the main program (`SAPL...`)
+    #[serde(rename = "@abapsource:sourceUri")]
+    pub source_uri: String,
+
+    /// Reference to the package the function group belongs to
+    #[serde(rename = "adtcore:packageRef")]
+    pub package: adtcore::PackageRef,
+
+    /// Relative URLs to related function group Operations
+    #[serde(rename = "atom:link", default)]
+    pub links: Vec<atom::Link>,
+}
+
+/// Represents a Function Module
+#[derive(Debug, Deserialize)]
+#[serde(rename = "fmodule:abapFunctionModule")]
+#[readonly::make]
+pub struct FunctionModule {
+    /// The name of the function module
+    #[serde(rename = "@adtcore:name")]
+    pub name: String,
+
+    /// The object type of the function module, should be `FUGR/FF`
+    #[serde(rename = "@adtcore:type")]
+    pub object_type: String,
+
+    /// The description of the function module
+    #[serde(rename = "@adtcore:description")]
+    pub description: String,
+
+    /// The version of the function module descriptor, e.g `active`
+    #[serde(rename = "@adtcore:version")]
+    pub version: String,
+
+    /// The datetime that the function module was last changed at (UTC)
+    #[serde(rename = "@adtcore:changedAt")]
+    pub last_changed: DateTime<Utc>,
+
+    /// The user who last changed this function module
+    #[serde(rename = "@adtcore:changedBy")]
+    pub changed_by: String,
+
+    /// How the function module is processed, see [`ProcessingType`]
+    #[serde(rename = "@fmodule:processingType")]
+    pub processing_type: ProcessingType,
+
+    /// Whether the function module supports basXML for RFC calls
+    #[serde(rename = "@fmodule:basXMLEnabled", default)]
+    pub basxml_enabled: bool,
+
+    /// The release state of the function module, e.g `notReleased`
+    #[serde(rename = "@fmodule:releaseState")]
+    pub release_state: Option<String>,
+
+    /// The relative uri to fetch the function module source code
+    #[serde(rename = "@abapsource:sourceUri")]
+    pub source_uri: String,
+
+    /// Reference to the function group the module belongs to
+    #[serde(rename = "adtcore:containerRef")]
+    pub group: ContainerRef,
+
+    /// Relative URLs to related function module Operations
+    #[serde(rename = "atom:link", default)]
+    pub links: Vec<atom::Link>,
+}
+
+impl FunctionModule {
+    /// Whether the function module can be called remotely (`DESTINATION ...`)
+    pub fn is_rfc_enabled(&self) -> bool {
+        self.processing_type == ProcessingType::RemoteEnabled
+    }
+}
+
+/// Represents an Include of a Function Group, e.g. `LZORDERTOP`
+#[derive(Debug, Deserialize)]
+#[serde(rename = "finclude:abapFunctionGroupInclude")]
+#[readonly::make]
+pub struct FunctionGroupInclude {
+    /// The name of the include
+    #[serde(rename = "@adtcore:name")]
+    pub name: String,
+
+    /// The object type of the include, should be `FUGR/I`
+    #[serde(rename = "@adtcore:type")]
+    pub object_type: String,
+
+    /// The description of the include
+    #[serde(rename = "@adtcore:description")]
+    pub description: String,
+
+    /// The version of the include descriptor, e.g `active`
+    #[serde(rename = "@adtcore:version")]
+    pub version: String,
+
+    /// The datetime that the include was last changed at (UTC)
+    #[serde(rename = "@adtcore:changedAt")]
+    pub last_changed: DateTime<Utc>,
+
+    /// The user who last changed this include
+    #[serde(rename = "@adtcore:changedBy")]
+    pub changed_by: String,
+
+    /// The relative uri to fetch the include source code
+    #[serde(rename = "@abapsource:sourceUri")]
+    pub source_uri: String,
+
+    /// Reference to the function group the include belongs to
+    #[serde(rename = "adtcore:containerRef")]
+    pub group: ContainerRef,
+
+    /// Relative URLs to related include Operations
+    #[serde(rename = "atom:link", default)]
+    pub links: Vec<atom::Link>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_function_module_data() {
+        let plain = r#"<?xml version="1.0" encoding="UTF-8"?><fmodule:abapFunctionModule xmlns:fmodule="http://www.sap.com/adt/functions/fmodules" fmodule:processingType="rfc" fmodule:basXMLEnabled="false" fmodule:releaseState="notReleased" abapsource:sourceUri="source/main" adtcore:name="Z_ORDER_CREATE" adtcore:type="FUGR/FF" adtcore:changedAt="2025-09-20T10:12:44Z" adtcore:version="active" adtcore:createdAt="2025-09-18T00:00:00Z" adtcore:changedBy="DEVELOPER" adtcore:description="Create an order" adtcore:descriptionTextLimit="74" adtcore:language="EN" xmlns:abapsource="http://www.sap.com/adt/abapsource" xmlns:adtcore="http://www.sap.com/adt/core">
+                        <atom:link xmlns:atom="http://www.w3.org/2005/Atom" href="source/main/versions" rel="http://www.sap.com/adt/relations/versions"/>
+                        <atom:link xmlns:atom="http://www.w3.org/2005/Atom" href="source/main" rel="http://www.sap.com/adt/relations/source" type="text/plain" etag="202509201012440011"/>
+                        <adtcore:containerRef adtcore:uri="/sap/bc/adt/functions/groups/zorder" adtcore:type="FUGR/F" adtcore:name="ZORDER"/>
+                    </fmodule:abapFunctionModule>"#;
+
+        let result: FunctionModule = serde_xml_rs::from_str(plain).unwrap();
+        assert!(result.is_rfc_enabled());
+        assert_eq!(result.group.name, "ZORDER");
+    }
+}
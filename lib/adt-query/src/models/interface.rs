@@ -0,0 +1,82 @@
+/// Interfaces - http://www.sap.com/adt/oo/interfaces
+///
+/// ABAP ADT Responsible: `CL_OO_ADT_RES_INTERFACE`
+use crate::models::{adtcore, atom};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+/// Represents a global ABAP Interface
+#[derive(Debug, Deserialize)]
+#[serde(rename = "intf:abapInterface")]
+#[readonly::make]
+pub struct AbapInterface {
+    /// The name of the interface
+    #[serde(rename = "@adtcore:name")]
+    pub name: String,
+
+    /// The object type of the interface, should be `INTF/OI`
+    #[serde(rename = "@adtcore:type")]
+    pub object_type: String,
+
+    /// The description of the interface
+    #[serde(rename = "@adtcore:description")]
+    pub description: String,
+
+    /// The version of the interface descriptor, e.g `active`
+    #[serde(rename = "@adtcore:version")]
+    pub version: String,
+
+    /// The datetime that the interface was last changed at (UTC)
+    #[serde(rename = "@adtcore:changedAt")]
+    pub last_changed: DateTime<Utc>,
+
+    /// The user who last changed this interface
+    #[serde(rename = "@adtcore:changedBy")]
+    pub changed_by: String,
+
+    /// The datetime that the interface was created on (UTC)
+    #[serde(rename = "@adtcore:createdAt")]
+    pub created_at: DateTime<Utc>,
+
+    /// The user who is responsible for this interface
+    #[serde(rename = "@adtcore:responsible")]
+    pub responsible: String,
+
+    /// Master language of the interface
+    #[serde(rename = "@adtcore:masterLanguage")]
+    pub master_language: String,
+
+    /// The ABAP Version of the interface
+    #[serde(rename = "@adtcore:abapLanguageVersion")]
+    pub abap_language_version: Option<String>,
+
+    /// The relative uri to fetch the interface source code
+    #[serde(rename = "@abapsource:sourceUri")]
+    pub source_uri: String,
+
+    /// Reference to the package the interface belongs to
+    #[serde(rename = "adtcore:packageRef")]
+    pub package: adtcore::PackageRef,
+
+    /// Relative URLs to related interface Operations
+    #[serde(rename = "atom:link", default)]
+    pub links: Vec<atom::Link>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_abap_interface_data() {
+        let plain = r#"<?xml version="1.0" encoding="UTF-8"?><intf:abapInterface xmlns:intf="http://www.sap.com/adt/oo/interfaces" abapoo:modeled="false" abapsource:sourceUri="source/main" abapsource:fixPointArithmetic="false" abapsource:activeUnicodeCheck="false" adtcore:responsible="DEVELOPER" adtcore:masterLanguage="EN" adtcore:masterSystem="A4H" adtcore:abapLanguageVersion="X" adtcore:name="ZIF_ORDER_REPOSITORY" adtcore:type="INTF/OI" adtcore:changedAt="2025-09-20T10:12:44Z" adtcore:version="active" adtcore:createdAt="2025-09-18T00:00:00Z" adtcore:changedBy="DEVELOPER" adtcore:createdBy="DEVELOPER" adtcore:description="Order repository" adtcore:descriptionTextLimit="60" adtcore:language="EN" xmlns:abapoo="http://www.sap.com/adt/oo" xmlns:abapsource="http://www.sap.com/adt/abapsource" xmlns:adtcore="http://www.sap.com/adt/core">
+                        <atom:link xmlns:atom="http://www.w3.org/2005/Atom" href="source/main/versions" rel="http://www.sap.com/adt/relations/versions"/>
+                        <atom:link xmlns:atom="http://www.w3.org/2005/Atom" href="source/main" rel="http://www.sap.com/adt/relations/source" type="text/plain" etag="202509201012440011"/>
+                        <adtcore:packageRef adtcore:uri="/sap/bc/adt/packages/%24tmp" adtcore:type="DEVC/K" adtcore:name="$TMP"/>
+                    </intf:abapInterface>"#;
+
+        let result: AbapInterface = serde_xml_rs::from_str(plain).unwrap();
+        assert_eq!(result.name, "ZIF_ORDER_REPOSITORY");
+        assert_eq!(result.package.name, "$TMP");
+    }
+}
@@ -0,0 +1,312 @@
+use std::borrow::Cow;
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::models::{adtcore::SourceLocation, serialize::IntoXmlRoot};
+
+const NAMESPACE: &str = "http://www.sap.com/adt/ris/usageReferences";
+
+/// The objects affected by a where-used search, restricts the search if not empty.
+///
+/// Left empty by the IDE unless the search is narrowed down to specific objects.
+#[derive(Debug, Serialize, Clone, Default)]
+#[serde(rename = "usagereferences:affectedObjects")]
+pub struct AffectedObjects {}
+
+/// The body of a where-used search for the element at the uri of the request.
+#[derive(Debug, Serialize, Clone, Default)]
+#[serde(rename = "usagereferences:usageReferenceRequest")]
+pub struct UsageReferenceRequest {
+    #[serde(rename = "usagereferences:affectedObjects")]
+    affected_objects: AffectedObjects,
+}
+
+impl IntoXmlRoot for UsageReferenceRequest {
+    fn namespaces(&self) -> Vec<(Cow<'static, str>, Cow<'static, str>)> {
+        vec![("usagereferences".into(), NAMESPACE.into())]
+    }
+}
+
+/// The result of a where-used search.
+///
+/// The referencing objects are returned as a flat tree, e.g. the package, the class
+/// and the method using the element, see [`ReferencedObject::parent_uri`].
+#[derive(Debug, Deserialize)]
+#[serde(rename = "usageReferences:usageReferenceResult")]
+#[readonly::make]
+pub struct UsageReferenceResult {
+    /// The number of results, e.g. `3`
+    #[serde(rename = "@usageReferences:numberOfResults", default)]
+    pub number_of_results: u32,
+
+    /// A summary of the search, e.g. `Where-Used List for ZCL_ORDER=>CREATE`
+    #[serde(rename = "@usageReferences:resultDescription", default)]
+    pub description: String,
+
+    #[serde(rename = "usageReferences:referencedObjects", default)]
+    pub referenced_objects: ReferencedObjects,
+}
+
+impl UsageReferenceResult {
+    /// The objects that actually use the element, i.e. without their packages.
+    pub fn results(&self) -> impl Iterator<Item = &ReferencedObject> {
+        self.referenced_objects
+            .objects
+            .iter()
+            .filter(|obj| obj.is_result)
+    }
+}
+
+/// Wraps a collection of [`ReferencedObject`]
+#[derive(Debug, Deserialize, Default)]
+#[readonly::make]
+pub struct ReferencedObjects {
+    #[serde(rename = "usageReferences:referencedObject", default)]
+    pub objects: Vec<ReferencedObject>,
+}
+
+/// An object in the tree of a where-used search.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "usageReferences:referencedObject")]
+#[readonly::make]
+pub struct ReferencedObject {
+    /// The uri of the object or the part of it, e.g. `/sap/bc/adt/oo/classes/zcl_x/source/main#type=CLAS%2FOM;name=RUN`
+    #[serde(rename = "@uri")]
+    pub uri: String,
+
+    /// The uri of the parent of the object in the tree, e.g. the package.
+    #[serde(rename = "@parentUri")]
+    pub parent_uri: Option<String>,
+
+    /// Whether the object uses the element, otherwise it only groups other results.
+    #[serde(rename = "@isResult", default)]
+    pub is_result: bool,
+
+    #[serde(rename = "@canHaveChildren", default)]
+    pub can_have_children: bool,
+
+    /// How the element is used, e.g. `gradeDirect,includeProductive`
+    #[serde(rename = "@usageInformation")]
+    pub usage_information: Option<String>,
+
+    /// Identifies the object in a [`UsageSnippetRequest`] to retrieve the code snippets.
+    #[serde(rename = "usageReferences:objectIdentifier")]
+    pub object_identifier: Option<String>,
+
+    #[serde(rename = "usageReferences:adtObject")]
+    pub adt_object: Option<AdtObject>,
+}
+
+/// The repository object of a [`ReferencedObject`]
+#[derive(Debug, Deserialize)]
+#[readonly::make]
+pub struct AdtObject {
+    /// The name of the object, e.g `ZCL_ORDER_SERVICE`
+    #[serde(rename = "@adtcore:name")]
+    pub name: String,
+
+    /// The type of the object, e.g `CLAS/OC`
+    #[serde(rename = "@adtcore:type")]
+    pub kind: Option<String>,
+
+    #[serde(rename = "@adtcore:description")]
+    pub description: Option<String>,
+
+    #[serde(rename = "@adtcore:responsible")]
+    pub responsible: Option<String>,
+}
+
+/// An object of which to retrieve the code snippets of a where-used search.
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename = "usagereferences:objectIdentifier")]
+pub struct ObjectIdentifier {
+    #[serde(rename = "@optional")]
+    optional: bool,
+
+    #[serde(rename = "#text")]
+    identifier: String,
+}
+
+impl<T: Into<String>> From<T> for ObjectIdentifier {
+    fn from(value: T) -> Self {
+        Self {
+            optional: false,
+            identifier: value.into(),
+        }
+    }
+}
+
+/// Wraps a collection of [`ObjectIdentifier`]
+#[derive(Debug, Serialize, Clone, Default)]
+pub struct ObjectIdentifiers {
+    #[serde(rename = "usagereferences:objectIdentifier")]
+    identifiers: Vec<ObjectIdentifier>,
+}
+
+/// The body of the follow-up request of a where-used search to retrieve the code
+/// snippets of the objects, see [`ReferencedObject::object_identifier`].
+#[derive(Builder, Debug, Serialize, Clone, Default)]
+#[serde(rename = "usagereferences:usageSnippetRequest")]
+pub struct UsageSnippetRequest {
+    #[serde(rename = "usagereferences:objectIdentifiers")]
+    #[builder(setter(custom), default)]
+    object_identifiers: ObjectIdentifiers,
+
+    #[serde(rename = "usagereferences:affectedObjects")]
+    #[builder(setter(skip))]
+    affected_objects: AffectedObjects,
+}
+
+impl UsageSnippetRequestBuilder {
+    pub fn object_identifier(&mut self, identifier: impl Into<ObjectIdentifier>) -> &mut Self {
+        self.object_identifiers
+            .get_or_insert_with(ObjectIdentifiers::default)
+            .identifiers
+            .push(identifier.into());
+        self
+    }
+}
+
+impl IntoXmlRoot for UsageSnippetRequest {
+    fn namespaces(&self) -> Vec<(Cow<'static, str>, Cow<'static, str>)> {
+        vec![("usagereferences".into(), NAMESPACE.into())]
+    }
+}
+
+/// The code snippets of the objects of a where-used search.
+#[derive(Debug, Deserialize)]
+#[serde(rename = "usageReferences:usageSnippetResult")]
+#[readonly::make]
+pub struct UsageSnippetResult {
+    #[serde(rename = "usageReferences:codeSnippetObjects", default)]
+    pub objects: CodeSnippetObjects,
+}
+
+/// Wraps a collection of [`CodeSnippetObject`]
+#[derive(Debug, Deserialize, Default)]
+#[readonly::make]
+pub struct CodeSnippetObjects {
+    #[serde(rename = "usageReferences:codeSnippetObject", default)]
+    pub objects: Vec<CodeSnippetObject>,
+}
+
+/// The code snippets of an object, i.e. where it uses the element.
+#[derive(Debug, Deserialize)]
+#[readonly::make]
+pub struct CodeSnippetObject {
+    #[serde(rename = "usageReferences:objectIdentifier")]
+    pub object_identifier: String,
+
+    #[serde(rename = "usageReferences:codeSnippets", default)]
+    pub snippets: CodeSnippets,
+}
+
+/// Wraps a collection of [`CodeSnippet`]
+#[derive(Debug, Deserialize, Default)]
+#[readonly::make]
+pub struct CodeSnippets {
+    #[serde(rename = "usageReferences:codeSnippet", default)]
+    pub snippets: Vec<CodeSnippet>,
+}
+
+/// A usage of the element within the source of an object.
+#[derive(Debug, Deserialize)]
+#[readonly::make]
+pub struct CodeSnippet {
+    /// The location of the usage, e.g `/sap/bc/adt/programs/programs/ztest/source/main#start=5,10;end=5,15`
+    #[serde(rename = "@uri")]
+    pub uri: String,
+
+    /// The line of code containing the usage.
+    #[serde(rename = "usageReferences:content", default)]
+    pub content: String,
+
+    #[serde(rename = "usageReferences:description", default)]
+    pub description: String,
+}
+
+impl CodeSnippet {
+    /// The location of the usage, parsed from its [`uri`](Self::uri).
+    pub fn location(&self) -> SourceLocation {
+        SourceLocation::parse(&self.uri)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_usage_reference_request() {
+        let result = UsageReferenceRequest::default().into_xml_root().unwrap();
+        assert!(result.contains("usagereferences:usageReferenceRequest"));
+        assert!(result.contains("usagereferences:affectedObjects"));
+    }
+
+    #[test]
+    fn deserialize_usage_reference_result() {
+        let plain_text = r#"<?xml version="1.0" encoding="utf-8"?>
+            <usageReferences:usageReferenceResult xmlns:usageReferences="http://www.sap.com/adt/ris/usageReferences" usageReferences:numberOfResults="2" usageReferences:resultDescription="Where-Used List for ZCL_ORDER=>CREATE">
+                <usageReferences:referencedObjects>
+                    <usageReferences:referencedObject uri="/sap/bc/adt/packages/z_orders" isResult="false" canHaveChildren="true">
+                        <usageReferences:adtObject adtcore:name="Z_ORDERS" adtcore:type="DEVC/K" xmlns:adtcore="http://www.sap.com/adt/core"/>
+                    </usageReferences:referencedObject>
+                    <usageReferences:referencedObject uri="/sap/bc/adt/programs/programs/zorder_report" parentUri="/sap/bc/adt/packages/z_orders" isResult="true" canHaveChildren="false" usageInformation="gradeDirect,includeProductive">
+                        <usageReferences:objectIdentifier>ABAPFULLNAME;ZORDER_REPORT                  PR</usageReferences:objectIdentifier>
+                        <usageReferences:adtObject adtcore:name="ZORDER_REPORT" adtcore:type="PROG/P" adtcore:responsible="DEVELOPER" xmlns:adtcore="http://www.sap.com/adt/core">
+                            <adtcore:packageRef adtcore:name="Z_ORDERS" adtcore:uri="/sap/bc/adt/packages/z_orders"/>
+                        </usageReferences:adtObject>
+                    </usageReferences:referencedObject>
+                </usageReferences:referencedObjects>
+            </usageReferences:usageReferenceResult>"#;
+
+        let result: UsageReferenceResult = serde_xml_rs::from_str(plain_text).unwrap();
+        assert_eq!(result.number_of_results, 2);
+        assert_eq!(result.referenced_objects.objects.len(), 2);
+
+        let results: Vec<_> = result.results().collect();
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].adt_object.as_ref().unwrap().name,
+            "ZORDER_REPORT"
+        );
+        assert!(results[0].object_identifier.is_some());
+    }
+
+    #[test]
+    fn serialize_usage_snippet_request() {
+        let result = UsageSnippetRequestBuilder::default()
+            .object_identifier("ABAPFULLNAME;ZORDER_REPORT")
+            .build()
+            .unwrap()
+            .into_xml_root()
+            .unwrap();
+
+        assert!(result.contains(r#"optional="false""#));
+        assert!(result.contains(">ABAPFULLNAME;ZORDER_REPORT</usagereferences:objectIdentifier>"));
+    }
+
+    #[test]
+    fn deserialize_usage_snippet_result() {
+        let plain_text = r#"<?xml version="1.0" encoding="utf-8"?>
+            <usageReferences:usageSnippetResult xmlns:usageReferences="http://www.sap.com/adt/ris/usageReferences">
+                <usageReferences:codeSnippetObjects>
+                    <usageReferences:codeSnippetObject>
+                        <usageReferences:objectIdentifier>ABAPFULLNAME;ZORDER_REPORT                  PR</usageReferences:objectIdentifier>
+                        <usageReferences:codeSnippets>
+                            <usageReferences:codeSnippet uri="/sap/bc/adt/programs/programs/zorder_report/source/main#start=12,4;end=12,10" matches="1">
+                                <usageReferences:content>    zcl_order=>create( ).</usageReferences:content>
+                                <usageReferences:description>START-OF-SELECTION</usageReferences:description>
+                            </usageReferences:codeSnippet>
+                        </usageReferences:codeSnippets>
+                    </usageReferences:codeSnippetObject>
+                </usageReferences:codeSnippetObjects>
+            </usageReferences:usageSnippetResult>"#;
+
+        let result: UsageSnippetResult = serde_xml_rs::from_str(plain_text).unwrap();
+        let snippet = &result.objects.objects[0].snippets.snippets[0];
+        assert_eq!(snippet.location().start, Some((12, 4)));
+        assert_eq!(snippet.location().end, Some((12, 10)));
+    }
+}
@@ -23,6 +23,15 @@ pub enum RepositoryObject {
     #[serde(rename = "INTF/OI")]
     Interface,
 
+    #[serde(rename = "FUGR/F")]
+    FunctionGroup,
+
+    #[serde(rename = "FUGR/FF")]
+    FunctionModule,
+
+    #[serde(rename = "FUGR/I")]
+    FunctionGroupInclude,
+
     #[serde(rename = "PINF/KI")]
     PackageInterface,
 
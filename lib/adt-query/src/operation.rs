@@ -3,8 +3,8 @@ use crate::error::{OperationError, RequestError, ResponseError};
 use crate::session::UserSessionId;
 use crate::{Client, QueryParameters, RequestDispatch};
 use async_trait::async_trait;
-use http::HeaderMap;
 use http::request::Builder as RequestBuilder;
+use http::{HeaderMap, HeaderValue, header};
 use std::borrow::Cow;
 
 pub trait OperationKind {}
@@ -134,3 +134,14 @@ where
     }
     Ok(req)
 }
+
+/// Headers need to handle whether we have a cached version locally and provide the ETag.
+pub(crate) fn cache_headers(etag: Option<&str>, accept: &'static str) -> HeaderMap {
+    let mut map = HeaderMap::new();
+    match etag {
+        None => map.insert(header::CACHE_CONTROL, HeaderValue::from_static("no-cache")),
+        Some(etag) => map.insert(header::IF_NONE_MATCH, HeaderValue::from_str(etag).unwrap()),
+    };
+    map.insert(header::ACCEPT, HeaderValue::from_static(accept));
+    map
+}
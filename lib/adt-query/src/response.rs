@@ -48,17 +48,17 @@ where
                     T::deserialize_response(body)?,
                 )))
             }
-            _ => Err(ResponseError::BadStatusCode(value)),
+            _ => Err(ResponseError::from_bad_status(value)),
         }
     }
 }
 
 #[derive(Debug)]
-pub struct Success<T: DeserializeOwned>(http::Response<T>);
+pub struct Success<T: DeserializeResponse>(http::Response<T>);
 
 impl<T> Success<T>
 where
-    T: DeserializeOwned,
+    T: DeserializeResponse,
 {
     pub fn take(self) -> http::Response<T> {
         self.0
@@ -67,7 +67,7 @@ where
 
 impl<T> Deref for Success<T>
 where
-    T: DeserializeOwned,
+    T: DeserializeResponse,
 {
     type Target = http::Response<T>;
     fn deref(&self) -> &Self::Target {
@@ -77,7 +77,7 @@ where
 
 impl<T> TryFrom<http::Response<String>> for Success<T>
 where
-    T: DeserializeOwned,
+    T: DeserializeResponse,
 {
     type Error = ResponseError;
 
@@ -87,10 +87,10 @@ where
                 let (res, body) = value.into_parts();
                 Ok(Self(http::Response::from_parts(
                     res,
-                    serde_xml_rs::from_str(&body)?,
+                    T::deserialize_response(body)?,
                 )))
             }
-            _ => Err(ResponseError::BadStatusCode(value)),
+            _ => Err(ResponseError::from_bad_status(value)),
         }
     }
 }
@@ -119,3 +119,32 @@ impl<'a> Deref for Plain<'a> {
         &self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::exception::ExceptionKind;
+
+    #[test]
+    fn exception_body_is_parsed_on_bad_status() {
+        let body = r#"<?xml version="1.0" encoding="utf-8"?>
+                    <exc:exception xmlns:exc="http://www.sap.com/abapxml/types/communicationframework">
+                        <namespace id="com.sap.adt"/>
+                        <type id="ExceptionResourceNotFound"/>
+                        <message lang="EN">Resource ZDOES_NOT_EXIST does not exist</message>
+                    </exc:exception>"#;
+        let response = http::Response::builder()
+            .status(StatusCode::NOT_FOUND)
+            .body(body.to_owned())
+            .unwrap();
+
+        let result = Success::<()>::try_from(response);
+        match result {
+            Err(ResponseError::Exception(status, exception)) => {
+                assert_eq!(status, StatusCode::NOT_FOUND);
+                assert_eq!(exception.kind(), &ExceptionKind::ResourceNotFound);
+            }
+            other => panic!("Expected an exception, got {other:?}"),
+        }
+    }
+}
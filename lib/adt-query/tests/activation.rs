@@ -0,0 +1,37 @@
+use adt_query::{
+    api::activation, dispatch::StatelessDispatch, models::adtcore::ObjectReferenceBuilder,
+};
+
+mod common;
+
+#[tokio::test]
+async fn inactive_objects_can_be_listed() {
+    let client = common::setup_test_system_client();
+
+    let op = activation::InactiveObjectList::default();
+
+    let result = op.dispatch(&client).await;
+    assert!(
+        result.is_ok(),
+        "Failed to list inactive objects: {result:?}"
+    );
+}
+
+#[tokio::test]
+async fn active_program_can_be_activated_again() {
+    let client = common::setup_test_system_client();
+
+    let op = activation::ActivateBuilder::default()
+        .object(
+            ObjectReferenceBuilder::default()
+                .uri("/sap/bc/adt/programs/programs/zdemo1")
+                .name("ZDEMO1")
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+
+    let result = op.dispatch(&client).await.unwrap();
+    assert!(result.body().is_activated());
+}
@@ -0,0 +1,55 @@
+use adt_query::{api, dispatch::StatelessDispatch, models::adtcore::ObjectReferenceBuilder};
+
+mod common;
+
+#[tokio::test]
+async fn objects_are_checked_by_atc() {
+    let client = common::setup_test_system_client();
+
+    let customizing = api::atc::AtcCustomizingBuilder::default()
+        .build()
+        .unwrap()
+        .dispatch(&client)
+        .await
+        .unwrap();
+    let variant = customizing
+        .body()
+        .system_check_variant()
+        .unwrap_or("DEFAULT")
+        .to_owned();
+
+    let worklist_id = api::atc::CreateWorklistBuilder::default()
+        .check_variant(variant)
+        .build()
+        .unwrap()
+        .dispatch(&client)
+        .await
+        .unwrap()
+        .take()
+        .into_body()
+        .inner();
+
+    let run = api::atc::RunAtcBuilder::default()
+        .worklist_id(worklist_id.as_ref())
+        .object(
+            ObjectReferenceBuilder::default()
+                .uri("/sap/bc/adt/programs/programs/zdemo1")
+                .name("ZDEMO1")
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap()
+        .dispatch(&client)
+        .await
+        .unwrap();
+
+    api::atc::AtcWorklistBuilder::default()
+        .id(worklist_id.as_ref())
+        .timestamp(run.body().timestamp.as_str())
+        .build()
+        .unwrap()
+        .dispatch(&client)
+        .await
+        .unwrap();
+}
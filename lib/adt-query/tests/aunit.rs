@@ -0,0 +1,20 @@
+use adt_query::{api, dispatch::StatelessDispatch, models::adtcore::ObjectReferenceBuilder};
+
+mod common;
+
+#[tokio::test]
+async fn unit_tests_are_run() {
+    let client = common::setup_test_system_client();
+
+    let op = api::aunit::RunUnitTestsBuilder::default()
+        .object(
+            ObjectReferenceBuilder::default()
+                .uri("/sap/bc/adt/programs/programs/zdemo1")
+                .name("ZDEMO1")
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+    op.dispatch(&client).await.unwrap();
+}
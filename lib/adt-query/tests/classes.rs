@@ -0,0 +1,42 @@
+use adt_query::{
+    api::classes, dispatch::StatelessDispatch, models::adtcore, models::class::ClassInclude,
+    response::CacheControlled,
+};
+
+mod common;
+
+#[tokio::test]
+async fn class_data_is_fetched_with_includes() {
+    let client = common::setup_test_system_client();
+
+    let op = classes::ClassBuilder::default()
+        .name("cl_abap_typedescr")
+        .version(adtcore::Version::Active)
+        .build()
+        .unwrap();
+
+    let result = op.dispatch(&client).await.unwrap();
+    match result {
+        CacheControlled::Modified(response) => {
+            let class = response.body();
+            assert_eq!(class.name, "CL_ABAP_TYPEDESCR");
+            assert!(class.include(ClassInclude::Main).is_some());
+        }
+        _ => panic!("Expected the class data to be fetched."),
+    }
+}
+
+#[tokio::test]
+async fn class_include_source_is_fetched_without_cache() {
+    let client = common::setup_test_system_client();
+
+    let op = classes::ClassSourceBuilder::default()
+        .name("cl_abap_typedescr")
+        .include(ClassInclude::Definitions)
+        .version(adtcore::Version::Active)
+        .build()
+        .unwrap();
+
+    let result = op.dispatch(&client).await.unwrap();
+    assert!(matches!(result, CacheControlled::Modified(_)))
+}
@@ -0,0 +1,26 @@
+use adt_query::{api, dispatch::StatelessDispatch};
+
+mod common;
+
+#[tokio::test]
+async fn transport_requests_are_retrieved() {
+    let client = common::setup_test_system_client();
+
+    let op = api::cts::TransportRequestsBuilder::default()
+        .user("DEVELOPER")
+        .build()
+        .unwrap();
+    op.dispatch(&client).await.unwrap();
+}
+
+#[tokio::test]
+async fn local_objects_require_no_transport() {
+    let client = common::setup_test_system_client();
+
+    let op = api::cts::CheckTransportBuilder::default()
+        .object_uri("/sap/bc/adt/programs/programs/zdemo1")
+        .build()
+        .unwrap();
+    let result = op.dispatch(&client).await.unwrap();
+    assert!(!result.body().requires_transport())
+}
@@ -0,0 +1,48 @@
+use adt_query::{
+    api::functions, dispatch::StatelessDispatch, models::adtcore, response::CacheControlled,
+};
+
+mod common;
+
+#[tokio::test]
+async fn function_module_data_is_fetched() {
+    let client = common::setup_test_system_client();
+
+    let op = functions::FunctionModuleBuilder::default()
+        .group("srfc")
+        .name("rfc_ping")
+        .version(adtcore::Version::Active)
+        .build()
+        .unwrap();
+
+    let result = op.dispatch(&client).await.unwrap();
+    match result {
+        CacheControlled::Modified(response) => {
+            let module = response.body();
+            assert_eq!(module.name, "RFC_PING");
+            assert!(module.is_rfc_enabled());
+        }
+        _ => panic!("Expected the function module data to be fetched."),
+    }
+}
+
+#[tokio::test]
+async fn function_module_source_is_fetched() {
+    let client = common::setup_test_system_client();
+
+    let op = functions::FunctionModuleSourceBuilder::default()
+        .group("srfc")
+        .name("rfc_ping")
+        .version(adtcore::Version::Active)
+        .build()
+        .unwrap();
+
+    let result = op.dispatch(&client).await.unwrap();
+    match result {
+        CacheControlled::Modified(response) => {
+            let source = response.body().to_uppercase();
+            assert!(source.contains("FUNCTION RFC_PING"));
+        }
+        _ => panic!("Expected the function module source to be fetched."),
+    }
+}
@@ -1,4 +1,4 @@
-use adt_query::{api::object, dispatch::StatefulDispatch};
+use adt_query::{api::object, dispatch::StatefulDispatch, models::exception::ExceptionKind};
 mod common;
 
 #[tokio::test()]
@@ -41,9 +41,14 @@ async fn object_is_already_locked() {
 
     // Query again, this should cause an `AlreadyLocked` Error.
     let result = op.dispatch(&client, ctx).await;
+    let exception = result.as_ref().err().and_then(|e| e.adt_exception());
     assert!(
-        matches!(result, Err(_)),
-        "Expected the resource to be locked already."
+        exception.is_some_and(|e| e.kind() == &ExceptionKind::ResourceAlreadyLocked),
+        "Expected the resource to be locked already, got {result:?}"
+    );
+    assert!(
+        exception.unwrap().text().contains("DEVELOPER"),
+        "Expected the lock owner to be part of the message."
     );
 
     // Unlock
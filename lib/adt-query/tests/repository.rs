@@ -96,3 +96,23 @@ async fn no_transports_are_retrieved() {
     let result = op.dispatch(&client).await.unwrap();
     assert!(result.body().transports.is_empty())
 }
+
+#[tokio::test]
+async fn objects_are_found_by_quick_search() {
+    let client = common::setup_test_system_client();
+
+    let op = api::repository::QuickSearchBuilder::default()
+        .query("CL_RIS_ADT_RES_*")
+        .max_results(10)
+        .build()
+        .unwrap();
+    let result = op.dispatch(&client).await.unwrap();
+    assert!(
+        result
+            .body()
+            .objects
+            .iter()
+            .any(|o| o.name == "CL_RIS_ADT_RES_APP"),
+        "The application resource should be found"
+    )
+}
@@ -0,0 +1,34 @@
+use adt_query::{
+    api::usagereferences::{UsageReferencesBuilder, UsageSnippetsBuilder},
+    dispatch::StatelessDispatch,
+    models::usagereferences::UsageSnippetRequestBuilder,
+};
+
+mod common;
+
+#[tokio::test]
+async fn usages_of_class_are_found_with_snippets() {
+    let client = common::setup_test_system_client();
+
+    let op = UsageReferencesBuilder::default()
+        .uri("/sap/bc/adt/oo/classes/cl_ris_adt_res_app")
+        .build()
+        .unwrap();
+    let result = op.dispatch(&client).await.unwrap();
+
+    let mut request = UsageSnippetRequestBuilder::default();
+    for identifier in result
+        .body()
+        .results()
+        .filter_map(|obj| obj.object_identifier.clone())
+        .take(5)
+    {
+        request.object_identifier(identifier);
+    }
+
+    let op = UsageSnippetsBuilder::default()
+        .request(request.build().unwrap())
+        .build()
+        .unwrap();
+    op.dispatch(&client).await.unwrap();
+}